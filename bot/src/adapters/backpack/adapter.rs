@@ -0,0 +1,711 @@
+//! Backpack Adapter Implementation
+//!
+//! WebSocket adapter for Backpack Exchange using the `depth.<symbol>` stream.
+//! Read-only market data — each subscribed book is seeded from a REST
+//! snapshot and kept in sync with the stream's incremental updates.
+//!
+//! Docs: https://docs.backpack.exchange/#tag/Streams
+
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::shared::{backoff_delay_ms, ReconnectConfig};
+use crate::adapters::traits::ExchangeAdapter;
+use crate::adapters::types::{
+    create_http_client_with_headers, Capabilities, ConnectionHealth, ConnectionState, Orderbook,
+};
+use crate::core::channels::{
+    AtomicBestPrices, BestPricePublisher, OrderbookNotify, SharedBestPrices, SharedOrderbooks,
+    ThrottledBookWriter,
+};
+
+use super::config::BackpackConfig;
+use super::types::{BackpackBook, BackpackDepthEvent, BackpackDepthSnapshot, BackpackWsMessage};
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+fn current_time_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// =============================================================================
+// WebSocket Type Aliases
+// =============================================================================
+
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+type WsWriter = SplitSink<WsStream, Message>;
+type WsReader = SplitStream<WsStream>;
+
+/// Sync state of one market's local book
+enum BookSync {
+    /// Waiting for the REST snapshot; stream events are buffered meanwhile
+    Pending(Vec<BackpackDepthEvent>),
+    /// Snapshot applied, stream events are merged as they arrive
+    Live(BackpackBook),
+}
+
+/// Local books keyed by Backpack symbol, shared with the reader task
+type SyncedBooks = Arc<Mutex<HashMap<String, BookSync>>>;
+
+// =============================================================================
+// BackpackAdapter
+// =============================================================================
+
+/// Backpack Exchange Adapter implementing ExchangeAdapter trait
+pub struct BackpackAdapter {
+    config: BackpackConfig,
+    http_client: reqwest::Client,
+    ws_stream: Option<Mutex<WsStream>>,
+    ws_sender: Option<Arc<Mutex<WsWriter>>>,
+    reader_handle: Option<JoinHandle<()>>,
+    heartbeat_handle: Option<JoinHandle<()>>,
+    connected: bool,
+    subscriptions: Vec<String>,
+    synced_books: SyncedBooks,
+    orderbooks: HashMap<String, Orderbook>,
+    shared_orderbooks: SharedOrderbooks,
+    shared_best_prices: SharedBestPrices,
+    orderbook_notify: Option<OrderbookNotify>,
+    connection_health: ConnectionHealth,
+}
+
+impl BackpackAdapter {
+    /// Create a new BackpackAdapter
+    pub fn new(config: BackpackConfig) -> Self {
+        Self {
+            http_client: create_http_client_with_headers("Backpack", &config.extra_headers),
+            config,
+            ws_stream: None,
+            ws_sender: None,
+            reader_handle: None,
+            heartbeat_handle: None,
+            connected: false,
+            subscriptions: Vec::new(),
+            synced_books: Arc::new(Mutex::new(HashMap::new())),
+            orderbooks: HashMap::new(),
+            shared_orderbooks: Arc::new(RwLock::new(HashMap::new())),
+            shared_best_prices: Arc::new(AtomicBestPrices::new()),
+            orderbook_notify: None,
+            connection_health: ConnectionHealth::default(),
+        }
+    }
+
+    /// Writer for `shared_orderbooks`; Backpack books are written through
+    /// unthrottled
+    fn book_writer(&self) -> ThrottledBookWriter {
+        ThrottledBookWriter::new(Arc::clone(&self.shared_orderbooks), 0)
+    }
+
+    fn best_price_publisher(&self) -> BestPricePublisher {
+        BestPricePublisher::new(
+            Arc::clone(&self.shared_best_prices),
+            self.orderbook_notify.clone(),
+            false,
+        )
+    }
+
+    // =========================================================================
+    // WebSocket Connection
+    // =========================================================================
+
+    async fn connect_websocket(&mut self) -> ExchangeResult<()> {
+        let url = self.config.ws_url();
+        tracing::info!("Connecting to Backpack WebSocket: {}", url);
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
+        self.ws_stream = Some(Mutex::new(ws_stream));
+        Ok(())
+    }
+
+    fn split_and_spawn_reader(&mut self) -> ExchangeResult<()> {
+        let ws_stream_mutex = self.ws_stream.take().ok_or_else(|| {
+            ExchangeError::ConnectionFailed("No WebSocket stream to split".into())
+        })?;
+
+        let ws_stream = ws_stream_mutex.into_inner();
+        let (ws_sender, ws_receiver) = ws_stream.split();
+
+        self.ws_sender = Some(Arc::new(Mutex::new(ws_sender)));
+
+        let synced_books = Arc::clone(&self.synced_books);
+        let book_writer = self.book_writer();
+        let best_prices = self.best_price_publisher();
+        let health = self.connection_health.clone_refs();
+
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
+        // Set reader_alive BEFORE spawn to prevent race with monitoring loop
+        health.reader_alive.store(true, Ordering::Relaxed);
+
+        let handle = tokio::spawn(async move {
+            Self::message_reader_loop(
+                ws_receiver,
+                synced_books,
+                book_writer,
+                best_prices,
+                health,
+            )
+            .await;
+        });
+
+        self.reader_handle = Some(handle);
+        Ok(())
+    }
+
+    async fn send_stream_request(&self, method: &str, symbol: &str) -> ExchangeResult<()> {
+        let ws_sender = self
+            .ws_sender
+            .as_ref()
+            .ok_or_else(|| ExchangeError::ConnectionFailed("WebSocket not connected".into()))?;
+
+        let msg = serde_json::json!({
+            "method": method,
+            "params": [format!("depth.{}", symbol)],
+        });
+        let mut sender = ws_sender.lock().await;
+        sender
+            .send(Message::Text(msg.to_string()))
+            .await
+            .map_err(|e| ExchangeError::WebSocket(Box::new(e)))
+    }
+
+    // =========================================================================
+    // REST Snapshot
+    // =========================================================================
+
+    /// Fetch the full depth for `symbol` via `GET /api/v1/depth`
+    async fn fetch_depth(&self, symbol: &str) -> ExchangeResult<BackpackDepthSnapshot> {
+        let url = format!("{}/api/v1/depth", self.config.rest_base_url());
+
+        let response = self
+            .http_client
+            .get(&url)
+            .query(&[("symbol", symbol)])
+            .send()
+            .await
+            .map_err(|e| {
+                ExchangeError::ConnectionFailed(format!("Depth snapshot request failed: {}", e))
+            })?;
+        if !response.status().is_success() {
+            return Err(ExchangeError::InvalidResponse(format!(
+                "Depth snapshot for {} returned {}",
+                symbol,
+                response.status()
+            )));
+        }
+
+        response.json::<BackpackDepthSnapshot>().await.map_err(|e| {
+            ExchangeError::InvalidResponse(format!("Failed to parse depth snapshot: {}", e))
+        })
+    }
+
+    /// Seed `symbol`'s book from a snapshot, then merge the stream events
+    /// buffered while it was fetched.
+    async fn sync_book(&self, symbol: &str) -> ExchangeResult<()> {
+        let snapshot = self.fetch_depth(symbol).await?;
+        let mut book = BackpackBook::from_snapshot(&snapshot)?;
+
+        let orderbook = {
+            let mut synced = self.synced_books.lock().await;
+            if let Some(BookSync::Pending(buffered)) = synced.remove(symbol) {
+                for event in &buffered {
+                    book.apply(event)?;
+                }
+            }
+            let orderbook = book.to_orderbook(current_time_ms());
+            synced.insert(symbol.to_string(), BookSync::Live(book));
+            orderbook
+        };
+
+        self.best_price_publisher().publish(&orderbook);
+        self.book_writer().write(symbol.to_string(), orderbook).await;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Background Reader Loop
+    // =========================================================================
+
+    async fn message_reader_loop(
+        mut ws_receiver: WsReader,
+        synced_books: SyncedBooks,
+        mut book_writer: ThrottledBookWriter,
+        best_prices: BestPricePublisher,
+        health: ConnectionHealth,
+    ) {
+        tracing::info!("Backpack message_reader_loop started");
+
+        const READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+        loop {
+            let msg_result = match tokio::time::timeout(READ_TIMEOUT, ws_receiver.next()).await {
+                Ok(Some(msg)) => msg,
+                Ok(None) => {
+                    tracing::info!("Backpack WebSocket stream ended");
+                    break;
+                }
+                Err(_elapsed) => {
+                    tracing::warn!(
+                        "Backpack: no WS message received in {}s, assuming dead connection",
+                        READ_TIMEOUT.as_secs()
+                    );
+                    break;
+                }
+            };
+
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
+
+            match msg_result {
+                Ok(Message::Text(text)) => {
+                    let event = match health.parse_stats.observe(serde_json::from_str::<BackpackWsMessage>(&text)) {
+                        Ok(BackpackWsMessage::Stream(msg)) => msg.data,
+                        Ok(BackpackWsMessage::Error { error }) => {
+                            tracing::warn!(code = error.code, message = %error.message, "Backpack: request rejected");
+                            continue;
+                        }
+                        Err(_) => {
+                            tracing::trace!(message = %text, "Backpack: unknown message format");
+                            continue;
+                        }
+                    };
+
+                    let update = {
+                        let mut synced = synced_books.lock().await;
+                        match synced.get_mut(&event.symbol) {
+                            Some(BookSync::Pending(buffered)) => {
+                                buffered.push(event);
+                                continue;
+                            }
+                            Some(BookSync::Live(book)) => match book.apply(&event) {
+                                Ok(true) => {
+                                    let received_ms = current_time_ms();
+                                    health.ingest_latency.record("backpack", event.timestamp_ms(), received_ms);
+                                    Some(book.to_orderbook(received_ms))
+                                }
+                                Ok(false) => None,
+                                Err(e) => {
+                                    // Missed updates: drop the connection so the
+                                    // manager reconnects and re-seeds every book
+                                    tracing::warn!(error = %e, "Backpack: depth out of sync");
+                                    break;
+                                }
+                            },
+                            // Unsubscribed market still in flight
+                            None => None,
+                        }
+                    };
+
+                    if let Some(orderbook) = update {
+                        best_prices.publish(&orderbook);
+                        book_writer.write(event.symbol, orderbook).await;
+                    }
+                }
+                Ok(Message::Close(_)) => {
+                    tracing::info!("Backpack WebSocket closed by server");
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::error!("Backpack WebSocket error: {}", e);
+                    break;
+                }
+            }
+        }
+
+        health.reader_alive.store(false, Ordering::Relaxed);
+        tracing::warn!("Backpack message reader loop ended");
+    }
+
+    // =========================================================================
+    // Heartbeat (WebSocket ping every 5s)
+    // =========================================================================
+
+    fn spawn_heartbeat_task(&mut self) {
+        let ws_sender = match &self.ws_sender {
+            Some(sender) => Arc::clone(sender),
+            None => return,
+        };
+
+        let last_pong = Arc::clone(&self.connection_health.last_pong);
+        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        last_pong.store(current_time_ms(), Ordering::Relaxed);
+
+        let handle = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(5));
+            interval.tick().await; // skip first immediate tick
+
+            loop {
+                interval.tick().await;
+
+                {
+                    let mut sender = ws_sender.lock().await;
+                    if let Err(e) = sender.send(Message::Ping(vec![])).await {
+                        tracing::warn!("Backpack heartbeat: Failed to send ping - {}", e);
+                        reader_alive.store(false, Ordering::Relaxed);
+                        break;
+                    }
+                }
+
+                tokio::time::sleep(Duration::from_secs(5)).await;
+
+                let pong_age_ms = current_time_ms().saturating_sub(last_pong.load(Ordering::Relaxed));
+                if pong_age_ms > 30_000 {
+                    tracing::warn!(
+                        "Backpack heartbeat: PONG stale ({}ms ago), marking dead",
+                        pong_age_ms
+                    );
+                    reader_alive.store(false, Ordering::Relaxed);
+                    break;
+                }
+            }
+
+            tracing::debug!("Backpack heartbeat task ended");
+        });
+
+        self.heartbeat_handle = Some(handle);
+    }
+}
+
+// =============================================================================
+// ExchangeAdapter Implementation
+// =============================================================================
+
+#[async_trait]
+impl ExchangeAdapter for BackpackAdapter {
+    async fn connect(&mut self) -> ExchangeResult<()> {
+        self.connect_websocket().await?;
+        self.split_and_spawn_reader()?;
+        self.spawn_heartbeat_task();
+
+        self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
+        tracing::info!(exchange = "backpack", "Backpack WebSocket connected");
+
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> ExchangeResult<()> {
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
+
+        if let Some(handle) = self.reader_handle.take() {
+            handle.abort();
+        }
+        if let Some(handle) = self.heartbeat_handle.take() {
+            handle.abort();
+        }
+        if let Some(ws_sender) = self.ws_sender.take() {
+            let mut sender = ws_sender.lock().await;
+            let _ = sender.close().await;
+        }
+        if let Some(ws) = self.ws_stream.take() {
+            let mut stream = ws.lock().await;
+            let _ = stream.close(None).await;
+        }
+
+        self.connected = false;
+        self.subscriptions.clear();
+
+        self.connection_health.last_pong.store(0, Ordering::Relaxed);
+        self.connection_health.last_data.store(0, Ordering::Relaxed);
+        self.connection_health
+            .reader_alive
+            .store(false, Ordering::Relaxed);
+
+        self.synced_books.lock().await.clear();
+        let mut books = self.shared_orderbooks.write().await;
+        books.clear();
+        self.orderbooks.clear();
+
+        Ok(())
+    }
+
+    /// Subscribe to `depth.<symbol>`, then seed the book from REST.
+    ///
+    /// Stream events arriving before the snapshot are buffered and merged
+    /// once it lands, so no update is lost in between.
+    async fn subscribe_orderbook(&mut self, symbol: &str) -> ExchangeResult<()> {
+        if !self.connected {
+            return Err(ExchangeError::ConnectionFailed("Not connected".into()));
+        }
+
+        self.synced_books
+            .lock()
+            .await
+            .insert(symbol.to_string(), BookSync::Pending(Vec::new()));
+        self.send_stream_request("SUBSCRIBE", symbol).await?;
+
+        if let Err(e) = self.sync_book(symbol).await {
+            self.synced_books.lock().await.remove(symbol);
+            let _ = self.send_stream_request("UNSUBSCRIBE", symbol).await;
+            return Err(e);
+        }
+
+        self.subscriptions.push(symbol.to_string());
+        Ok(())
+    }
+
+    async fn unsubscribe_orderbook(&mut self, symbol: &str) -> ExchangeResult<()> {
+        if !self.connected {
+            return Err(ExchangeError::ConnectionFailed("Not connected".into()));
+        }
+
+        self.send_stream_request("UNSUBSCRIBE", symbol).await?;
+        self.subscriptions.retain(|s| s != symbol);
+        self.synced_books.lock().await.remove(symbol);
+
+        {
+            let mut books = self.shared_orderbooks.write().await;
+            books.remove(symbol);
+        }
+        self.orderbooks.remove(symbol);
+
+        Ok(())
+    }
+
+    fn get_orderbook(&self, symbol: &str) -> Option<&Orderbook> {
+        self.orderbooks.get(symbol)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    fn is_stale(&self) -> bool {
+        if !self.connected {
+            return true;
+        }
+        if !self.connection_health.reader_alive.load(Ordering::Relaxed) {
+            return true;
+        }
+        let last_data = self.connection_health.last_data.load(Ordering::Relaxed);
+        if last_data == 0 {
+            return false;
+        }
+        let now = current_time_ms();
+        use crate::adapters::types::STALE_THRESHOLD_MS;
+        now.saturating_sub(last_data) > STALE_THRESHOLD_MS
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            rest_snapshot: true,
+            ..Capabilities::ORDERBOOK_ONLY
+        }
+    }
+
+    async fn fetch_orderbook_snapshot(&self, symbol: &str) -> ExchangeResult<Orderbook> {
+        let snapshot = self.fetch_depth(symbol).await?;
+        Ok(BackpackBook::from_snapshot(&snapshot)?.to_orderbook(current_time_ms()))
+    }
+
+    async fn sync_orderbooks(&mut self) {
+        let books = self.shared_orderbooks.read().await;
+        self.orderbooks = books.clone();
+    }
+
+    async fn reconnect(&mut self) -> ExchangeResult<()> {
+        tracing::info!("Backpack: Initiating reconnection...");
+
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
+
+        let saved_subscriptions = self.subscriptions.clone();
+        self.disconnect().await?;
+
+        let reconnect_config = ReconnectConfig::default();
+        let mut last_error: Option<ExchangeError> = None;
+
+        for attempt in 0..reconnect_config.max_attempts {
+            let backoff_ms = backoff_delay_ms(&reconnect_config, attempt);
+            tracing::info!(
+                "Backpack: Reconnect attempt {} of {}, waiting {}ms...",
+                attempt + 1,
+                reconnect_config.max_attempts,
+                backoff_ms
+            );
+
+            tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+
+            self.connection_health.set_state(ConnectionState::Reconnecting).await;
+
+            match self.connect().await {
+                Ok(()) => {
+                    for symbol in &saved_subscriptions {
+                        if let Err(e) = self.subscribe_orderbook(symbol).await {
+                            tracing::warn!(
+                                "Backpack: Failed to re-subscribe to {}: {}",
+                                symbol,
+                                e
+                            );
+                        }
+                    }
+
+                    tracing::info!(
+                        "Backpack: Reconnection complete ({} subscriptions restored)",
+                        self.subscriptions.len()
+                    );
+
+                    return Ok(());
+                }
+                Err(e) if e.is_maintenance() || e.is_fatal() => {
+                    self.connection_health.set_state(ConnectionState::Disconnected).await;
+                    return Err(e);
+                }
+                Err(e) => {
+                    tracing::warn!("Backpack: Reconnect attempt {} failed: {}", attempt + 1, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
+
+        Err(last_error.unwrap_or_else(|| {
+            ExchangeError::ConnectionFailed("Reconnection failed after max attempts".into())
+        }))
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        "backpack"
+    }
+
+    fn get_shared_orderbooks(&self) -> SharedOrderbooks {
+        Arc::clone(&self.shared_orderbooks)
+    }
+
+    fn get_shared_best_prices(&self) -> SharedBestPrices {
+        Arc::clone(&self.shared_best_prices)
+    }
+
+    fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
+        self.orderbook_notify = Some(notify);
+    }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapter_not_connected_initially() {
+        let adapter = BackpackAdapter::new(BackpackConfig::default());
+        assert!(!adapter.connected);
+        assert!(adapter.is_stale());
+        assert_eq!(adapter.exchange_name(), "backpack");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_orderbook_snapshot_from_rest() {
+        use axum::{extract::Query, routing::get, Json, Router};
+
+        let app = Router::new().route(
+            "/api/v1/depth",
+            get(|Query(params): Query<HashMap<String, String>>| async move {
+                assert_eq!(params.get("symbol").map(String::as_str), Some("BTC_USDC_PERP"));
+                Json(serde_json::json!({
+                    "asks": [["42010.0", "2.0"], ["42011.0", "1.0"]],
+                    "bids": [["41999.0", "3.0"], ["42000.0", "1.0"]],
+                    "lastUpdateId": "7",
+                    "timestamp": 1700000000000u64
+                }))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let config = BackpackConfig {
+            rest_url: Some(format!("http://{}", addr)),
+            ..Default::default()
+        };
+        let adapter = BackpackAdapter::new(config);
+        let book = adapter.fetch_orderbook_snapshot("BTC_USDC_PERP").await.unwrap();
+        assert_eq!(book.best_bid(), Some(42000.0));
+        assert_eq!(book.best_ask(), Some(42010.0));
+        assert_eq!(book.timestamp, 1700000000000, "keeps the exchange's snapshot time");
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_request_carries_extra_headers() {
+        use axum::{http::HeaderMap, routing::get, Json, Router};
+
+        let app = Router::new().route(
+            "/api/v1/depth",
+            get(|headers: HeaderMap| async move {
+                assert_eq!(headers.get("x-route").and_then(|v| v.to_str().ok()), Some("eu-1"));
+                Json(serde_json::json!({
+                    "asks": [["42010.0", "2.0"]],
+                    "bids": [["42000.0", "1.0"]],
+                    "lastUpdateId": "7",
+                    "timestamp": 1700000000000u64
+                }))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let config = BackpackConfig {
+            rest_url: Some(format!("http://{}", addr)),
+            extra_headers: HashMap::from([("X-Route".to_string(), "eu-1".to_string())]),
+            ..Default::default()
+        };
+        let adapter = BackpackAdapter::new(config);
+        // A failed header assertion closes the connection, failing the fetch
+        let book = adapter.fetch_orderbook_snapshot("BTC_USDC_PERP").await.unwrap();
+        assert_eq!(book.best_bid(), Some(42000.0));
+    }
+
+    #[tokio::test]
+    async fn test_reader_counts_only_unknown_frames_as_malformed() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            let depth = serde_json::json!({
+                "stream": "depth.BTC_USDC_PERP",
+                "data": {"s": "BTC_USDC_PERP", "a": [], "b": [["42000.0", "1.0"]], "U": 8, "u": 8}
+            });
+            let rejected = serde_json::json!({"id": null, "error": {"code": 4006, "message": "Invalid stream"}});
+            for frame in [depth.to_string(), rejected.to_string(), "not json".into(), r#"{"unexpected":true}"#.into()] {
+                ws.send(Message::Text(frame)).await.unwrap();
+            }
+            ws.close(None).await.unwrap();
+        });
+        let (stream, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let mut adapter = BackpackAdapter::new(BackpackConfig::default());
+        adapter.ws_stream = Some(Mutex::new(stream));
+        adapter.split_and_spawn_reader().unwrap();
+        tokio::time::timeout(Duration::from_secs(2), adapter.reader_handle.take().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(adapter.parse_stats(), (2, 2), "the error response is a known shape");
+    }
+}
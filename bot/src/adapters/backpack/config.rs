@@ -0,0 +1,78 @@
+//! Backpack Configuration
+//!
+//! Configuration for the Backpack public WebSocket and REST endpoints.
+
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// Backpack public WebSocket URL
+const WS_URL: &str = "wss://ws.backpack.exchange";
+
+/// Backpack REST API base URL
+const REST_URL: &str = "https://api.backpack.exchange";
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// Configuration for Backpack exchange connection (public market data)
+#[derive(Debug, Clone, Default)]
+pub struct BackpackConfig {
+    /// Override for the WebSocket URL (regional proxies, local mocks)
+    pub ws_url: Option<String>,
+    /// Override for the REST base URL (regional proxies, local mocks)
+    pub rest_url: Option<String>,
+    /// Extra headers sent on every REST request and the WS handshake
+    /// (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+}
+
+impl BackpackConfig {
+    /// Create configuration from environment variables
+    pub fn from_env() -> Self {
+        Self {
+            ws_url: std::env::var("BACKPACK_WS_URL").ok(),
+            rest_url: std::env::var("BACKPACK_REST_URL").ok(),
+            extra_headers: extra_headers_from_env("BACKPACK_EXTRA_HEADERS"),
+        }
+    }
+
+    /// Get WebSocket URL
+    pub fn ws_url(&self) -> &str {
+        self.ws_url.as_deref().unwrap_or(WS_URL)
+    }
+
+    /// Get REST API base URL
+    pub fn rest_base_url(&self) -> &str {
+        self.rest_url.as_deref().unwrap_or(REST_URL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_default() {
+        let config = BackpackConfig::default();
+        assert_eq!(config.ws_url(), "wss://ws.backpack.exchange");
+        assert_eq!(config.rest_base_url(), "https://api.backpack.exchange");
+    }
+
+    #[test]
+    fn test_config_from_env() {
+        std::env::set_var("BACKPACK_WS_URL", "ws://127.0.0.1:9001");
+        std::env::set_var("BACKPACK_REST_URL", "http://127.0.0.1:9002");
+        let config = BackpackConfig::from_env();
+        std::env::remove_var("BACKPACK_WS_URL");
+        std::env::remove_var("BACKPACK_REST_URL");
+
+        assert_eq!(config.ws_url(), "ws://127.0.0.1:9001");
+        assert_eq!(config.rest_base_url(), "http://127.0.0.1:9002");
+    }
+}
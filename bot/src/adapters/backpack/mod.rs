@@ -0,0 +1,11 @@
+//! Backpack Exchange adapter module
+//!
+//! Provides WebSocket-based real-time orderbook data for Backpack perpetuals,
+//! kept in sync from a REST depth snapshot plus `depth` stream deltas.
+
+pub mod adapter;
+pub mod config;
+pub mod types;
+
+pub use adapter::BackpackAdapter;
+pub use config::BackpackConfig;
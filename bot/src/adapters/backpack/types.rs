@@ -0,0 +1,275 @@
+//! Backpack Types
+//!
+//! REST depth snapshots and `depth.<symbol>` stream messages, plus the local
+//! book they are merged into.
+//!
+//! Docs: https://docs.backpack.exchange/#tag/Streams
+//!
+//! Depth format (both REST and stream):
+//!   bids/asks: [["price", "quantity"], ...] — a quantity of "0" removes the level
+//!
+//! Stream events carry the update id range `U..=u`. A book is synced by
+//! taking a REST snapshot (`lastUpdateId`), dropping events it already
+//! covers, then requiring each event to start right after the previous one.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::types::{Orderbook, OrderbookLevel, MAX_ORDERBOOK_DEPTH};
+
+/// Prices are keyed as integers at this scale so levels sort exactly
+const PRICE_SCALE: f64 = 1e8;
+
+// =============================================================================
+// REST Types
+// =============================================================================
+
+/// `GET /api/v1/depth` response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackpackDepthSnapshot {
+    pub asks: Vec<[String; 2]>,
+    pub bids: Vec<[String; 2]>,
+    /// Last update id included in the snapshot (sent as a string)
+    pub last_update_id: String,
+    /// Exchange time the snapshot was taken, in ms (0 if absent)
+    #[serde(default)]
+    pub timestamp: u64,
+}
+
+// =============================================================================
+// WebSocket Message Types
+// =============================================================================
+
+/// Stream envelope: `{"stream": "depth.SOL_USDC_PERP", "data": {...}}`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackpackStreamMessage {
+    pub stream: String,
+    pub data: BackpackDepthEvent,
+}
+
+/// Any frame on the stream connection
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BackpackWsMessage {
+    Stream(BackpackStreamMessage),
+    /// Rejected request: `{"id": null, "error": {"code": 4006, "message": "..."}}`
+    Error { error: BackpackWsError },
+}
+
+/// Error carried by a rejected stream request
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackpackWsError {
+    pub code: i64,
+    pub message: String,
+}
+
+/// Incremental depth update
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackpackDepthEvent {
+    /// Market symbol (e.g. "SOL_USDC_PERP")
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a", default)]
+    pub asks: Vec<[String; 2]>,
+    #[serde(rename = "b", default)]
+    pub bids: Vec<[String; 2]>,
+    /// First update id in this event
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    /// Last update id in this event
+    #[serde(rename = "u")]
+    pub last_update_id: u64,
+    /// Event time in µs (0 if absent)
+    #[serde(rename = "E", default)]
+    pub event_time_us: u64,
+    /// Matching engine time in µs (0 if absent)
+    #[serde(rename = "T", default)]
+    pub engine_time_us: u64,
+}
+
+impl BackpackDepthEvent {
+    /// Exchange time of the update in ms, preferring the engine time (0 if absent)
+    pub fn timestamp_ms(&self) -> u64 {
+        match self.engine_time_us {
+            0 => self.event_time_us / 1_000,
+            us => us / 1_000,
+        }
+    }
+}
+
+// =============================================================================
+// Local Book
+// =============================================================================
+
+/// Full-depth book for one market, kept in sync with stream deltas
+#[derive(Debug, Clone, Default)]
+pub struct BackpackBook {
+    bids: BTreeMap<i64, f64>,
+    asks: BTreeMap<i64, f64>,
+    last_update_id: u64,
+    /// Exchange time of the snapshot or latest event applied, in ms
+    exchange_time_ms: u64,
+}
+
+impl BackpackBook {
+    /// Seed a book from a REST snapshot
+    pub fn from_snapshot(snapshot: &BackpackDepthSnapshot) -> ExchangeResult<Self> {
+        let last_update_id = snapshot.last_update_id.parse::<u64>().map_err(|e| {
+            ExchangeError::InvalidResponse(format!("Invalid lastUpdateId: {}", e))
+        })?;
+        let mut book = Self { last_update_id, exchange_time_ms: snapshot.timestamp, ..Default::default() };
+        apply_levels(&mut book.bids, &snapshot.bids)?;
+        apply_levels(&mut book.asks, &snapshot.asks)?;
+        Ok(book)
+    }
+
+    /// Last update id reflected in the book
+    pub fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    /// Exchange time of the state the book reflects in ms (0 if unknown)
+    pub fn exchange_time_ms(&self) -> u64 {
+        self.exchange_time_ms
+    }
+
+    /// Apply a stream event.
+    ///
+    /// Returns `Ok(false)` for an event the book already covers, and an
+    /// error if updates were missed (the book must be re-synced).
+    pub fn apply(&mut self, event: &BackpackDepthEvent) -> ExchangeResult<bool> {
+        if event.last_update_id <= self.last_update_id {
+            return Ok(false);
+        }
+        if event.first_update_id > self.last_update_id + 1 {
+            return Err(ExchangeError::InvalidResponse(format!(
+                "{} depth gap: expected update {}, got {}..={}",
+                event.symbol,
+                self.last_update_id + 1,
+                event.first_update_id,
+                event.last_update_id
+            )));
+        }
+        apply_levels(&mut self.bids, &event.bids)?;
+        apply_levels(&mut self.asks, &event.asks)?;
+        self.last_update_id = event.last_update_id;
+        self.exchange_time_ms = event.timestamp_ms().max(self.exchange_time_ms);
+        Ok(true)
+    }
+
+    /// Top `MAX_ORDERBOOK_DEPTH` levels per side as our canonical Orderbook,
+    /// stamped with the exchange time, or `received_ms` if that is unknown
+    pub fn to_orderbook(&self, received_ms: u64) -> Orderbook {
+        let level = |(&key, &qty): (&i64, &f64)| OrderbookLevel::new(key as f64 / PRICE_SCALE, qty);
+        Orderbook {
+            bids: self.bids.iter().rev().take(MAX_ORDERBOOK_DEPTH).map(level).collect(),
+            asks: self.asks.iter().take(MAX_ORDERBOOK_DEPTH).map(level).collect(),
+            timestamp: match self.exchange_time_ms {
+                0 => received_ms,
+                ts => ts,
+            },
+        }
+    }
+}
+
+/// Upsert `[price, quantity]` levels into `side`, removing zero quantities
+fn apply_levels(side: &mut BTreeMap<i64, f64>, levels: &[[String; 2]]) -> ExchangeResult<()> {
+    for [price, qty] in levels {
+        let p = price
+            .parse::<f64>()
+            .map_err(|e| ExchangeError::InvalidResponse(format!("Invalid price '{}': {}", price, e)))?;
+        let q = qty
+            .parse::<f64>()
+            .map_err(|e| ExchangeError::InvalidResponse(format!("Invalid quantity '{}': {}", qty, e)))?;
+        let key = (p * PRICE_SCALE).round() as i64;
+        if q > 0.0 {
+            side.insert(key, q);
+        } else {
+            side.remove(&key);
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> BackpackDepthSnapshot {
+        let json = r#"{
+            "asks": [["96501.5", "0.80"], ["96502.0", "1.20"]],
+            "bids": [["96499.0", "2.00"], ["96500.0", "1.50"]],
+            "lastUpdateId": "1000",
+            "timestamp": 1700000000000
+        }"#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn event(json: &str) -> BackpackDepthEvent {
+        serde_json::from_str::<BackpackStreamMessage>(json).unwrap().data
+    }
+
+    #[test]
+    fn test_snapshot_parsing() {
+        let book = BackpackBook::from_snapshot(&snapshot()).unwrap();
+        assert_eq!(book.last_update_id(), 1000);
+
+        // Bids arrive ascending; the Orderbook puts the best bid first
+        let ob = book.to_orderbook(1);
+        assert_eq!(ob.best_bid(), Some(96500.0));
+        assert_eq!(ob.best_ask(), Some(96501.5));
+        assert_eq!(ob.bids[1], OrderbookLevel::new(96499.0, 2.0));
+        assert_eq!(ob.asks.len(), 2);
+        assert_eq!(ob.timestamp, 1700000000000, "stamped with the snapshot time");
+    }
+
+    #[test]
+    fn test_depth_event_parsing() {
+        let msg: BackpackStreamMessage = serde_json::from_str(
+            r#"{"stream":"depth.BTC_USDC_PERP","data":{"e":"depth","E":1700000000000123,"s":"BTC_USDC_PERP","a":[["96501.5","0"]],"b":[["96500.5","0.25"]],"U":1001,"u":1002,"T":1700000000000100}}"#,
+        )
+        .unwrap();
+        assert_eq!(msg.stream, "depth.BTC_USDC_PERP");
+        assert_eq!(msg.data.symbol, "BTC_USDC_PERP");
+        assert_eq!((msg.data.first_update_id, msg.data.last_update_id), (1001, 1002));
+        assert_eq!(msg.data.bids, vec![["96500.5".to_string(), "0.25".to_string()]]);
+        assert_eq!(msg.data.timestamp_ms(), 1700000000000, "engine time, µs to ms");
+    }
+
+    #[test]
+    fn test_apply_updates_and_removes_levels() {
+        let mut book = BackpackBook::from_snapshot(&snapshot()).unwrap();
+        let update = event(
+            r#"{"stream":"depth.BTC_USDC_PERP","data":{"s":"BTC_USDC_PERP","a":[["96501.5","0"]],"b":[["96500.5","0.25"]],"U":1001,"u":1002,"T":1700000000500000}}"#,
+        );
+        assert!(book.apply(&update).unwrap());
+
+        let ob = book.to_orderbook(1);
+        assert_eq!(ob.timestamp, 1700000000500);
+        assert_eq!(ob.best_bid(), Some(96500.5));
+        assert_eq!(ob.best_ask(), Some(96502.0));
+        assert_eq!(book.last_update_id(), 1002);
+    }
+
+    #[test]
+    fn test_apply_skips_covered_and_rejects_gaps() {
+        let mut book = BackpackBook::from_snapshot(&snapshot()).unwrap();
+        let covered = event(
+            r#"{"stream":"depth.BTC_USDC_PERP","data":{"s":"BTC_USDC_PERP","a":[],"b":[["1.0","1.0"]],"U":990,"u":1000}}"#,
+        );
+        assert!(!book.apply(&covered).unwrap());
+        assert_eq!(book.to_orderbook(1).best_bid(), Some(96500.0));
+
+        let gapped = event(
+            r#"{"stream":"depth.BTC_USDC_PERP","data":{"s":"BTC_USDC_PERP","a":[],"b":[],"U":1005,"u":1006}}"#,
+        );
+        assert!(matches!(book.apply(&gapped), Err(ExchangeError::InvalidResponse(_))));
+    }
+}
@@ -6,7 +6,7 @@
 //! Docs: https://docs.dydx.xyz/indexer-client/websockets
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -92,7 +92,7 @@ impl DydxAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url();
         tracing::info!("Connecting to dYdX WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -110,13 +110,11 @@ impl DydxAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let health = self.connection_health.clone_refs();
 
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
         // Set reader_alive BEFORE spawn to prevent race with monitoring loop
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
             Self::message_reader_loop(
@@ -124,9 +122,7 @@ impl DydxAdapter {
                 shared_orderbooks,
                 shared_best_prices,
                 orderbook_notify,
-                last_pong,
-                last_data,
-                reader_alive,
+                health,
             )
             .await;
         });
@@ -186,9 +182,7 @@ impl DydxAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!("dYdX message_reader_loop started");
 
@@ -210,14 +204,14 @@ impl DydxAdapter {
                 }
             };
 
-            last_data.store(current_time_ms(), Ordering::Relaxed);
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
                     tracing::trace!("dYdX raw WS message: {}", text);
 
-                    match serde_json::from_str::<DydxWsMessage>(&text) {
+                    match health.parse_stats.observe(serde_json::from_str::<DydxWsMessage>(&text)) {
                         Ok(DydxWsMessage::Subscribed { id, contents, .. }) => {
                             let symbol = id.unwrap_or_default();
                             tracing::debug!(
@@ -307,7 +301,7 @@ impl DydxAdapter {
                 Ok(Message::Pong(_)) => {}
                 Ok(Message::Binary(data)) => {
                     if let Ok(text) = String::from_utf8(data) {
-                        if let Ok(msg) = serde_json::from_str::<DydxWsMessage>(&text) {
+                        if let Ok(msg) = health.parse_stats.observe(serde_json::from_str::<DydxWsMessage>(&text)) {
                             match msg {
                                 DydxWsMessage::ChannelData { id, contents, .. }
                                 | DydxWsMessage::Subscribed { id, contents, .. } => {
@@ -337,7 +331,7 @@ impl DydxAdapter {
             }
         }
 
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("dYdX message reader loop ended");
     }
 
@@ -409,16 +403,14 @@ impl ExchangeAdapter for DydxAdapter {
         self.subscribe_to_orderbooks().await?;
 
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "dydx", "dYdX WebSocket connected");
 
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         if let Some(handle) = self.reader_handle.take() {
             handle.abort();
@@ -514,10 +506,7 @@ impl ExchangeAdapter for DydxAdapter {
     async fn reconnect(&mut self) -> ExchangeResult<()> {
         tracing::info!("dYdX: Initiating reconnection...");
 
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Reconnecting;
-        }
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
         let saved_subscriptions = self.subscriptions.clone();
         self.disconnect().await?;
@@ -536,17 +525,11 @@ impl ExchangeAdapter for DydxAdapter {
 
             tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
 
-            {
-                let mut state = self.connection_health.state.write().await;
-                *state = ConnectionState::Reconnecting;
-            }
+            self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
             match self.connect().await {
                 Ok(()) => {
-                    {
-                        let mut state = self.connection_health.state.write().await;
-                        *state = ConnectionState::Connected;
-                    }
+                    self.connection_health.set_state(ConnectionState::Connected).await;
 
                     for symbol in &saved_subscriptions {
                         if let Err(e) = self.subscribe_orderbook(symbol).await {
@@ -565,6 +548,12 @@ impl ExchangeAdapter for DydxAdapter {
 
                     return Ok(());
                 }
+                Err(e) if e.is_maintenance() || e.is_fatal() => {
+                    // Not an attempt: the caller waits out the maintenance
+                    // window, or gives up on a fatal error
+                    self.connection_health.set_state(ConnectionState::Disconnected).await;
+                    return Err(e);
+                }
                 Err(e) => {
                     tracing::warn!("dYdX: Reconnect attempt {} failed: {}", attempt + 1, e);
                     last_error = Some(e);
@@ -572,10 +561,7 @@ impl ExchangeAdapter for DydxAdapter {
             }
         }
 
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         Err(last_error.unwrap_or_else(|| {
             ExchangeError::ConnectionFailed("Reconnection failed after max attempts".into())
@@ -597,6 +583,10 @@ impl ExchangeAdapter for DydxAdapter {
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
         self.orderbook_notify = Some(notify);
     }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
 }
 
 // =============================================================================
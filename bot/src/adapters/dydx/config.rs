@@ -2,6 +2,10 @@
 //!
 //! Configuration for dYdX v4 Indexer WebSocket connection.
 
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -18,11 +22,13 @@ const WS_URL: &str = "wss://indexer.dydx.trade/v4/ws";
 pub struct DydxConfig {
     /// Use production endpoints
     pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
 }
 
 impl Default for DydxConfig {
     fn default() -> Self {
-        Self { production: true }
+        Self { production: true, extra_headers: HashMap::new() }
     }
 }
 
@@ -34,7 +40,7 @@ impl DydxConfig {
             .parse::<bool>()
             .unwrap_or(true);
 
-        Self { production }
+        Self { production, extra_headers: extra_headers_from_env("DYDX_EXTRA_HEADERS") }
     }
 
     /// Get WebSocket URL
@@ -4,29 +4,93 @@
 //! which implements thiserror for consistent error handling.
 
 use thiserror::Error;
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::Error as WsError;
 
 /// Exchange-specific error types for adapter operations
+///
+/// Each variant is either retryable (transient, worth retrying with
+/// backoff) or fatal (retrying the same call gives the same answer); see
+/// [`ExchangeError::is_retryable`].
 #[derive(Error, Debug)]
 pub enum ExchangeError {
-    /// Connection to exchange failed
+    /// Connection to exchange failed. Retryable.
     #[error("Connection failed: {0}")]
     ConnectionFailed(String),
 
-    /// Subscription to market data failed
+    /// Subscription to market data failed. Fatal: the exchange rejected
+    /// the channel, and resending it gets the same rejection.
     #[error("Subscription failed for {symbol}: {reason}")]
     SubscriptionFailed { symbol: String, reason: String },
 
-    /// Network operation timed out
+    /// Network operation timed out. Retryable.
     #[error("Network timeout after {0}ms")]
     NetworkTimeout(u64),
 
-    /// Invalid or unexpected response from exchange
+    /// Invalid or unexpected response from exchange. Retryable: usually a
+    /// truncated or out-of-band message.
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
 
-    /// WebSocket protocol error (boxed to reduce enum size)
+    /// Exchange name not known to the adapter factory. Fatal.
+    #[error("Unknown exchange: {0}")]
+    UnknownExchange(String),
+
+    /// Operation not implemented by this adapter. Fatal.
+    #[error("Not supported: {0}")]
+    NotSupported(String),
+
+    /// Local input the adapter depends on is unusable (e.g. an unreadable
+    /// or corrupt replay recording). Fatal: retrying reads the same input.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// Exchange is down for scheduled maintenance (e.g. HTTP 503).
+    /// Retryable, after a fixed delay (see `MaintenanceBackoff`).
+    #[error("Exchange under maintenance: {0}")]
+    Maintenance(String),
+
+    /// WebSocket protocol error (boxed to reduce enum size). Retryable,
+    /// except a bad URL or a 4xx handshake response (auth rejected,
+    /// unknown endpoint) other than 408/429.
     #[error("WebSocket error: {0}")]
-    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+    WebSocket(Box<WsError>),
+}
+
+impl ExchangeError {
+    /// Whether the exchange reported a maintenance window
+    pub fn is_maintenance(&self) -> bool {
+        matches!(self, ExchangeError::Maintenance(_))
+    }
+
+    /// Whether the operation may succeed if retried later
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ExchangeError::ConnectionFailed(_)
+            | ExchangeError::NetworkTimeout(_)
+            | ExchangeError::InvalidResponse(_)
+            | ExchangeError::Maintenance(_) => true,
+            ExchangeError::SubscriptionFailed { .. }
+            | ExchangeError::UnknownExchange(_)
+            | ExchangeError::NotSupported(_)
+            | ExchangeError::InvalidInput(_) => false,
+            ExchangeError::WebSocket(e) => match e.as_ref() {
+                WsError::Url(_) => false,
+                WsError::Http(response) => {
+                    let status = response.status();
+                    !status.is_client_error()
+                        || status == StatusCode::REQUEST_TIMEOUT
+                        || status == StatusCode::TOO_MANY_REQUESTS
+                }
+                _ => true,
+            },
+        }
+    }
+
+    /// Whether retrying is pointless and the error should be escalated
+    pub fn is_fatal(&self) -> bool {
+        !self.is_retryable()
+    }
 }
 
 /// Result type alias for exchange operations
@@ -65,4 +129,58 @@ mod tests {
         let err = ExchangeError::InvalidResponse("malformed JSON".to_string());
         assert_eq!(err.to_string(), "Invalid response: malformed JSON");
     }
+
+    #[test]
+    fn test_unknown_exchange_display() {
+        let err = ExchangeError::UnknownExchange("binance".to_string());
+        assert_eq!(err.to_string(), "Unknown exchange: binance");
+    }
+
+    #[test]
+    fn test_retryable_classification_per_variant() {
+        let http = |status: u16| {
+            let response = tokio_tungstenite::tungstenite::http::Response::builder()
+                .status(status)
+                .body(None)
+                .unwrap();
+            ExchangeError::WebSocket(Box::new(WsError::Http(response)))
+        };
+        let url = ExchangeError::WebSocket(Box::new(WsError::Url(
+            tokio_tungstenite::tungstenite::error::UrlError::NoHostName,
+        )));
+
+        let retryable = [
+            ExchangeError::ConnectionFailed("refused".into()),
+            ExchangeError::NetworkTimeout(5000),
+            ExchangeError::InvalidResponse("truncated".into()),
+            ExchangeError::Maintenance("HTTP 503".into()),
+            ExchangeError::WebSocket(Box::new(WsError::ConnectionClosed)),
+            http(429),
+            http(502),
+        ];
+        for err in &retryable {
+            assert!(err.is_retryable() && !err.is_fatal(), "{err}");
+        }
+
+        let fatal = [
+            ExchangeError::SubscriptionFailed {
+                symbol: "FOO-PERP".into(),
+                reason: "unknown channel".into(),
+            },
+            ExchangeError::UnknownExchange("binance".into()),
+            ExchangeError::NotSupported("nado REST orderbook snapshot".into()),
+            http(401),
+            http(404),
+            url,
+        ];
+        for err in &fatal {
+            assert!(err.is_fatal() && !err.is_retryable(), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_not_supported_display() {
+        let err = ExchangeError::NotSupported("nado REST orderbook snapshot".to_string());
+        assert_eq!(err.to_string(), "Not supported: nado REST orderbook snapshot");
+    }
 }
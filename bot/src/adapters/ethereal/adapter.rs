@@ -1,7 +1,7 @@
 //! Ethereal Adapter — BookDepth channel
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -59,7 +59,7 @@ impl EtherealAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url();
         tracing::info!("Connecting to Ethereal WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -73,13 +73,11 @@ impl EtherealAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        let health = self.connection_health.clone_refs();
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, last_pong, last_data, reader_alive).await;
+            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, health).await;
         });
         self.reader_handle = Some(handle);
         Ok(())
@@ -103,18 +101,16 @@ impl EtherealAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
         while let Some(msg_result) = ws_receiver.next().await {
-            last_data.store(current_time_ms(), Ordering::Relaxed);
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
-                    if let Ok(msg) = serde_json::from_str::<EtherealWsMessage>(&text) {
+                    if let Ok(msg) = health.parse_stats.observe(serde_json::from_str::<EtherealWsMessage>(&text)) {
                         if let Some(data) = msg.data {
                             let product_id = match &data.product_id { Some(p) => p.clone(), None => continue };
                             if let Some(symbol) = product_id_to_symbol(&product_id) {
@@ -140,7 +136,7 @@ impl EtherealAdapter {
                 Err(e) => { tracing::error!("Ethereal WS error: {}", e); break; }
             }
         }
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
     }
 
     fn spawn_heartbeat_task(&mut self) {
@@ -175,10 +171,11 @@ impl ExchangeAdapter for EtherealAdapter {
         self.spawn_heartbeat_task();
         self.subscribe_to_book_depth().await?;
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         Ok(())
     }
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Disconnected; }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         if let Some(h) = self.reader_handle.take() { h.abort(); }
         if let Some(h) = self.heartbeat_handle.take() { h.abort(); }
         if let Some(s) = self.ws_sender.take() { let mut s = s.lock().await; let _ = s.close().await; }
@@ -225,6 +222,7 @@ impl ExchangeAdapter for EtherealAdapter {
     fn get_shared_orderbooks(&self) -> SharedOrderbooks { Arc::clone(&self.shared_orderbooks) }
     fn get_shared_best_prices(&self) -> SharedBestPrices { Arc::clone(&self.shared_best_prices) }
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) { self.orderbook_notify = Some(notify); }
+    fn connection_health(&self) -> &ConnectionHealth { &self.connection_health }
 }
 
 #[cfg(test)]
@@ -1,13 +1,24 @@
 //! Ethereal Configuration
 //! Ethereal uses Socket.IO but we approximate with raw WS.
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 const MAINNET_WS_URL: &str = "wss://ws.ethereal.trade/v1/stream";
 
 #[derive(Debug, Clone)]
-pub struct EtherealConfig { pub production: bool }
-impl Default for EtherealConfig { fn default() -> Self { Self { production: true } } }
+pub struct EtherealConfig {
+    pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+}
+impl Default for EtherealConfig { fn default() -> Self { Self { production: true, extra_headers: HashMap::new() } } }
 impl EtherealConfig {
     pub fn from_env() -> Self {
-        Self { production: std::env::var("ETHEREAL_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true) }
+        Self {
+            production: std::env::var("ETHEREAL_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true),
+            extra_headers: extra_headers_from_env("ETHEREAL_EXTRA_HEADERS"),
+        }
     }
     pub fn ws_url(&self) -> &str { MAINNET_WS_URL }
 }
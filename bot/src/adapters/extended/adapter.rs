@@ -7,7 +7,7 @@
 //! every 50ms to minimize RwLock contention with the manager poll loop.
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -76,7 +76,7 @@ impl ExtendedAdapter {
         tracing::info!("Connecting to Extended WebSocket: {}", url);
 
         // Extended requires browser-like headers to avoid 403 Forbidden
-        let request = tokio_tungstenite::tungstenite::http::Request::builder()
+        let mut request = tokio_tungstenite::tungstenite::http::Request::builder()
             .uri(url)
             .header("Host", "api.starknet.extended.exchange")
             .header(
@@ -100,6 +100,7 @@ impl ExtendedAdapter {
                     e
                 ))
             })?;
+        crate::adapters::shared::add_headers(&mut request, &self.config.extra_headers)?;
 
         let ws_stream = crate::adapters::shared::connect_tls_with_request(request).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
@@ -118,12 +119,10 @@ impl ExtendedAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        let health = self.connection_health.clone_refs();
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
         // Set reader_alive BEFORE spawn to prevent race with monitoring loop
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
             Self::message_reader_loop(
@@ -131,9 +130,7 @@ impl ExtendedAdapter {
                 shared_orderbooks,
                 shared_best_prices,
                 orderbook_notify,
-                last_pong,
-                last_data,
-                reader_alive,
+                health,
             )
             .await;
         });
@@ -150,9 +147,7 @@ impl ExtendedAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!("Extended message_reader_loop started");
         // reader_alive already set by split_and_spawn_reader()
@@ -171,8 +166,8 @@ impl ExtendedAdapter {
                 msg_result = tokio::time::timeout(READ_TIMEOUT, ws_receiver.next()) => {
                     match msg_result {
                         Ok(Some(Ok(Message::Text(text)))) => {
-                            last_data.store(current_time_ms(), Ordering::Relaxed);
-                            last_pong.store(current_time_ms(), Ordering::Relaxed);
+                            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+                            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
                             msg_count += 1;
 
                             if msg_count <= 3 {
@@ -184,7 +179,7 @@ impl ExtendedAdapter {
                                 );
                             }
 
-                            if let Ok(msg) = serde_json::from_str::<ExtendedOrderbookMsg>(&text) {
+                            if let Ok(msg) = health.parse_stats.observe(serde_json::from_str::<ExtendedOrderbookMsg>(&text)) {
                                 let msg_type = match &msg.msg_type {
                                     Some(t) => t.clone(),
                                     None => continue,
@@ -231,7 +226,7 @@ impl ExtendedAdapter {
                             break;
                         }
                         Ok(Some(Ok(Message::Ping(_) | Message::Pong(_)))) => {
-                            last_pong.store(current_time_ms(), Ordering::Relaxed);
+                            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
                         }
                         Ok(Some(Ok(_))) => {} // Binary, Frame
                         Ok(Some(Err(e))) => {
@@ -280,7 +275,7 @@ impl ExtendedAdapter {
             }
         }
 
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("Extended message reader loop ended");
     }
 
@@ -330,15 +325,13 @@ impl ExchangeAdapter for ExtendedAdapter {
         self.spawn_heartbeat_task();
         // No explicit subscribe needed — the URL already selects the stream
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "extended", "Extended WebSocket connected");
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         if let Some(h) = self.reader_handle.take() {
             h.abort();
         }
@@ -463,6 +456,10 @@ impl ExchangeAdapter for ExtendedAdapter {
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
         self.orderbook_notify = Some(notify);
     }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
 }
 
 #[cfg(test)]
@@ -1,17 +1,24 @@
 //! Extended Configuration
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 const MAINNET_WS_URL: &str = "wss://api.starknet.extended.exchange/stream.extended.exchange/v1/orderbooks?depth=1";
 
 #[derive(Debug, Clone)]
 pub struct ExtendedConfig {
     pub production: bool,
     pub api_key: Option<String>,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
 }
-impl Default for ExtendedConfig { fn default() -> Self { Self { production: true, api_key: None } } }
+impl Default for ExtendedConfig { fn default() -> Self { Self { production: true, api_key: None, extra_headers: HashMap::new() } } }
 impl ExtendedConfig {
     pub fn from_env() -> Self {
         Self {
             production: std::env::var("EXTENDED_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true),
             api_key: std::env::var("EXTENDED_API_KEY").ok(),
+            extra_headers: extra_headers_from_env("EXTENDED_EXTRA_HEADERS"),
         }
     }
     pub fn ws_url(&self) -> &str { MAINNET_WS_URL }
@@ -3,8 +3,11 @@
 //! Creates `ExchangeAdapter` instances from config strings.
 //! Uses an enum-based dispatch pattern (no `Box<dyn>`) to preserve monomorphization.
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 
+use crate::adapters::backpack::{BackpackAdapter, BackpackConfig};
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
 use crate::adapters::dydx::{DydxAdapter, DydxConfig};
 use crate::adapters::ethereal::{EtherealAdapter, EtherealConfig};
@@ -17,11 +20,13 @@ use crate::adapters::nado::{NadoAdapter, NadoConfig};
 use crate::adapters::nord::{NordWsAdapter, NordConfig};
 use crate::adapters::pacifica::{PacificaAdapter, PacificaConfig};
 use crate::adapters::paradex::{ParadexAdapter, ParadexConfig};
+use crate::adapters::replay::ReplayAdapter;
 use crate::adapters::reya::{ReyaAdapter, ReyaConfig};
 use crate::adapters::traits::ExchangeAdapter;
-use crate::adapters::types::Orderbook;
+use crate::adapters::types::{Capabilities, ConnectionHealth, MarketInfo, Orderbook};
 use crate::adapters::vest::{VestAdapter, VestConfig};
-use crate::core::channels::{OrderbookNotify, SharedBestPrices, SharedOrderbooks};
+use crate::config::QuoteCurrency;
+use crate::core::channels::{BookTap, OrderbookNotify, SharedBestPrices, SharedOrderbooks};
 
 // =============================================================================
 // AnyAdapter — enum-based dispatch for dynamic exchange selection
@@ -42,6 +47,9 @@ pub enum AnyAdapter {
     Nord(NordWsAdapter),
     Ethereal(EtherealAdapter),
     Dydx(DydxAdapter),
+    Backpack(BackpackAdapter),
+    /// Plays back a recording instead of connecting (see [`crate::adapters::replay`])
+    Replay(ReplayAdapter),
 }
 
 /// Macro to reduce boilerplate for delegating trait methods
@@ -61,6 +69,8 @@ macro_rules! delegate {
             AnyAdapter::Nord(a) => a.$method($($arg),*),
             AnyAdapter::Ethereal(a) => a.$method($($arg),*),
             AnyAdapter::Dydx(a) => a.$method($($arg),*),
+            AnyAdapter::Backpack(a) => a.$method($($arg),*),
+            AnyAdapter::Replay(a) => a.$method($($arg),*),
         }
     };
     (await $self:expr, $method:ident ( $($arg:expr),* )) => {
@@ -78,6 +88,8 @@ macro_rules! delegate {
             AnyAdapter::Nord(a) => a.$method($($arg),*).await,
             AnyAdapter::Ethereal(a) => a.$method($($arg),*).await,
             AnyAdapter::Dydx(a) => a.$method($($arg),*).await,
+            AnyAdapter::Backpack(a) => a.$method($($arg),*).await,
+            AnyAdapter::Replay(a) => a.$method($($arg),*).await,
         }
     };
     (mut $self:expr, $method:ident ( $($arg:expr),* )) => {
@@ -95,6 +107,8 @@ macro_rules! delegate {
             AnyAdapter::Nord(a) => a.$method($($arg),*),
             AnyAdapter::Ethereal(a) => a.$method($($arg),*),
             AnyAdapter::Dydx(a) => a.$method($($arg),*),
+            AnyAdapter::Backpack(a) => a.$method($($arg),*),
+            AnyAdapter::Replay(a) => a.$method($($arg),*),
         }
     };
     (mut await $self:expr, $method:ident ( $($arg:expr),* )) => {
@@ -112,6 +126,8 @@ macro_rules! delegate {
             AnyAdapter::Nord(a) => a.$method($($arg),*).await,
             AnyAdapter::Ethereal(a) => a.$method($($arg),*).await,
             AnyAdapter::Dydx(a) => a.$method($($arg),*).await,
+            AnyAdapter::Backpack(a) => a.$method($($arg),*).await,
+            AnyAdapter::Replay(a) => a.$method($($arg),*).await,
         }
     };
 }
@@ -146,6 +162,18 @@ impl ExchangeAdapter for AnyAdapter {
         delegate!(self, is_stale())
     }
 
+    fn capabilities(&self) -> Capabilities {
+        delegate!(self, capabilities())
+    }
+
+    async fn fetch_orderbook_snapshot(&self, symbol: &str) -> ExchangeResult<Orderbook> {
+        delegate!(await self, fetch_orderbook_snapshot(symbol))
+    }
+
+    async fn fetch_market_info(&self, symbol: &str) -> ExchangeResult<MarketInfo> {
+        delegate!(await self, fetch_market_info(symbol))
+    }
+
     async fn sync_orderbooks(&mut self) {
         delegate!(mut await self, sync_orderbooks())
     }
@@ -169,6 +197,54 @@ impl ExchangeAdapter for AnyAdapter {
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
         delegate!(mut self, set_orderbook_notify(notify))
     }
+
+    fn set_book_tap(&mut self, tap: BookTap) -> bool {
+        delegate!(mut self, set_book_tap(tap))
+    }
+
+    fn lost_subscriptions(&self) -> Vec<String> {
+        delegate!(self, lost_subscriptions())
+    }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        delegate!(self, connection_health())
+    }
+}
+
+/// Generates `as_<exchange>_mut()` accessors for exchange-specific setup
+/// (e.g. injecting shared caches) that the trait does not cover.
+macro_rules! concrete_accessors {
+    ($($method:ident => $variant:ident($adapter:ty)),* $(,)?) => {
+        impl AnyAdapter {
+            $(
+                #[doc = concat!("Mutable access to the concrete adapter if this is `AnyAdapter::", stringify!($variant), "`")]
+                pub fn $method(&mut self) -> Option<&mut $adapter> {
+                    match self {
+                        AnyAdapter::$variant(a) => Some(a),
+                        _ => None,
+                    }
+                }
+            )*
+        }
+    };
+}
+
+concrete_accessors! {
+    as_vest_mut => Vest(VestAdapter),
+    as_paradex_mut => Paradex(ParadexAdapter),
+    as_lighter_mut => Lighter(LighterAdapter),
+    as_hyperliquid_mut => Hyperliquid(HyperliquidAdapter),
+    as_grvt_mut => Grvt(GrvtAdapter),
+    as_reya_mut => Reya(ReyaAdapter),
+    as_hotstuff_mut => Hotstuff(HotstuffAdapter),
+    as_pacifica_mut => Pacifica(PacificaAdapter),
+    as_extended_mut => Extended(ExtendedAdapter),
+    as_nado_mut => Nado(NadoAdapter),
+    as_nord_mut => Nord(NordWsAdapter),
+    as_ethereal_mut => Ethereal(EtherealAdapter),
+    as_dydx_mut => Dydx(DydxAdapter),
+    as_backpack_mut => Backpack(BackpackAdapter),
+    as_replay_mut => Replay(ReplayAdapter),
 }
 
 // =============================================================================
@@ -178,7 +254,7 @@ impl ExchangeAdapter for AnyAdapter {
 /// All supported exchange adapter names.
 pub const SUPPORTED_EXCHANGES: &[&str] = &[
     "vest", "paradex", "lighter", "hyperliquid", "grvt", "reya",
-    "hotstuff", "pacifica", "extended", "nado", "nord", "ethereal", "dydx",
+    "hotstuff", "pacifica", "extended", "nado", "nord", "ethereal", "dydx", "backpack",
 ];
 
 /// Create an adapter from a config name string.
@@ -238,6 +314,10 @@ pub fn create_adapter(name: &str) -> ExchangeResult<AnyAdapter> {
             let config = DydxConfig::from_env();
             Ok(AnyAdapter::Dydx(DydxAdapter::new(config)))
         }
+        "backpack" => {
+            let config = BackpackConfig::from_env();
+            Ok(AnyAdapter::Backpack(BackpackAdapter::new(config)))
+        }
         _ => Err(ExchangeError::ConnectionFailed(format!(
             "Unknown exchange adapter: '{}'. Supported: {}",
             name,
@@ -246,26 +326,166 @@ pub fn create_adapter(name: &str) -> ExchangeResult<AnyAdapter> {
     }
 }
 
-/// Returns the default orderbook symbol for a given exchange + trading pair.
-pub fn resolve_symbol(exchange: &str, pair: &str) -> String {
-    match (exchange, pair) {
-        // BTC perpetuals
-        ("vest", "BTC") => "BTC-PERP".to_string(),
-        ("paradex", "BTC") => "BTC-USD-PERP".to_string(),
-        ("lighter", "BTC") => "BTC".to_string(),
-        // ETH perpetuals
-        ("vest", "ETH") => "ETH-PERP".to_string(),
-        ("paradex", "ETH") => "ETH-USD-PERP".to_string(),
-        ("lighter", "ETH") => "ETH".to_string(),
-        // SOL perpetuals
-        ("vest", "SOL") => "SOL-PERP".to_string(),
-        ("paradex", "SOL") => "SOL-USD-PERP".to_string(),
-        ("lighter", "SOL") => "SOL".to_string(),
-        // New exchanges — most use BASE-USD format
-        ("hyperliquid" | "grvt" | "reya" | "hotstuff" | "pacifica"
-        | "nado" | "nord" | "ethereal" | "dydx", base) => format!("{}-USD", base),
-        ("extended", base) => format!("{}-USD", base),
-        // Fallback: use pair as-is
-        _ => pair.to_string(),
+/// Market symbol template for an exchange.
+///
+/// `{base}` and `{quote}` are substituted by `resolve_symbol_with_quote()`.
+pub fn symbol_template(exchange: &str) -> Option<&'static str> {
+    match exchange {
+        "vest" => Some("{base}-PERP"),
+        "paradex" => Some("{base}-{quote}-PERP"),
+        "lighter" => Some("{base}"),
+        // Most newer exchanges use BASE-QUOTE format
+        "hyperliquid" | "grvt" | "reya" | "hotstuff" | "pacifica" | "extended" | "nado"
+        | "nord" | "ethereal" | "dydx" => Some("{base}-{quote}"),
+        // Backpack perps are all USDC-margined
+        "backpack" => Some("{base}_USDC_PERP"),
+        _ => None,
+    }
+}
+
+/// Returns the orderbook symbol for a given exchange + base asset + quote currency.
+pub fn resolve_symbol_with_quote(
+    exchange: &str,
+    base: &str,
+    quote: QuoteCurrency,
+) -> ExchangeResult<String> {
+    let template = symbol_template(exchange)
+        .ok_or_else(|| ExchangeError::UnknownExchange(exchange.to_string()))?;
+    Ok(template
+        .replace("{base}", base)
+        .replace("{quote}", quote.as_str()))
+}
+
+/// Returns the default (USD-quoted) orderbook symbol for a given exchange + base asset.
+pub fn resolve_symbol(exchange: &str, base: &str) -> ExchangeResult<String> {
+    resolve_symbol_with_quote(exchange, base, QuoteCurrency::Usd)
+}
+
+/// Per-exchange listings that don't follow the symbol template
+/// (e.g. PEPE listed as `1000PEPE`), keyed by exchange then base asset.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolOverrides {
+    by_exchange: HashMap<String, HashMap<String, String>>,
+}
+
+impl SymbolOverrides {
+    /// Create from an `{exchange: {base: symbol}}` map
+    pub fn new(by_exchange: HashMap<String, HashMap<String, String>>) -> Self {
+        Self { by_exchange }
+    }
+
+    /// Like [`resolve_symbol`], preferring an override when one is set
+    pub fn resolve(&self, exchange: &str, base: &str) -> ExchangeResult<String> {
+        self.resolve_with_quote(exchange, base, QuoteCurrency::Usd)
+    }
+
+    /// Like [`resolve_symbol_with_quote`], preferring an override when one is set.
+    ///
+    /// An override is the exchange's full symbol, so it applies whatever the quote.
+    pub fn resolve_with_quote(
+        &self,
+        exchange: &str,
+        base: &str,
+        quote: QuoteCurrency,
+    ) -> ExchangeResult<String> {
+        match self.by_exchange.get(exchange).and_then(|bases| bases.get(base)) {
+            Some(symbol) => Ok(symbol.clone()),
+            None => resolve_symbol_with_quote(exchange, base, quote),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_symbol_templates() {
+        let cases = [
+            ("vest", "BTC-PERP"),
+            ("paradex", "BTC-USD-PERP"),
+            ("lighter", "BTC"),
+            ("hyperliquid", "BTC-USD"),
+            ("grvt", "BTC-USD"),
+            ("reya", "BTC-USD"),
+            ("hotstuff", "BTC-USD"),
+            ("pacifica", "BTC-USD"),
+            ("extended", "BTC-USD"),
+            ("nado", "BTC-USD"),
+            ("nord", "BTC-USD"),
+            ("ethereal", "BTC-USD"),
+            ("dydx", "BTC-USD"),
+            ("backpack", "BTC_USDC_PERP"),
+        ];
+        for (exchange, expected) in cases {
+            assert_eq!(resolve_symbol(exchange, "BTC").unwrap(), expected, "{}", exchange);
+        }
+        // Every supported exchange has a template
+        for exchange in SUPPORTED_EXCHANGES {
+            assert!(symbol_template(exchange).is_some(), "{}", exchange);
+        }
+    }
+
+    #[test]
+    fn test_resolve_symbol_uses_quote_currency() {
+        assert_eq!(
+            resolve_symbol_with_quote("paradex", "ETH", QuoteCurrency::Usdc).unwrap(),
+            "ETH-USDC-PERP"
+        );
+        assert_eq!(
+            resolve_symbol_with_quote("dydx", "SOL", QuoteCurrency::Usdc).unwrap(),
+            "SOL-USDC"
+        );
+        // Vest markets carry no quote in the symbol
+        assert_eq!(
+            resolve_symbol_with_quote("vest", "SOL", QuoteCurrency::Usdc).unwrap(),
+            "SOL-PERP"
+        );
+    }
+
+    #[test]
+    fn test_concrete_accessors_match_variant() {
+        let mut vest = AnyAdapter::Vest(VestAdapter::new(VestConfig::default()));
+        let mut paradex = AnyAdapter::Paradex(ParadexAdapter::new(ParadexConfig::default()));
+
+        assert!(vest.as_vest_mut().is_some());
+        assert!(vest.as_paradex_mut().is_none());
+        assert!(paradex.as_vest_mut().is_none());
+        assert!(paradex.as_paradex_mut().is_some());
+    }
+
+    #[test]
+    fn test_capabilities_per_adapter() {
+        for &name in SUPPORTED_EXCHANGES {
+            let caps = create_adapter(name).unwrap().capabilities();
+            assert!(caps.orderbook, "{name}");
+            // Only Vest, Paradex and Backpack implement the REST snapshot fallback
+            assert_eq!(caps.rest_snapshot, matches!(name, "vest" | "paradex" | "backpack"), "{name}");
+            assert_eq!(caps.market_info, matches!(name, "vest" | "paradex"), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_symbol_override_takes_precedence() {
+        let overrides = SymbolOverrides::new(HashMap::from([(
+            "lighter".to_string(),
+            HashMap::from([("PEPE".to_string(), "1000PEPE".to_string())]),
+        )]));
+
+        assert_eq!(overrides.resolve("lighter", "PEPE").unwrap(), "1000PEPE");
+        assert_eq!(
+            overrides.resolve_with_quote("lighter", "PEPE", QuoteCurrency::Usdc).unwrap(),
+            "1000PEPE"
+        );
+        // Other bases and exchanges fall back to the template
+        assert_eq!(overrides.resolve("lighter", "BTC").unwrap(), "BTC");
+        assert_eq!(overrides.resolve("paradex", "PEPE").unwrap(), "PEPE-USD-PERP");
+        assert_eq!(SymbolOverrides::default().resolve("vest", "PEPE").unwrap(), "PEPE-PERP");
+    }
+
+    #[test]
+    fn test_resolve_symbol_unknown_exchange_errors() {
+        let err = resolve_symbol("binance", "BTC").unwrap_err();
+        assert!(matches!(err, ExchangeError::UnknownExchange(ref name) if name == "binance"));
     }
 }
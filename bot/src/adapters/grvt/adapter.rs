@@ -4,7 +4,7 @@
 //! JSON-RPC 2.0 protocol, subscribes to best bid/ask per instrument.
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -84,7 +84,7 @@ impl GrvtAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url();
         tracing::info!("Connecting to GRVT WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -101,11 +101,9 @@ impl GrvtAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let health = self.connection_health.clone_refs();
 
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
             Self::message_reader_loop(
@@ -113,9 +111,7 @@ impl GrvtAdapter {
                 shared_orderbooks,
                 shared_best_prices,
                 orderbook_notify,
-                last_pong,
-                last_data,
-                reader_alive,
+                health,
             )
             .await;
         });
@@ -177,17 +173,15 @@ impl GrvtAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!("GRVT message_reader_loop started");
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
         let mut msg_count: u64 = 0;
 
         while let Some(msg_result) = ws_receiver.next().await {
-            last_data.store(current_time_ms(), Ordering::Relaxed);
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
@@ -196,7 +190,7 @@ impl GrvtAdapter {
                         tracing::info!(exchange = "grvt", msg_count, raw = %text.chars().take(300).collect::<String>(), "RAW WS message");
                     }
 
-                    if let Ok(msg) = serde_json::from_str::<GrvtTickerMessage>(&text) {
+                    if let Ok(msg) = health.parse_stats.observe(serde_json::from_str::<GrvtTickerMessage>(&text)) {
                         // Subscription confirmation (has result, no params)
                         if msg.result.is_some() {
                             tracing::debug!("GRVT subscription confirmed (id={:?})", msg.id);
@@ -247,7 +241,7 @@ impl GrvtAdapter {
             }
         }
 
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("GRVT message reader loop ended");
     }
 
@@ -316,15 +310,13 @@ impl ExchangeAdapter for GrvtAdapter {
         self.subscribe_to_tickers().await?;
 
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "grvt", "GRVT WebSocket connected");
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         if let Some(handle) = self.reader_handle.take() { handle.abort(); }
         if let Some(handle) = self.heartbeat_handle.take() { handle.abort(); }
         if let Some(ws_sender) = self.ws_sender.take() {
@@ -381,7 +373,7 @@ impl ExchangeAdapter for GrvtAdapter {
 
     async fn reconnect(&mut self) -> ExchangeResult<()> {
         tracing::info!("GRVT: Initiating reconnection...");
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Reconnecting; }
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
         let saved = self.subscriptions.clone();
         self.disconnect().await?;
 
@@ -389,21 +381,25 @@ impl ExchangeAdapter for GrvtAdapter {
             let backoff_ms = std::cmp::min(500 * (1u64 << attempt), 5000);
             tracing::info!("GRVT: Reconnect attempt {}/3, waiting {}ms...", attempt + 1, backoff_ms);
             tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
-            { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Reconnecting; }
+            self.connection_health.set_state(ConnectionState::Reconnecting).await;
             match self.connect().await {
                 Ok(()) => {
-                    { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Connected; }
+                    self.connection_health.set_state(ConnectionState::Connected).await;
                     for symbol in &saved {
                         let _ = self.subscribe_orderbook(symbol).await;
                     }
                     tracing::info!("GRVT: Reconnection complete");
                     return Ok(());
                 }
+                Err(e) if e.is_maintenance() || e.is_fatal() => {
+                    self.connection_health.set_state(ConnectionState::Disconnected).await;
+                    return Err(e);
+                }
                 Err(e) => tracing::warn!("GRVT: Reconnect attempt {} failed: {}", attempt + 1, e),
             }
         }
 
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Disconnected; }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         Err(ExchangeError::ConnectionFailed("GRVT reconnection failed after max attempts".into()))
     }
 
@@ -411,6 +407,7 @@ impl ExchangeAdapter for GrvtAdapter {
     fn get_shared_orderbooks(&self) -> SharedOrderbooks { Arc::clone(&self.shared_orderbooks) }
     fn get_shared_best_prices(&self) -> SharedBestPrices { Arc::clone(&self.shared_best_prices) }
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) { self.orderbook_notify = Some(notify); }
+    fn connection_health(&self) -> &ConnectionHealth { &self.connection_health }
 }
 
 #[cfg(test)]
@@ -2,17 +2,23 @@
 //!
 //! Configuration for GRVT exchange WebSocket connection.
 
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 const MAINNET_WS_URL: &str = "wss://market-data.grvt.io/ws/full";
 
 /// Configuration for GRVT exchange connection (public market data)
 #[derive(Debug, Clone)]
 pub struct GrvtConfig {
     pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
 }
 
 impl Default for GrvtConfig {
     fn default() -> Self {
-        Self { production: true }
+        Self { production: true, extra_headers: HashMap::new() }
     }
 }
 
@@ -22,7 +28,7 @@ impl GrvtConfig {
             .unwrap_or_else(|_| "true".to_string())
             .parse::<bool>()
             .unwrap_or(true);
-        Self { production }
+        Self { production, extra_headers: extra_headers_from_env("GRVT_EXTRA_HEADERS") }
     }
 
     pub fn ws_url(&self) -> &str {
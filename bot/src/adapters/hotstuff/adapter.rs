@@ -1,7 +1,7 @@
 //! HotStuff Adapter — JSON-RPC 2.0, ticker channel per symbol
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -59,7 +59,7 @@ impl HotstuffAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url();
         tracing::info!("Connecting to HotStuff WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -73,13 +73,11 @@ impl HotstuffAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        let health = self.connection_health.clone_refs();
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, last_pong, last_data, reader_alive).await;
+            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, health).await;
         });
         self.reader_handle = Some(handle);
         Ok(())
@@ -110,17 +108,15 @@ impl HotstuffAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!("HotStuff message_reader_loop started");
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
         let mut msg_count: u64 = 0;
 
         while let Some(msg_result) = ws_receiver.next().await {
-            last_data.store(current_time_ms(), Ordering::Relaxed);
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
@@ -128,7 +124,7 @@ impl HotstuffAdapter {
                     if msg_count <= 3 {
                         tracing::info!(exchange = "hotstuff", msg_count, raw = %text.chars().take(300).collect::<String>(), "RAW WS message");
                     }
-                    if let Ok(msg) = serde_json::from_str::<HotstuffJsonRpcMessage>(&text) {
+                    if let Ok(msg) = health.parse_stats.observe(serde_json::from_str::<HotstuffJsonRpcMessage>(&text)) {
                         if msg.result.is_some() { continue; }
                         if let Some(params) = msg.params {
                             if let Some(data) = params.data {
@@ -157,7 +153,7 @@ impl HotstuffAdapter {
                 Err(e) => { tracing::error!("HotStuff WS error: {}", e); break; }
             }
         }
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("HotStuff message reader loop ended");
     }
 
@@ -198,12 +194,13 @@ impl ExchangeAdapter for HotstuffAdapter {
         self.spawn_heartbeat_task();
         self.subscribe_to_tickers().await?;
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "hotstuff", "HotStuff WebSocket connected");
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Disconnected; }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         if let Some(h) = self.reader_handle.take() { h.abort(); }
         if let Some(h) = self.heartbeat_handle.take() { h.abort(); }
         if let Some(s) = self.ws_sender.take() { let mut s = s.lock().await; let _ = s.close().await; }
@@ -246,18 +243,18 @@ impl ExchangeAdapter for HotstuffAdapter {
 
     async fn reconnect(&mut self) -> ExchangeResult<()> {
         tracing::info!("HotStuff: Reconnecting...");
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Reconnecting; }
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
         let saved = self.subscriptions.clone(); self.disconnect().await?;
         for attempt in 0..3u32 {
             let backoff = std::cmp::min(500 * (1u64 << attempt), 5000);
             tokio::time::sleep(Duration::from_millis(backoff)).await;
             if self.connect().await.is_ok() {
-                { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Connected; }
+                self.connection_health.set_state(ConnectionState::Connected).await;
                 for s in &saved { let _ = self.subscribe_orderbook(s).await; }
                 return Ok(());
             }
         }
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Disconnected; }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         Err(ExchangeError::ConnectionFailed("HotStuff reconnection failed".into()))
     }
 
@@ -265,6 +262,7 @@ impl ExchangeAdapter for HotstuffAdapter {
     fn get_shared_orderbooks(&self) -> SharedOrderbooks { Arc::clone(&self.shared_orderbooks) }
     fn get_shared_best_prices(&self) -> SharedBestPrices { Arc::clone(&self.shared_best_prices) }
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) { self.orderbook_notify = Some(notify); }
+    fn connection_health(&self) -> &ConnectionHealth { &self.connection_health }
 }
 
 #[cfg(test)]
@@ -1,13 +1,21 @@
 //! HotStuff Configuration
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 const MAINNET_WS_URL: &str = "wss://api.hotstuff.trade/ws/";
 
 #[derive(Debug, Clone)]
-pub struct HotstuffConfig { pub production: bool }
-impl Default for HotstuffConfig { fn default() -> Self { Self { production: true } } }
+pub struct HotstuffConfig {
+    pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+}
+impl Default for HotstuffConfig { fn default() -> Self { Self { production: true, extra_headers: HashMap::new() } } }
 impl HotstuffConfig {
     pub fn from_env() -> Self {
         let production = std::env::var("HOTSTUFF_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true);
-        Self { production }
+        Self { production, extra_headers: extra_headers_from_env("HOTSTUFF_EXTRA_HEADERS") }
     }
     pub fn ws_url(&self) -> &str { MAINNET_WS_URL }
 }
@@ -6,7 +6,7 @@
 //! Docs: https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -92,7 +92,7 @@ impl HyperliquidAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url();
         tracing::info!("Connecting to Hyperliquid WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -110,11 +110,9 @@ impl HyperliquidAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let health = self.connection_health.clone_refs();
 
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
             Self::message_reader_loop(
@@ -122,9 +120,7 @@ impl HyperliquidAdapter {
                 shared_orderbooks,
                 shared_best_prices,
                 orderbook_notify,
-                last_pong,
-                last_data,
-                reader_alive,
+                health,
             )
             .await;
         });
@@ -184,27 +180,26 @@ impl HyperliquidAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!("Hyperliquid message_reader_loop started");
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
 
         while let Some(msg_result) = ws_receiver.next().await {
-            last_data.store(current_time_ms(), Ordering::Relaxed);
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
                     tracing::trace!("Raw WS message: {}", text);
 
-                    match serde_json::from_str::<HyperliquidWsMessage>(&text) {
+                    match health.parse_stats.observe(serde_json::from_str::<HyperliquidWsMessage>(&text)) {
                         Ok(HyperliquidWsMessage::L2Book(book)) => {
                             let symbol = coin_to_symbol(&book.coin);
 
                             match book.to_orderbook() {
                                 Ok(orderbook) => {
+                                    health.ingest_latency.record("hyperliquid", orderbook.timestamp, current_time_ms());
                                     shared_best_prices.store(
                                         orderbook.best_bid().unwrap_or(0.0),
                                         orderbook.best_ask().unwrap_or(0.0),
@@ -229,14 +224,16 @@ impl HyperliquidAdapter {
                             }
                         }
                         Ok(HyperliquidWsMessage::Pong) => {
-                            last_pong.store(current_time_ms(), Ordering::Relaxed);
+                            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
                             tracing::trace!("Hyperliquid PONG received");
                         }
                         Ok(HyperliquidWsMessage::SubscriptionResponse(_)) => {
                             tracing::debug!("Hyperliquid subscription confirmed");
                         }
+                        Ok(HyperliquidWsMessage::Error(error)) => {
+                            tracing::warn!(error = %error, "Hyperliquid: request rejected");
+                        }
                         Err(_) => {
-                            // Silently ignore unknown messages (e.g. error responses)
                             tracing::trace!(
                                 message = %text,
                                 "Hyperliquid: unknown message format"
@@ -252,10 +249,11 @@ impl HyperliquidAdapter {
                 Ok(Message::Binary(data)) => {
                     if let Ok(text) = String::from_utf8(data) {
                         if let Ok(HyperliquidWsMessage::L2Book(book)) =
-                            serde_json::from_str::<HyperliquidWsMessage>(&text)
+                            health.parse_stats.observe(serde_json::from_str::<HyperliquidWsMessage>(&text))
                         {
                             let symbol = coin_to_symbol(&book.coin);
                             if let Ok(orderbook) = book.to_orderbook() {
+                                health.ingest_latency.record("hyperliquid", orderbook.timestamp, current_time_ms());
                                 shared_best_prices.store(
                                     orderbook.best_bid().unwrap_or(0.0),
                                     orderbook.best_ask().unwrap_or(0.0),
@@ -277,7 +275,7 @@ impl HyperliquidAdapter {
             }
         }
 
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("Hyperliquid message reader loop ended");
     }
 
@@ -350,16 +348,14 @@ impl ExchangeAdapter for HyperliquidAdapter {
         self.subscribe_to_l2books().await?;
 
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "hyperliquid", "Hyperliquid WebSocket connected");
 
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         if let Some(handle) = self.reader_handle.take() {
             handle.abort();
@@ -455,10 +451,7 @@ impl ExchangeAdapter for HyperliquidAdapter {
     async fn reconnect(&mut self) -> ExchangeResult<()> {
         tracing::info!("Hyperliquid: Initiating reconnection...");
 
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Reconnecting;
-        }
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
         let saved_subscriptions = self.subscriptions.clone();
         self.disconnect().await?;
@@ -477,17 +470,11 @@ impl ExchangeAdapter for HyperliquidAdapter {
 
             tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
 
-            {
-                let mut state = self.connection_health.state.write().await;
-                *state = ConnectionState::Reconnecting;
-            }
+            self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
             match self.connect().await {
                 Ok(()) => {
-                    {
-                        let mut state = self.connection_health.state.write().await;
-                        *state = ConnectionState::Connected;
-                    }
+                    self.connection_health.set_state(ConnectionState::Connected).await;
 
                     for symbol in &saved_subscriptions {
                         if let Err(e) = self.subscribe_orderbook(symbol).await {
@@ -506,6 +493,12 @@ impl ExchangeAdapter for HyperliquidAdapter {
 
                     return Ok(());
                 }
+                Err(e) if e.is_maintenance() || e.is_fatal() => {
+                    // Not an attempt: the caller waits out the maintenance
+                    // window, or gives up on a fatal error
+                    self.connection_health.set_state(ConnectionState::Disconnected).await;
+                    return Err(e);
+                }
                 Err(e) => {
                     tracing::warn!("Hyperliquid: Reconnect attempt {} failed: {}", attempt + 1, e);
                     last_error = Some(e);
@@ -513,10 +506,7 @@ impl ExchangeAdapter for HyperliquidAdapter {
             }
         }
 
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         Err(last_error.unwrap_or_else(|| {
             ExchangeError::ConnectionFailed("Reconnection failed after max attempts".into())
@@ -538,6 +528,10 @@ impl ExchangeAdapter for HyperliquidAdapter {
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
         self.orderbook_notify = Some(notify);
     }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
 }
 
 // =============================================================================
@@ -2,6 +2,10 @@
 //!
 //! Configuration for Hyperliquid exchange WebSocket connection.
 
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -18,11 +22,13 @@ const MAINNET_WS_URL: &str = "wss://api.hyperliquid.xyz/ws";
 pub struct HyperliquidConfig {
     /// Use production endpoints
     pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
 }
 
 impl Default for HyperliquidConfig {
     fn default() -> Self {
-        Self { production: true }
+        Self { production: true, extra_headers: HashMap::new() }
     }
 }
 
@@ -34,7 +40,7 @@ impl HyperliquidConfig {
             .parse::<bool>()
             .unwrap_or(true);
 
-        Self { production }
+        Self { production, extra_headers: extra_headers_from_env("HYPERLIQUID_EXTRA_HEADERS") }
     }
 
     /// Get WebSocket URL
@@ -110,6 +110,9 @@ pub(crate) enum HyperliquidWsMessage {
     /// Subscription confirmation
     #[serde(rename = "subscriptionResponse")]
     SubscriptionResponse(serde_json::Value),
+    /// Rejected request, e.g. an unknown coin
+    #[serde(rename = "error")]
+    Error(serde_json::Value),
 }
 
 // =============================================================================
@@ -0,0 +1,182 @@
+//! Simulated-latency adapter wrapper for tests
+//!
+//! `LatencyInjector` delegates to an inner adapter but sleeps before every
+//! network-bound call (connect, subscribe, REST snapshot, reconnect), so a
+//! test can make one leg deterministically slower than the other. Reads of
+//! cached or shared state are never delayed.
+//!
+//! Only compiled for tests or with the `testing` feature.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::adapters::errors::ExchangeResult;
+use crate::adapters::traits::ExchangeAdapter;
+use crate::adapters::types::{Capabilities, ConnectionHealth, MarketInfo, Orderbook};
+use crate::core::channels::{BookTap, OrderbookNotify, SharedBestPrices, SharedOrderbooks};
+
+/// Adapter wrapper that adds a fixed delay to network-bound calls
+pub struct LatencyInjector<A> {
+    inner: A,
+    delay: Duration,
+}
+
+impl<A: ExchangeAdapter> LatencyInjector<A> {
+    /// Wrap `inner`, delaying each network-bound call by `delay`
+    pub fn new(inner: A, delay: Duration) -> Self {
+        Self { inner, delay }
+    }
+
+    /// Change the injected delay
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.delay = delay;
+    }
+
+    /// The wrapped adapter
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Unwrap, returning the inner adapter
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+
+    async fn lag(&self) {
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+    }
+}
+
+#[async_trait]
+impl<A: ExchangeAdapter> ExchangeAdapter for LatencyInjector<A> {
+    async fn connect(&mut self) -> ExchangeResult<()> {
+        self.lag().await;
+        self.inner.connect().await
+    }
+
+    async fn disconnect(&mut self) -> ExchangeResult<()> {
+        self.inner.disconnect().await
+    }
+
+    async fn subscribe_orderbook(&mut self, symbol: &str) -> ExchangeResult<()> {
+        self.lag().await;
+        self.inner.subscribe_orderbook(symbol).await
+    }
+
+    async fn unsubscribe_orderbook(&mut self, symbol: &str) -> ExchangeResult<()> {
+        self.lag().await;
+        self.inner.unsubscribe_orderbook(symbol).await
+    }
+
+    fn get_orderbook(&self, symbol: &str) -> Option<&Orderbook> {
+        self.inner.get_orderbook(symbol)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.inner.is_connected()
+    }
+
+    fn is_stale(&self) -> bool {
+        self.inner.is_stale()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.inner.capabilities()
+    }
+
+    async fn fetch_orderbook_snapshot(&self, symbol: &str) -> ExchangeResult<Orderbook> {
+        self.lag().await;
+        self.inner.fetch_orderbook_snapshot(symbol).await
+    }
+
+    async fn fetch_market_info(&self, symbol: &str) -> ExchangeResult<MarketInfo> {
+        self.lag().await;
+        self.inner.fetch_market_info(symbol).await
+    }
+
+    async fn sync_orderbooks(&mut self) {
+        self.inner.sync_orderbooks().await
+    }
+
+    async fn reconnect(&mut self) -> ExchangeResult<()> {
+        self.lag().await;
+        self.inner.reconnect().await
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        self.inner.exchange_name()
+    }
+
+    fn get_shared_orderbooks(&self) -> SharedOrderbooks {
+        self.inner.get_shared_orderbooks()
+    }
+
+    fn get_shared_best_prices(&self) -> SharedBestPrices {
+        self.inner.get_shared_best_prices()
+    }
+
+    fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
+        self.inner.set_orderbook_notify(notify)
+    }
+
+    fn set_book_tap(&mut self, tap: BookTap) -> bool {
+        self.inner.set_book_tap(tap)
+    }
+
+    fn lost_subscriptions(&self) -> Vec<String> {
+        self.inner.lost_subscriptions()
+    }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        self.inner.connection_health()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::stub::StubAdapter;
+
+    #[tokio::test]
+    async fn test_injector_delays_network_calls() {
+        let mut adapter = LatencyInjector::new(StubAdapter::new(), Duration::from_millis(100));
+
+        let started = tokio::time::Instant::now();
+        adapter.connect().await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert!(adapter.is_connected());
+
+        let started = tokio::time::Instant::now();
+        adapter.fetch_orderbook_snapshot("BTC-PERP").await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(100));
+
+        // Cached reads and disconnect are not delayed
+        let started = tokio::time::Instant::now();
+        assert_eq!(adapter.exchange_name(), "stub");
+        adapter.disconnect().await.unwrap();
+        assert!(started.elapsed() < Duration::from_millis(50));
+    }
+
+    #[tokio::test]
+    async fn test_slow_leg_finishes_after_fast_leg() {
+        let mut fast = LatencyInjector::new(StubAdapter::new(), Duration::from_millis(10));
+        let mut slow = LatencyInjector::new(StubAdapter::new(), Duration::from_millis(100));
+
+        let started = tokio::time::Instant::now();
+        let (fast_done, slow_done) = tokio::join!(
+            async {
+                fast.subscribe_orderbook("BTC-PERP").await.unwrap();
+                started.elapsed()
+            },
+            async {
+                slow.subscribe_orderbook("BTC-PERP").await.unwrap();
+                started.elapsed()
+            },
+        );
+        assert!(fast_done < slow_done);
+        assert!(slow_done >= Duration::from_millis(100));
+    }
+}
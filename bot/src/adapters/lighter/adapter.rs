@@ -10,15 +10,12 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use futures_util::{SinkExt, StreamExt};
 use tokio::sync::{Mutex, RwLock};
-use tokio_tungstenite::{
-    connect_async,
-    tungstenite::protocol::Message,
-};
+use tokio_tungstenite::tungstenite::protocol::Message;
 
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
 use crate::adapters::traits::ExchangeAdapter;
 use crate::adapters::types::{
-    create_http_client, next_subscription_id, ConnectionHealth, ConnectionState,
+    create_http_client_with_headers, next_subscription_id, ConnectionHealth, ConnectionState,
     Orderbook, OrderbookLevel, MAX_ORDERBOOK_DEPTH, STALE_THRESHOLD_MS,
 };
 use crate::core::channels::{AtomicBestPrices, OrderbookNotify, SharedBestPrices, SharedOrderbooks};
@@ -67,7 +64,7 @@ pub struct LighterAdapter {
 impl LighterAdapter {
     /// Create a new LighterAdapter with the given configuration
     pub fn new(config: LighterConfig) -> Self {
-        let http = create_http_client("lighter");
+        let http = create_http_client_with_headers("lighter", &config.extra_headers);
         let health = ConnectionHealth::new();
         let last_data = Arc::clone(&health.last_data);
 
@@ -87,7 +84,7 @@ impl LighterAdapter {
     }
 
     /// Fetch exchange/market info from REST API (v1 orderBookDetails)
-    async fn fetch_market_info(&mut self) -> ExchangeResult<()> {
+    async fn load_markets(&mut self) -> ExchangeResult<()> {
         let url = format!("{}/api/v1/orderBookDetails", self.config.rest_url());
         let resp = self.http.get(&url).send().await.map_err(|e| {
             ExchangeError::ConnectionFailed(format!("Failed to fetch market info: {}", e))
@@ -389,13 +386,11 @@ impl ExchangeAdapter for LighterAdapter {
         tracing::info!(exchange = "lighter", url = %self.config.rest_url(), "Connecting…");
 
         // 1. Fetch market info (needed for symbol → market_id mapping)
-        self.fetch_market_info().await?;
+        self.load_markets().await?;
 
         // 2. Connect WebSocket (public orderbook, no auth needed)
         let ws_url = self.config.ws_url();
-        let (ws_stream, _) = connect_async(ws_url).await.map_err(|e| {
-            ExchangeError::ConnectionFailed(format!("WebSocket connect failed: {}", e))
-        })?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(ws_url, &self.config.extra_headers).await?;
         let (sink, reader) = ws_stream.split();
 
         {
@@ -407,10 +402,7 @@ impl ExchangeAdapter for LighterAdapter {
         self.spawn_reader(reader);
 
         // 4. Mark connected
-        {
-            let mut state = self.health.state.write().await;
-            *state = ConnectionState::Connected;
-        }
+        self.health.set_state(ConnectionState::Connected).await;
 
         // Update last_data to now
         let now_ms = SystemTime::now()
@@ -436,8 +428,7 @@ impl ExchangeAdapter for LighterAdapter {
             *sink_guard = None;
         }
 
-        let mut state = self.health.state.write().await;
-        *state = ConnectionState::Disconnected;
+        self.health.set_state(ConnectionState::Disconnected).await;
         tracing::info!(exchange = "lighter", "Disconnected");
         Ok(())
     }
@@ -599,4 +590,8 @@ impl ExchangeAdapter for LighterAdapter {
     fn set_orderbook_notify(&mut self, notify: crate::core::channels::OrderbookNotify) {
         self.orderbook_notify = Some(notify);
     }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.health
+    }
 }
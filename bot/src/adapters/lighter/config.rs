@@ -2,6 +2,10 @@
 //!
 //! Configuration for Lighter exchange connection including environment loading.
 
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 // =============================================================================
 // Constants
 // =============================================================================
@@ -27,11 +31,13 @@ const TESTNET_WS_URL: &str = "wss://testnet.zklighter.elliot.ai/stream";
 pub struct LighterConfig {
     /// Use production endpoints
     pub production: bool,
+    /// Extra headers sent on the REST requests and the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
 }
 
 impl Default for LighterConfig {
     fn default() -> Self {
-        Self { production: true }
+        Self { production: true, extra_headers: HashMap::new() }
     }
 }
 
@@ -43,7 +49,7 @@ impl LighterConfig {
             .parse::<bool>()
             .unwrap_or(true);
 
-        Self { production }
+        Self { production, extra_headers: extra_headers_from_env("LIGHTER_EXTRA_HEADERS") }
     }
 
     /// Get REST API base URL
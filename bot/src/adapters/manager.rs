@@ -4,15 +4,134 @@
 //! a shared `tokio::broadcast` channel by reading from the adapter's
 //! `AtomicBestPrices` (lock-free hot path).
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{error, info, warn};
 
-use crate::adapters::{ExchangeAdapter, create_adapter, resolve_symbol};
+use crate::adapters::replay::{RecordingLayer, ReplayAdapter, ReplayPace};
+use crate::adapters::shared::{MaintenanceBackoff, ReconnectStagger};
+use crate::adapters::types::{ConsolidatedOrderbook, CrossedBookPolicy, Orderbook};
+use crate::adapters::{
+    AnyAdapter, ExchangeAdapter, ExchangeError, ExchangeResult, SharedOrderbooks, SymbolOverrides, create_adapter,
+};
+use crate::core::channels::AtomicBestPrices;
+use crate::core::metrics::Metrics;
+use crate::core::shutdown::ShutdownListener;
 use crate::core::types::{current_time_ms, PriceData};
 
+/// An adapter's orderbook storage, published for cross-exchange views
+struct Feed {
+    books: SharedOrderbooks,
+    /// Cleared while the adapter is disconnected or stale
+    healthy: Arc<AtomicBool>,
+    /// Exchange symbols whose subscription was lost on the last reconnect
+    lost: Arc<std::sync::Mutex<HashSet<String>>>,
+}
+
+impl Feed {
+    /// Whether `exchange_symbol`'s leg is streaming: adapter healthy and
+    /// subscription in place
+    fn is_live(&self, exchange_symbol: &str) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+            && !self.lost.lock().unwrap_or_else(|e| e.into_inner()).contains(exchange_symbol)
+    }
+}
+
+/// Feeds of the running adapters, keyed by exchange name
+type FeedRegistry = Arc<RwLock<HashMap<String, Feed>>>;
+
+/// Read access to the running adapters' books, cheap to clone into other tasks
+#[derive(Clone)]
+pub struct BookReader {
+    feeds: FeedRegistry,
+    symbol_overrides: Arc<SymbolOverrides>,
+}
+
+impl BookReader {
+    /// `exchange`'s current book for `base` (e.g. "BTC"), `None` while the
+    /// leg is not healthy (see [`Self::is_healthy`]) or the book has not
+    /// arrived yet.
+    pub async fn book(&self, exchange: &str, base: &str) -> Option<Orderbook> {
+        let feeds = self.feeds.read().await;
+        let exchange_symbol = self.symbol_overrides.resolve(exchange, base).ok()?;
+        let feed = feeds.get(exchange).filter(|feed| feed.is_live(&exchange_symbol))?;
+        let book = feed.books.read().await.get(&exchange_symbol).cloned();
+        book
+    }
+
+    /// Exchange time of `exchange`'s latest `base` book, `None` while the leg
+    /// is not healthy or the exchange does not stamp its books
+    pub async fn exchange_time_ms(&self, exchange: &str, base: &str) -> Option<u64> {
+        let feeds = self.feeds.read().await;
+        let exchange_symbol = self.symbol_overrides.resolve(exchange, base).ok()?;
+        let feed = feeds.get(exchange).filter(|feed| feed.is_live(&exchange_symbol))?;
+        let timestamp = feed.books.read().await.get(&exchange_symbol)?.timestamp;
+        (timestamp > 0).then_some(timestamp)
+    }
+
+    /// Whether `exchange`'s `base` leg is streaming: false while the adapter
+    /// reconnects or is stale, after its subscription was lost on a
+    /// reconnect, and for exchanges that are not running
+    pub async fn is_healthy(&self, exchange: &str, base: &str) -> bool {
+        let Ok(exchange_symbol) = self.symbol_overrides.resolve(exchange, base) else {
+            return false;
+        };
+        self.feeds
+            .read()
+            .await
+            .get(exchange)
+            .is_some_and(|feed| feed.is_live(&exchange_symbol))
+    }
+}
+
+/// Where the adapters get their orderbooks from
+#[derive(Clone)]
+enum FeedSource {
+    /// Connect to the exchanges, optionally recording every book change
+    Live { recorder: Option<Arc<RecordingLayer>> },
+    /// Play back a recording made with a [`RecordingLayer`]
+    Replay { path: PathBuf, pace: ReplayPace },
+}
+
+impl FeedSource {
+    fn create_adapter(&self, exchange: &str) -> ExchangeResult<AnyAdapter> {
+        match self {
+            FeedSource::Live { .. } => create_adapter(exchange),
+            FeedSource::Replay { path, pace } => {
+                ReplayAdapter::new(exchange, path.clone(), *pace).map(AnyAdapter::Replay)
+            }
+        }
+    }
+
+    fn recorder(&self) -> Option<&RecordingLayer> {
+        match self {
+            FeedSource::Live { recorder } => recorder.as_deref(),
+            FeedSource::Replay { .. } => None,
+        }
+    }
+}
+
+/// Everything one adapter task needs besides its shutdown listener,
+/// cloned from the manager for each exchange
+#[derive(Clone)]
+struct AdapterTaskConfig {
+    exchange: String,
+    symbols: Vec<String>,
+    price_tx: broadcast::Sender<PriceData>,
+    poll_ms: u64,
+    metrics: Option<Arc<Metrics>>,
+    maintenance: MaintenanceBackoff,
+    feeds: FeedRegistry,
+    overrides: Arc<SymbolOverrides>,
+    source: FeedSource,
+    crossed_book_policy: CrossedBookPolicy,
+    reconnect_stagger: ReconnectStagger,
+}
+
 /// Manages multiple exchange adapters and emits price data.
 pub struct ExchangeManager {
     /// Broadcast sender for price data
@@ -23,6 +142,22 @@ pub struct ExchangeManager {
     symbols: Vec<String>,
     /// Poll interval in milliseconds
     poll_interval_ms: u64,
+    /// Stops the adapter tasks (and disconnects adapters) when triggered
+    shutdown: Option<ShutdownListener>,
+    /// Connection up/down gauges, when metrics are exported
+    metrics: Option<Arc<Metrics>>,
+    /// Fixed reconnect delay while an exchange reports maintenance
+    maintenance: MaintenanceBackoff,
+    /// Orderbooks of the running adapters, for [`Self::consolidated_book`]
+    feeds: FeedRegistry,
+    /// Exchange symbols that don't follow the per-exchange template
+    symbol_overrides: Arc<SymbolOverrides>,
+    /// Live exchanges (the default) or a recorded session
+    source: FeedSource,
+    /// Handling of quotes with best bid above best ask
+    crossed_book_policy: CrossedBookPolicy,
+    /// Shared by the adapter tasks so their reconnects start spaced apart
+    reconnect_stagger: ReconnectStagger,
 }
 
 impl ExchangeManager {
@@ -41,6 +176,14 @@ impl ExchangeManager {
             exchanges,
             symbols,
             poll_interval_ms: 100,
+            shutdown: None,
+            metrics: None,
+            maintenance: MaintenanceBackoff::default(),
+            feeds: Arc::new(RwLock::new(HashMap::new())),
+            symbol_overrides: Arc::new(SymbolOverrides::default()),
+            source: FeedSource::Live { recorder: None },
+            crossed_book_policy: CrossedBookPolicy::default(),
+            reconnect_stagger: ReconnectStagger::default(),
         }
     }
 
@@ -50,6 +193,64 @@ impl ExchangeManager {
         self
     }
 
+    /// Stop adapter tasks when `listener` fires.
+    ///
+    /// Without this, adapter tasks only stop when aborted.
+    pub fn with_shutdown(mut self, listener: ShutdownListener) -> Self {
+        self.shutdown = Some(listener);
+        self
+    }
+
+    /// Report each adapter's connection state into `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Set the reconnect delay used during maintenance windows (default 60s).
+    pub fn with_maintenance_backoff(mut self, maintenance: MaintenanceBackoff) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
+    /// Subscribe to these symbols instead of the template where listed.
+    pub fn with_symbol_overrides(mut self, overrides: SymbolOverrides) -> Self {
+        self.symbol_overrides = Arc::new(overrides);
+        self
+    }
+
+    /// Append every orderbook the adapters write to `recorder`.
+    ///
+    /// Adapters without a tap point (see [`ExchangeAdapter::set_book_tap`])
+    /// are recorded each time their top of book changes instead. Ignored in
+    /// replay mode.
+    pub fn with_recorder(mut self, recorder: Arc<RecordingLayer>) -> Self {
+        if let FeedSource::Live { recorder: slot } = &mut self.source {
+            *slot = Some(recorder);
+        }
+        self
+    }
+
+    /// Set how crossed quotes are handled (default: discard).
+    pub fn with_crossed_book_policy(mut self, policy: CrossedBookPolicy) -> Self {
+        self.crossed_book_policy = policy;
+        self
+    }
+
+    /// Space adapter reconnect starts with `stagger` (default: at least
+    /// `ReconnectConfig::default().initial_delay_ms` apart).
+    pub fn with_reconnect_stagger(mut self, stagger: ReconnectStagger) -> Self {
+        self.reconnect_stagger = stagger;
+        self
+    }
+
+    /// Play back the recording at `path` instead of connecting to the
+    /// exchanges. Each adapter replays its own exchange's books.
+    pub fn with_replay(mut self, path: impl Into<PathBuf>, pace: ReplayPace) -> Self {
+        self.source = FeedSource::Replay { path: path.into(), pace };
+        self
+    }
+
     /// Connect all adapters and start streaming prices.
     ///
     /// Returns a map of exchange → JoinHandle for monitoring.
@@ -60,13 +261,23 @@ impl ExchangeManager {
         let mut handles = HashMap::new();
 
         for exchange_name in &self.exchanges {
-            let name = exchange_name.clone();
-            let symbols = self.symbols.clone();
-            let price_tx = self.price_tx.clone();
-            let poll_ms = self.poll_interval_ms;
+            let task = AdapterTaskConfig {
+                exchange: exchange_name.clone(),
+                symbols: self.symbols.clone(),
+                price_tx: self.price_tx.clone(),
+                poll_ms: self.poll_interval_ms,
+                metrics: self.metrics.clone(),
+                maintenance: self.maintenance,
+                feeds: self.feeds.clone(),
+                overrides: self.symbol_overrides.clone(),
+                source: self.source.clone(),
+                crossed_book_policy: self.crossed_book_policy,
+                reconnect_stagger: self.reconnect_stagger.clone(),
+            };
+            let shutdown = self.shutdown.clone();
 
             let handle = tokio::spawn(async move {
-                Self::run_adapter(name, symbols, price_tx, poll_ms).await;
+                Self::run_adapter(task, shutdown).await;
             });
 
             handles.insert(exchange_name.clone(), handle);
@@ -81,35 +292,163 @@ impl ExchangeManager {
         handles
     }
 
+    /// Handle for reading single exchange books from other tasks.
+    pub fn book_reader(&self) -> BookReader {
+        BookReader { feeds: self.feeds.clone(), symbol_overrides: self.symbol_overrides.clone() }
+    }
+
+    /// Merge `base` (e.g. "BTC") from every healthy adapter into one ladder.
+    ///
+    /// Each exchange's book is looked up under its own symbol name.
+    /// Disconnected or stale adapters, and exchanges that do not list
+    /// `base`, are left out.
+    pub async fn consolidated_book(&self, base: &str) -> ConsolidatedOrderbook {
+        let feeds = self.feeds.read().await;
+        let mut books = Vec::new();
+        for (exchange, feed) in feeds.iter() {
+            let Ok(exchange_symbol) = self.symbol_overrides.resolve(exchange, base) else {
+                continue;
+            };
+            if !feed.is_live(&exchange_symbol) {
+                continue;
+            }
+            if let Some(book) = feed.books.read().await.get(&exchange_symbol) {
+                books.push((exchange.as_str(), book.clone()));
+            }
+        }
+        // Sort by exchange so levels at equal prices come out in a stable order
+        books.sort_by(|a, b| a.0.cmp(b.0));
+        ConsolidatedOrderbook::merge(books.iter().map(|(exchange, book)| (*exchange, book)))
+    }
+
     /// Run a single adapter: connect → subscribe → poll prices → emit PriceData.
-    async fn run_adapter(
-        exchange: String,
-        symbols: Vec<String>,
-        price_tx: broadcast::Sender<PriceData>,
-        poll_ms: u64,
-    ) {
-        info!(exchange = %exchange, "Starting adapter");
+    ///
+    /// A maintenance response (see [`MaintenanceBackoff`]) on connect or
+    /// reconnect logs `MAINTENANCE_DETECTED` and waits the fixed maintenance
+    /// delay instead of failing or advancing the exponential backoff. Other
+    /// retryable errors back off exponentially; a fatal error (see
+    /// [`crate::adapters::ExchangeError::is_fatal`]) stops the adapter.
+    ///
+    /// With a recorder set, every book the adapter writes is recorded through
+    /// its book tap (or, without one, each time its top of book changes); the
+    /// recorder is flushed on shutdown.
+    ///
+    /// A crossed quote logs `CROSSED_BOOK`, counts towards
+    /// `arbi_crossed_books_total` and is dropped or clamped per
+    /// `crossed_book_policy`, both in the emitted price and in the shared
+    /// book (see [`CrossedBookPolicy::repair`]).
+    ///
+    /// Reconnect starts are spaced by `reconnect_stagger` across adapters,
+    /// so legs that dropped together do not all hit their exchanges at once.
+    /// The leg reads as unhealthy (see [`BookReader::is_healthy`]) until it
+    /// is back, which holds its opportunities in the pipeline.
+    async fn run_adapter(task: AdapterTaskConfig, shutdown: Option<ShutdownListener>) {
+        info!(exchange = %task.exchange, "Starting adapter");
 
         // Create the adapter
-        let mut adapter = match create_adapter(&exchange) {
+        let adapter = match task.source.create_adapter(&task.exchange) {
             Ok(a) => a,
             Err(e) => {
-                error!(exchange = %exchange, error = %e, "Failed to create adapter");
+                error!(exchange = %task.exchange, error = %e, "Failed to create adapter");
                 return;
             }
         };
+        Self::drive_adapter(adapter, task, shutdown).await;
+    }
 
-        // Connect
-        if let Err(e) = adapter.connect().await {
-            error!(exchange = %exchange, error = %e, "Failed to connect");
-            return;
+    /// Body of [`Self::run_adapter`] once the adapter exists
+    async fn drive_adapter<A: ExchangeAdapter>(
+        mut adapter: A,
+        task: AdapterTaskConfig,
+        mut shutdown: Option<ShutdownListener>,
+    ) {
+        let AdapterTaskConfig {
+            exchange,
+            symbols,
+            price_tx,
+            poll_ms,
+            metrics,
+            maintenance,
+            feeds,
+            overrides,
+            source,
+            crossed_book_policy,
+            reconnect_stagger,
+        } = task;
+        let healthy = Arc::new(AtomicBool::new(false));
+        let set_up = |up: bool| {
+            healthy.store(up, Ordering::Relaxed);
+            if let Some(m) = &metrics {
+                m.set_connected(&exchange, up);
+            }
+        };
+        set_up(false);
+
+        let lost = Arc::new(std::sync::Mutex::new(HashSet::new()));
+        feeds.write().await.insert(
+            exchange.clone(),
+            Feed { books: adapter.get_shared_orderbooks(), healthy: healthy.clone(), lost: lost.clone() },
+        );
+        // Raw feed recording, drained into the recorder on every poll
+        let mut book_tap = None;
+        if source.recorder().is_some() {
+            let (tap, taps) = tokio::sync::mpsc::unbounded_channel();
+            if adapter.set_book_tap(tap) {
+                book_tap = Some(taps);
+            }
+        }
+        let capabilities = adapter.capabilities();
+        info!(
+            exchange = %exchange,
+            orderbook = capabilities.orderbook,
+            rest_snapshot = capabilities.rest_snapshot,
+            market_info = capabilities.market_info,
+            "Adapter capabilities"
+        );
+
+        const MAX_RECONNECT_BACKOFF_MS: u64 = 60_000;
+
+        // Connect, waiting out maintenance windows and retrying transient
+        // failures; a fatal error stops the adapter
+        let mut connect_backoff_ms: u64 = 1_000;
+        loop {
+            let Err(e) = adapter.connect().await else {
+                break;
+            };
+            let delay = if let Some(delay) = maintenance.delay_for(&e) {
+                log_maintenance(&exchange, &e, delay);
+                delay
+            } else if e.is_retryable() {
+                warn!(
+                    exchange = %exchange,
+                    error = %e,
+                    retry_in_ms = connect_backoff_ms,
+                    "Failed to connect, retrying after backoff..."
+                );
+                let delay = tokio::time::Duration::from_millis(connect_backoff_ms);
+                connect_backoff_ms = (connect_backoff_ms * 2).min(MAX_RECONNECT_BACKOFF_MS);
+                delay
+            } else {
+                error!(exchange = %exchange, error = %e, "Failed to connect (fatal)");
+                return;
+            };
+            if wait_or_shutdown(&mut shutdown, delay).await {
+                return;
+            }
         }
 
         info!(exchange = %exchange, "Connected");
+        set_up(true);
 
         // Subscribe to all symbols (using exchange-specific symbol names)
         for symbol in &symbols {
-            let exchange_symbol = resolve_symbol(&exchange, symbol);
+            let exchange_symbol = match overrides.resolve(&exchange, symbol) {
+                Ok(s) => s,
+                Err(e) => {
+                    warn!(exchange = %exchange, symbol = %symbol, error = %e, "Cannot resolve symbol — skipping");
+                    continue;
+                }
+            };
             if let Err(e) = adapter.subscribe_orderbook(&exchange_symbol).await {
                 warn!(
                     exchange = %exchange,
@@ -126,6 +465,21 @@ impl ExchangeManager {
                     "Subscribed"
                 );
             }
+
+            // Fetched once per leg at startup; the adapter caches it
+            if capabilities.market_info {
+                match adapter.fetch_market_info(&exchange_symbol).await {
+                    Ok(market) => info!(
+                        exchange = %exchange,
+                        symbol = %symbol,
+                        tick_size = market.tick_size,
+                        size_step = market.size_step,
+                        min_notional = market.min_notional,
+                        "Market info"
+                    ),
+                    Err(e) => warn!(exchange = %exchange, symbol = %symbol, error = %e, "Failed to fetch market info"),
+                }
+            }
         }
 
         // Read prices from the adapter's AtomicBestPrices (lock-free)
@@ -136,40 +490,125 @@ impl ExchangeManager {
         // Track previously seen bid/ask per symbol to avoid duplicate emissions.
         let mut last_seen: HashMap<String, (f64, f64)> = HashMap::new();
 
+        // Last uncrossed book per exchange symbol, put back over a crossed
+        // one under the discard policy
+        let mut last_good: HashMap<String, Orderbook> = HashMap::new();
+
+        // REST books fetched when the feed went stale, re-seeded into shared
+        // storage until the WebSocket delivers fresh data again
+        let mut stale_snapshots: HashMap<String, Orderbook> = HashMap::new();
+
         // Track reconnection backoff
         let mut reconnect_backoff_ms: u64 = 1_000;
-        const MAX_RECONNECT_BACKOFF_MS: u64 = 60_000;
 
         info!(exchange = %exchange, "Entering price poll loop");
 
         loop {
-            tokio::time::sleep(poll_duration).await;
+            tokio::select! {
+                _ = tokio::time::sleep(poll_duration) => {}
+                _ = async {
+                    match shutdown.as_mut() {
+                        Some(listener) => listener.wait().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    info!(exchange = %exchange, "Shutdown requested, disconnecting");
+                    set_up(false);
+                    if let Some(recorder) = source.recorder() {
+                        drain_book_tap(recorder, &exchange, book_tap.as_mut()).await;
+                        if let Err(e) = recorder.flush().await {
+                            warn!(exchange = %exchange, error = %e, "Failed to flush orderbook recording");
+                        }
+                    }
+                    if let Err(e) = adapter.disconnect().await {
+                        warn!(exchange = %exchange, error = %e, "Disconnect failed during shutdown");
+                    }
+                    return;
+                }
+            }
+
+            if let Some(recorder) = source.recorder() {
+                drain_book_tap(recorder, &exchange, book_tap.as_mut()).await;
+            }
 
             // Check connection health — also detect stale (silent disconnect)
             if !adapter.is_connected() || adapter.is_stale() {
                 let reason = if !adapter.is_connected() { "disconnected" } else { "stale (no data)" };
+                if adapter.is_connected() {
+                    adapter.connection_health().report_stale();
+                    if capabilities.rest_snapshot && stale_snapshots.is_empty() {
+                        stale_snapshots = fetch_snapshots(&adapter, &exchange, &symbols, &overrides).await;
+                    }
+                }
                 warn!(exchange = %exchange, reason = %reason, "Adapter unhealthy, attempting reconnect...");
+                set_up(false);
 
+                // Legs that dropped together start reconnecting one by one
+                if wait_or_shutdown(&mut shutdown, reconnect_stagger.reserve_start()).await {
+                    return;
+                }
                 match adapter.reconnect().await {
                     Ok(()) => {
                         info!(exchange = %exchange, "Reconnected successfully");
+                        // Legs the adapter could not re-subscribe stay down
+                        let lost_now: HashSet<String> = adapter.lost_subscriptions().into_iter().collect();
+                        if !lost_now.is_empty() {
+                            warn!(exchange = %exchange, symbols = ?lost_now, "Legs lost on reconnect marked unhealthy");
+                        }
+                        *lost.lock().unwrap_or_else(|e| e.into_inner()) = lost_now;
+                        set_up(true);
+                        seed_snapshots(&adapter, &stale_snapshots).await;
+                        stale_snapshots.clear();
                         reconnect_backoff_ms = 1_000; // Reset backoff
                         continue;
                     }
+                    Err(e) if e.is_maintenance() => {
+                        seed_snapshots(&adapter, &stale_snapshots).await;
+                        let delay = tokio::time::Duration::from_millis(maintenance.delay_ms);
+                        log_maintenance(&exchange, &e, delay);
+                        if wait_or_shutdown(&mut shutdown, delay).await {
+                            return;
+                        }
+                        if stale_snapshots.is_empty() {
+                            continue;
+                        }
+                    }
+                    Err(e) if e.is_fatal() => {
+                        error!(exchange = %exchange, error = %e, "Reconnect failed with a fatal error, stopping adapter");
+                        return;
+                    }
                     Err(e) => {
+                        seed_snapshots(&adapter, &stale_snapshots).await;
                         error!(
                             exchange = %exchange,
                             error = %e,
                             retry_in_ms = reconnect_backoff_ms,
                             "Reconnect failed, retrying after backoff..."
                         );
-                        tokio::time::sleep(tokio::time::Duration::from_millis(reconnect_backoff_ms)).await;
+                        let delay = tokio::time::Duration::from_millis(reconnect_backoff_ms);
+                        if wait_or_shutdown(&mut shutdown, delay).await {
+                            return;
+                        }
                         reconnect_backoff_ms = (reconnect_backoff_ms * 2).min(MAX_RECONNECT_BACKOFF_MS);
-                        continue; // Keep trying, never break
+                        if stale_snapshots.is_empty() {
+                            continue; // Keep trying, never break
+                        }
+                        // Emit the REST snapshot while the WebSocket is down
                     }
                 }
             }
 
+            if let (Some(m), Some(rtt_ms)) = (&metrics, adapter.ws_rtt_ms()) {
+                m.set_ws_rtt(&exchange, rtt_ms);
+            }
+            if let (Some(m), Some(latency)) = (&metrics, adapter.ingest_latency_ms()) {
+                m.set_ingest_latency(&exchange, latency);
+            }
+            if let Some(m) = &metrics {
+                let (parsed, malformed) = adapter.parse_stats();
+                m.set_parse_stats(&exchange, parsed, malformed);
+            }
+
             // Read atomic best prices
             let (bid, ask) = best_prices.load();
             if bid <= 0.0 || ask <= 0.0 {
@@ -183,9 +622,13 @@ impl ExchangeManager {
             // We use the SharedOrderbooks to check which symbols have data.
             let shared_ob = adapter.get_shared_orderbooks();
             let books = shared_ob.read().await;
+            let mut to_record = Vec::new();
+            let mut crossed = Vec::new();
 
             for symbol in &symbols {
-                let exchange_symbol = resolve_symbol(&exchange, symbol);
+                let Ok(exchange_symbol) = overrides.resolve(&exchange, symbol) else {
+                    continue;
+                };
 
                 if let Some(orderbook) = books.get(&exchange_symbol) {
                     let ob_bid = orderbook.best_bid().unwrap_or(0.0);
@@ -207,12 +650,39 @@ impl ExchangeManager {
 
                     last_seen.insert(symbol.clone(), (ob_bid, ob_ask));
 
+                    // A crossed book would read as a free cross-exchange spread
+                    if ob_bid > ob_ask {
+                        warn!(
+                            event_type = "CROSSED_BOOK",
+                            exchange = %exchange,
+                            symbol = %symbol,
+                            bid = ob_bid,
+                            ask = ob_ask,
+                            policy = ?crossed_book_policy,
+                            "Crossed orderbook received"
+                        );
+                        if let Some(m) = &metrics {
+                            m.record_crossed_book(&exchange);
+                        }
+                        crossed.push(exchange_symbol.clone());
+                    } else if crossed_book_policy == CrossedBookPolicy::Discard {
+                        last_good.insert(exchange_symbol.clone(), orderbook.clone());
+                    }
+                    let Some((ob_bid, ob_ask)) = crossed_book_policy.resolve(ob_bid, ob_ask) else {
+                        continue;
+                    };
+                    if source.recorder().is_some() && book_tap.is_none() {
+                        to_record.push((exchange_symbol, orderbook.clone()));
+                    }
+
                     let price_data = PriceData {
                         exchange: exchange_arc.clone(),
                         symbol: Arc::from(symbol.as_str()),
                         bid: ob_bid,
                         ask: ob_ask,
                         timestamp_ms: current_time_ms(),
+                        // Kept apart from the receive time: exchange clocks drift
+                        exchange_time_ms: (orderbook.timestamp > 0).then_some(orderbook.timestamp),
                     };
 
                     // Broadcast — if no receivers, just drop
@@ -221,6 +691,464 @@ impl ExchangeManager {
             }
             // Drop the read lock before next iteration
             drop(books);
+
+            // Don't leave crossed books for BookReader and scan to pick up
+            if !crossed.is_empty() {
+                repair_crossed_books(&shared_ob, &best_prices, &crossed, crossed_book_policy, &last_good).await;
+            }
+
+            if let Some(recorder) = source.recorder() {
+                for (exchange_symbol, orderbook) in &to_record {
+                    if let Err(e) = recorder.record(&exchange, exchange_symbol, orderbook).await {
+                        warn!(exchange = %exchange, error = %e, "Failed to record orderbook");
+                    }
+                }
+            }
         }
     }
 }
+
+fn log_maintenance(exchange: &str, error: &crate::adapters::ExchangeError, delay: tokio::time::Duration) {
+    warn!(
+        event_type = "MAINTENANCE_DETECTED",
+        exchange = %exchange,
+        error = %error,
+        retry_in_ms = delay.as_millis() as u64,
+        "Exchange under maintenance, waiting before reconnecting"
+    );
+}
+
+/// Record the books queued on `book_tap` since the last drain.
+async fn drain_book_tap(
+    recorder: &RecordingLayer,
+    exchange: &str,
+    book_tap: Option<&mut tokio::sync::mpsc::UnboundedReceiver<(u64, String, Orderbook)>>,
+) {
+    let Some(book_tap) = book_tap else {
+        return;
+    };
+    while let Ok((received_ms, symbol, orderbook)) = book_tap.try_recv() {
+        if let Err(e) = recorder.record_at(received_ms, exchange, &symbol, &orderbook).await {
+            warn!(exchange = %exchange, error = %e, "Failed to record orderbook");
+        }
+    }
+}
+
+/// Replace the books in `symbols` that are still crossed per `policy`, and
+/// re-publish the best prices from the last one repaired.
+///
+/// A book the reader has since overwritten with an uncrossed one is left alone.
+async fn repair_crossed_books(
+    books: &SharedOrderbooks,
+    best_prices: &AtomicBestPrices,
+    symbols: &[String],
+    policy: CrossedBookPolicy,
+    last_good: &HashMap<String, Orderbook>,
+) {
+    let mut books = books.write().await;
+    for symbol in symbols {
+        let Some(book) = books.get(symbol) else {
+            continue;
+        };
+        if !matches!((book.best_bid(), book.best_ask()), (Some(bid), Some(ask)) if bid > ask) {
+            continue;
+        }
+        match policy.repair(book, last_good.get(symbol)) {
+            Some(repaired) => {
+                best_prices.store(repaired.best_bid().unwrap_or(0.0), repaired.best_ask().unwrap_or(0.0));
+                books.insert(symbol.clone(), repaired);
+            }
+            None => {
+                best_prices.store(0.0, 0.0);
+                books.remove(symbol);
+            }
+        }
+    }
+}
+
+/// Sleep for `delay`; returns `true` if shutdown was requested meanwhile.
+async fn wait_or_shutdown(shutdown: &mut Option<ShutdownListener>, delay: tokio::time::Duration) -> bool {
+    match shutdown.as_mut() {
+        Some(listener) => tokio::select! {
+            _ = tokio::time::sleep(delay) => false,
+            _ = listener.wait() => true,
+        },
+        None => {
+            tokio::time::sleep(delay).await;
+            false
+        }
+    }
+}
+
+/// Fetch a REST snapshot for every subscribed symbol.
+///
+/// Returns the books keyed by exchange symbol. Failures (including adapters
+/// without a REST endpoint) are logged and skipped.
+pub(crate) async fn fetch_snapshots<A: ExchangeAdapter>(
+    adapter: &A,
+    exchange: &str,
+    symbols: &[String],
+    overrides: &SymbolOverrides,
+) -> HashMap<String, Orderbook> {
+    let mut snapshots = HashMap::new();
+    for symbol in symbols {
+        let Ok(exchange_symbol) = overrides.resolve(exchange, symbol) else {
+            continue;
+        };
+        match adapter.fetch_orderbook_snapshot(&exchange_symbol).await {
+            Ok(orderbook) => {
+                snapshots.insert(exchange_symbol, orderbook);
+            }
+            Err(e) => {
+                warn!(exchange = %exchange, symbol = %symbol, error = %e, "REST snapshot unavailable");
+            }
+        }
+    }
+    if !snapshots.is_empty() {
+        info!(exchange = %exchange, books = snapshots.len(), "Feed stale — using REST orderbook snapshots");
+    }
+    snapshots
+}
+
+/// Insert snapshot books the WebSocket has not replaced yet into the
+/// adapter's shared storage. Returns the number of books inserted.
+pub(crate) async fn seed_snapshots<A: ExchangeAdapter>(
+    adapter: &A,
+    snapshots: &HashMap<String, Orderbook>,
+) -> usize {
+    if snapshots.is_empty() {
+        return 0;
+    }
+    let shared_ob = adapter.get_shared_orderbooks();
+    let best_prices = adapter.get_shared_best_prices();
+    let mut books = shared_ob.write().await;
+
+    let mut inserted = 0;
+    for (exchange_symbol, orderbook) in snapshots {
+        if books.contains_key(exchange_symbol) {
+            continue;
+        }
+        best_prices.store(
+            orderbook.best_bid().unwrap_or(0.0),
+            orderbook.best_ask().unwrap_or(0.0),
+        );
+        books.insert(exchange_symbol.clone(), orderbook.clone());
+        inserted += 1;
+    }
+    inserted
+}
+
+/// Disconnect every adapter concurrently, giving up on any still pending
+/// after `timeout`.
+///
+/// A failing or hung adapter does not hold up the others. Each error (a
+/// hang shows up as `NetworkTimeout`) is logged and returned with its
+/// exchange name rather than aborting the rest.
+pub async fn disconnect_all<A: ExchangeAdapter>(
+    adapters: &mut HashMap<String, A>,
+    timeout: tokio::time::Duration,
+) -> Vec<(String, ExchangeError)> {
+    let attempts = adapters.iter_mut().map(|(name, adapter)| async move {
+        let result = match tokio::time::timeout(timeout, adapter.disconnect()).await {
+            Ok(result) => result,
+            Err(_) => Err(ExchangeError::NetworkTimeout(timeout.as_millis() as u64)),
+        };
+        (name, result)
+    });
+
+    let mut errors = Vec::new();
+    for (name, result) in futures_util::future::join_all(attempts).await {
+        if let Err(e) = result {
+            warn!(exchange = %name, error = %e, "Disconnect failed");
+            errors.push((name.clone(), e));
+        }
+    }
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::paradex::{ParadexAdapter, ParadexConfig};
+    use crate::adapters::stub::{StubAdapter, StubOutcome};
+
+    /// Serve `GET /orderbook/BTC-USD-PERP` like the Paradex REST API
+    async fn spawn_mock_paradex() -> String {
+        use axum::{routing::get, Json, Router};
+
+        let app = Router::new().route(
+            "/orderbook/BTC-USD-PERP",
+            get(|| async {
+                Json(serde_json::json!({
+                    "market": "BTC-USD-PERP",
+                    "seq_no": 7,
+                    "last_updated_at": 1700000000000u64,
+                    "bids": [["42000.0", "1.0"]],
+                    "asks": [["42010.0", "2.0"]]
+                }))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_stale_adapter_repopulated_from_rest_snapshot() {
+        let config = ParadexConfig {
+            rest_url: Some(spawn_mock_paradex().await),
+            ..Default::default()
+        };
+        let adapter = ParadexAdapter::new(config);
+        assert!(adapter.is_stale());
+
+        let symbols = vec!["BTC".to_string()];
+        let snapshots = fetch_snapshots(&adapter, "paradex", &symbols, &SymbolOverrides::default()).await;
+        assert_eq!(seed_snapshots(&adapter, &snapshots).await, 1);
+
+        let shared_ob = adapter.get_shared_orderbooks();
+        let books = shared_ob.read().await;
+        let book = books.get("BTC-USD-PERP").expect("snapshot seeded");
+        assert_eq!(book.best_bid(), Some(42000.0));
+        assert_eq!(book.best_ask(), Some(42010.0));
+        assert_eq!(adapter.get_shared_best_prices().load(), (42000.0, 42010.0));
+    }
+
+    #[tokio::test]
+    async fn test_consolidated_book_skips_unhealthy_feeds() {
+        let (price_tx, _) = broadcast::channel(16);
+        let manager = ExchangeManager::new(vec![], vec!["BTC".to_string()], price_tx);
+
+        let register = |exchange: &str, symbol: &str, bid: f64, healthy: bool| {
+            let mut book = Orderbook::new();
+            book.bids.push(crate::adapters::types::OrderbookLevel::new(bid, 1.0));
+            let books: SharedOrderbooks =
+                Arc::new(RwLock::new(HashMap::from([(symbol.to_string(), book)])));
+            let feed = Feed { books, healthy: Arc::new(AtomicBool::new(healthy)), lost: Default::default() };
+            manager.feeds.try_write().unwrap().insert(exchange.to_string(), feed);
+        };
+        register("vest", "BTC-PERP", 42000.0, true);
+        register("paradex", "BTC-USD-PERP", 42005.0, true);
+        register("lighter", "BTC", 42010.0, false);
+
+        let book = manager.consolidated_book("BTC").await;
+        let bids: Vec<(&str, f64)> = book.bids.iter().map(|l| (&*l.exchange, l.price)).collect();
+        assert_eq!(bids, vec![("paradex", 42005.0), ("vest", 42000.0)]);
+
+        let reader = manager.book_reader();
+        assert_eq!(reader.book("vest", "BTC").await.and_then(|b| b.best_bid()), Some(42000.0));
+        assert!(reader.book("lighter", "BTC").await.is_none(), "unhealthy feed");
+        assert!(reader.is_healthy("vest", "BTC").await);
+        assert!(!reader.is_healthy("lighter", "BTC").await, "reconnecting");
+        assert!(!reader.is_healthy("dydx", "BTC").await, "not running");
+        assert!(reader.book("vest", "ETH").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_all_collects_errors_without_aborting() {
+        let mut adapters = HashMap::from([
+            ("vest".to_string(), StubAdapter::new()),
+            ("paradex".to_string(), StubAdapter::new().with_outcome(StubOutcome::DisconnectError)),
+            ("lighter".to_string(), StubAdapter::new().with_outcome(StubOutcome::DisconnectHang)),
+        ]);
+
+        let started = tokio::time::Instant::now();
+        let mut errors = disconnect_all(&mut adapters, tokio::time::Duration::from_millis(100)).await;
+        assert!(started.elapsed() < tokio::time::Duration::from_secs(1), "bounded by the timeout");
+
+        assert!(adapters.values().all(|a| a.disconnected.load(Ordering::SeqCst)));
+        errors.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, "lighter");
+        assert!(matches!(errors[0].1, ExchangeError::NetworkTimeout(100)));
+        assert_eq!(errors[1].0, "paradex");
+        assert!(matches!(errors[1].1, ExchangeError::ConnectionFailed(_)));
+    }
+
+    /// Manager watching BTC, which the "probe" exchange lists as BTC-PROBE
+    /// (and ETH as ETH-PROBE)
+    fn probe_manager(price_tx: broadcast::Sender<PriceData>) -> ExchangeManager {
+        let listings = HashMap::from([
+            ("BTC".to_string(), "BTC-PROBE".to_string()),
+            ("ETH".to_string(), "ETH-PROBE".to_string()),
+        ]);
+        ExchangeManager::new(vec![], vec!["BTC".to_string()], price_tx)
+            .with_symbol_overrides(SymbolOverrides::new(HashMap::from([("probe".to_string(), listings)])))
+    }
+
+    /// Task config for driving a `StubAdapter` as "probe" inside
+    /// `manager`, polling every 10ms
+    fn probe_task(manager: &ExchangeManager, crossed_book_policy: CrossedBookPolicy) -> AdapterTaskConfig {
+        AdapterTaskConfig {
+            exchange: "probe".to_string(),
+            symbols: vec!["BTC".to_string()],
+            price_tx: manager.price_tx.clone(),
+            poll_ms: 10,
+            metrics: None,
+            maintenance: MaintenanceBackoff::default(),
+            feeds: manager.feeds.clone(),
+            overrides: manager.symbol_overrides.clone(),
+            source: FeedSource::Live { recorder: None },
+            crossed_book_policy,
+            reconnect_stagger: ReconnectStagger::new(tokio::time::Duration::ZERO),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dead_reader_triggers_reconnect_and_pauses_leg() {
+        let (price_tx, _) = broadcast::channel(16);
+        let manager = probe_manager(price_tx);
+        let task = probe_task(&manager, CrossedBookPolicy::default());
+        let mut probe = StubAdapter::new();
+        probe.lost_on_reconnect = vec!["ETH-PROBE".to_string()];
+        let reader_alive = probe.health.reader_alive.clone();
+        let reconnects = probe.reconnects.clone();
+        let reconnect_gate = Arc::new(tokio::sync::Notify::new());
+        probe.reconnect_gate = Some(reconnect_gate.clone());
+        let reader = manager.book_reader();
+        let task = tokio::spawn(ExchangeManager::drive_adapter(probe, task, None));
+
+        let settle = || tokio::time::sleep(tokio::time::Duration::from_millis(50));
+        settle().await;
+        assert!(reader.is_healthy("probe", "BTC").await);
+        assert!(reader.is_healthy("probe", "ETH").await);
+        assert_eq!(reconnects.load(Ordering::SeqCst), 0);
+
+        // The reader task dies: the poll loop notices and reconnects, and
+        // the leg reads as down until the reconnect completes
+        reader_alive.store(false, Ordering::SeqCst);
+        settle().await;
+        assert_eq!(reconnects.load(Ordering::SeqCst), 1);
+        assert!(!reader.is_healthy("probe", "BTC").await, "pipeline skips the leg while reconnecting");
+
+        reconnect_gate.notify_one();
+        settle().await;
+        assert!(reader.is_healthy("probe", "BTC").await);
+        assert!(!reader.is_healthy("probe", "ETH").await, "subscription lost on reconnect");
+        assert_eq!(reconnects.load(Ordering::SeqCst), 1);
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_interrupts_reconnect_backoff() {
+        let (price_tx, _) = broadcast::channel(16);
+        let manager = probe_manager(price_tx);
+        let probe = StubAdapter::new().with_outcome(StubOutcome::Unreachable);
+        let reader_alive = probe.health.reader_alive.clone();
+        let reconnects = probe.reconnects.clone();
+        let signal = crate::core::ShutdownSignal::new();
+        let task = tokio::spawn(ExchangeManager::drive_adapter(
+            probe,
+            probe_task(&manager, CrossedBookPolicy::default()),
+            Some(signal.subscribe()),
+        ));
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        reader_alive.store(false, Ordering::SeqCst);
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        assert_eq!(reconnects.load(Ordering::SeqCst), 1, "now in the 1s backoff");
+
+        signal.trigger();
+        tokio::time::timeout(tokio::time::Duration::from_millis(500), task)
+            .await
+            .expect("stops without waiting out the backoff")
+            .unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_crossed_book_replaced_in_shared_storage() {
+        for policy in [CrossedBookPolicy::Discard, CrossedBookPolicy::Clamp] {
+            let (price_tx, mut price_rx) = broadcast::channel(16);
+            let manager = probe_manager(price_tx);
+            let task = probe_task(&manager, policy);
+            let probe = StubAdapter::new();
+            let (books, best_prices) = (probe.books.clone(), probe.best_prices.clone());
+            let write = |bids: &[f64], asks: &[f64]| {
+                let level = |&price: &f64| crate::adapters::types::OrderbookLevel::new(price, 1.0);
+                let book = Orderbook {
+                    bids: bids.iter().map(level).collect(),
+                    asks: asks.iter().map(level).collect(),
+                    timestamp: 0,
+                };
+                best_prices.store(bids[0], asks[0]);
+                let books = books.clone();
+                async move { books.write().await.insert("BTC-PROBE".to_string(), book) }
+            };
+            let settle = || tokio::time::sleep(tokio::time::Duration::from_millis(50));
+            let task = tokio::spawn(ExchangeManager::drive_adapter(probe, task, None));
+
+            write(&[100.0], &[101.0]).await;
+            settle().await;
+            write(&[103.0, 100.0], &[101.0, 104.0]).await;
+            settle().await;
+
+            let book = manager.book_reader().book("probe", "BTC").await.expect("book kept");
+            let (bid, ask) = best_prices.load();
+            match policy {
+                CrossedBookPolicy::Discard => {
+                    assert_eq!((book.best_bid(), book.best_ask()), (Some(100.0), Some(101.0)), "previous book restored");
+                    assert_eq!((bid, ask), (100.0, 101.0));
+                }
+                CrossedBookPolicy::Clamp => {
+                    assert_eq!((book.best_bid(), book.best_ask()), (Some(100.0), Some(104.0)), "crossed levels trimmed");
+                    assert_eq!((bid, ask), (100.0, 104.0));
+                }
+            }
+            let mut quotes = Vec::new();
+            while let Ok(price) = price_rx.try_recv() {
+                quotes.push((price.bid, price.ask));
+            }
+            assert!(quotes.iter().all(|(bid, ask)| bid <= ask), "{policy:?}: {quotes:?}");
+            task.abort();
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_emitted_price_carries_book_timestamp() {
+        let (price_tx, mut price_rx) = broadcast::channel(16);
+        let manager = probe_manager(price_tx);
+        let task = probe_task(&manager, CrossedBookPolicy::Discard);
+        let probe = StubAdapter::new();
+        let (books, best_prices) = (probe.books.clone(), probe.best_prices.clone());
+        let task = tokio::spawn(ExchangeManager::drive_adapter(probe, task, None));
+
+        for (bid, timestamp) in [(100.0, 1_700_000_000_000), (101.0, 0)] {
+            let mut book = Orderbook::new();
+            book.bids.push(crate::adapters::types::OrderbookLevel::new(bid, 1.0));
+            book.asks.push(crate::adapters::types::OrderbookLevel::new(102.0, 1.0));
+            book.timestamp = timestamp;
+            best_prices.store(bid, 102.0);
+            books.write().await.insert("BTC-PROBE".to_string(), book);
+            tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        }
+
+        let snapshot = price_rx.try_recv().expect("first book emitted");
+        assert_eq!(snapshot.exchange_time_ms, Some(1_700_000_000_000), "keeps the exchange time");
+        assert!(current_time_ms() - snapshot.timestamp_ms < 1_000, "received on the local clock");
+        let unstamped = price_rx.try_recv().expect("second book emitted");
+        assert_eq!(unstamped.exchange_time_ms, None);
+        task.abort();
+    }
+
+    #[tokio::test]
+    async fn test_seed_keeps_books_the_websocket_refreshed() {
+        let adapter = ParadexAdapter::new(ParadexConfig::default());
+        let mut fresh = Orderbook::new();
+        fresh.bids.push(crate::adapters::types::OrderbookLevel::new(43000.0, 1.0));
+        adapter
+            .get_shared_orderbooks()
+            .write()
+            .await
+            .insert("BTC-USD-PERP".to_string(), fresh);
+
+        let mut stale = Orderbook::new();
+        stale.bids.push(crate::adapters::types::OrderbookLevel::new(42000.0, 1.0));
+        let snapshots = HashMap::from([("BTC-USD-PERP".to_string(), stale)]);
+
+        assert_eq!(seed_snapshots(&adapter, &snapshots).await, 0);
+        let shared_ob = adapter.get_shared_orderbooks();
+        let books = shared_ob.read().await;
+        assert_eq!(books["BTC-USD-PERP"].best_bid(), Some(43000.0));
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides the core abstractions for connecting to
 //! various cryptocurrency exchanges via WebSocket for price monitoring.
 
+pub mod backpack;
 pub mod dydx;
 pub mod errors;
 pub mod ethereal;
@@ -11,34 +12,46 @@ pub mod factory;
 pub mod grvt;
 pub mod hotstuff;
 pub mod hyperliquid;
+#[cfg(any(test, feature = "testing"))]
+pub mod latency;
 pub mod lighter;
 pub mod manager;
 pub mod nado;
 pub mod nord;
 pub mod pacifica;
 pub mod paradex;
+pub mod replay;
 pub mod reya;
 pub mod shared;
+#[cfg(test)]
+pub(crate) mod stub;
 pub mod traits;
 pub mod types;
 pub mod vest;
 
 // Re-export commonly used types for convenience
 pub use errors::{ExchangeError, ExchangeResult};
+pub use backpack::{BackpackAdapter, BackpackConfig};
 pub use dydx::{DydxAdapter, DydxConfig};
-pub use factory::{AnyAdapter, create_adapter, resolve_symbol};
+pub use factory::{
+    AnyAdapter, SymbolOverrides, create_adapter, resolve_symbol, resolve_symbol_with_quote,
+};
 pub use ethereal::{EtherealAdapter, EtherealConfig};
 pub use extended::{ExtendedAdapter, ExtendedConfig};
 pub use grvt::{GrvtAdapter, GrvtConfig};
 pub use hotstuff::{HotstuffAdapter, HotstuffConfig};
 pub use hyperliquid::{HyperliquidAdapter, HyperliquidConfig};
 pub use lighter::{LighterAdapter, LighterConfig};
-pub use manager::ExchangeManager;
+pub use manager::{BookReader, ExchangeManager};
 pub use nado::{NadoAdapter, NadoConfig};
 pub use nord::{NordWsAdapter, NordConfig};
 pub use pacifica::{PacificaAdapter, PacificaConfig};
 pub use paradex::{ParadexAdapter, ParadexConfig};
+pub use replay::{RecordingLayer, ReplayAdapter, ReplayPace};
 pub use reya::{ReyaAdapter, ReyaConfig};
 pub use traits::ExchangeAdapter;
-pub use types::{Orderbook, OrderbookLevel, OrderbookUpdate};
+pub use types::{
+    Capabilities, ConsolidatedLevel, ConsolidatedOrderbook, CrossedBookPolicy, DepthRequirement,
+    LatencyPercentiles, MarketInfo, Orderbook, OrderbookLevel, OrderbookUpdate,
+};
 pub use vest::{SharedOrderbooks, VestAdapter, VestConfig};
@@ -11,7 +11,7 @@
 //! Subscribes to `best_bid_offer` streams for real-time BBO data.
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -68,17 +68,16 @@ impl NadoAdapter {
 
     fn spawn_ws_task(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url().to_string();
+        let extra_headers = self.config.extra_headers.clone();
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let health = self.connection_health.clone_refs();
 
         let handle = tokio::spawn(async move {
             Self::ws_subscribe_loop(
-                url, shared_orderbooks, shared_best_prices, orderbook_notify,
-                last_pong, last_data, reader_alive,
+                url, extra_headers, shared_orderbooks, shared_best_prices, orderbook_notify,
+                health,
             ).await;
         });
         self.reader_handle = Some(handle);
@@ -88,15 +87,15 @@ impl NadoAdapter {
     /// Connects via yawc with:
     ///   - Options with low_latency_compression → native permessage-deflate decompression
     ///   - .with_request() → explicit Sec-WebSocket-Extensions header for gateway
+    ///   - `extra_headers` from config added to the upgrade request
     /// Then subscribes to best_bid_offer and reads the push stream.
     async fn ws_subscribe_loop(
         url: String,
+        extra_headers: HashMap<String, String>,
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!(exchange = "nado", "Connecting to Nado WS: {}", url);
 
@@ -107,8 +106,11 @@ impl NadoAdapter {
 
         // Build custom HTTP request with the required Sec-WebSocket-Extensions header.
         // Nado's gateway checks for this header and returns 403 without it.
-        let http_builder = yawc::HttpRequestBuilder::new()
+        let mut http_builder = yawc::HttpRequestBuilder::new()
             .header("Sec-WebSocket-Extensions", "permessage-deflate");
+        for (name, value) in &extra_headers {
+            http_builder = http_builder.header(name.as_str(), value.as_str());
+        }
 
         // Enable compression so yawc:
         //   1) Sends the Sec-WebSocket-Extensions: permessage-deflate header (gateway requires it)
@@ -128,8 +130,8 @@ impl NadoAdapter {
         };
 
         tracing::info!(exchange = "nado", "Connected with yawc (deflate decompression + explicit header)");
-        reader_alive.store(true, Ordering::Relaxed);
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         // Subscribe to best_bid_offer for each market (BBO only)
         let markets = get_nado_markets();
@@ -143,7 +145,7 @@ impl NadoAdapter {
             let frame = yawc::Frame::text(json);
             if let Err(e) = ws.send(frame).await {
                 tracing::error!(exchange = "nado", error = %e, "Subscribe send failed");
-                reader_alive.store(false, Ordering::Relaxed);
+                health.reader_alive.store(false, Ordering::Relaxed);
                 return;
             }
         }
@@ -154,8 +156,8 @@ impl NadoAdapter {
 
         while let Some(frame) = ws.next().await {
             let now = current_time_ms();
-            last_data.store(now, Ordering::Relaxed);
-            last_pong.store(now, Ordering::Relaxed);
+            health.last_data.store(now, Ordering::Relaxed);
+            health.last_pong.store(now, Ordering::Relaxed);
 
             // Send ping every 25 seconds (Nado docs: keep-alive every 30s)
             if now.saturating_sub(last_ping) > 25_000 {
@@ -211,7 +213,7 @@ impl NadoAdapter {
         }
 
         tracing::warn!(exchange = "nado", msg_count, "WS stream ended");
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
     }
 }
 
@@ -238,12 +240,13 @@ impl ExchangeAdapter for NadoAdapter {
         }
 
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "nado", "Nado adapter started (yawc + deflate + explicit header)");
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Disconnected; }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         if let Some(h) = self.reader_handle.take() { h.abort(); }
         self.connected = false;
         self.subscriptions.clear();
@@ -314,6 +317,7 @@ impl ExchangeAdapter for NadoAdapter {
     fn get_shared_orderbooks(&self) -> SharedOrderbooks { Arc::clone(&self.shared_orderbooks) }
     fn get_shared_best_prices(&self) -> SharedBestPrices { Arc::clone(&self.shared_best_prices) }
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) { self.orderbook_notify = Some(notify); }
+    fn connection_health(&self) -> &ConnectionHealth { &self.connection_health }
 }
 
 #[cfg(test)]
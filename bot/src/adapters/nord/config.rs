@@ -1,12 +1,23 @@
 //! Nord Configuration
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 const MAINNET_WS_URL: &str = "wss://zo-mainnet.n1.xyz/ws/deltas@BTCUSD";
 
 #[derive(Debug, Clone)]
-pub struct NordConfig { pub production: bool }
-impl Default for NordConfig { fn default() -> Self { Self { production: true } } }
+pub struct NordConfig {
+    pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+}
+impl Default for NordConfig { fn default() -> Self { Self { production: true, extra_headers: HashMap::new() } } }
 impl NordConfig {
     pub fn from_env() -> Self {
-        Self { production: std::env::var("NORD_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true) }
+        Self {
+            production: std::env::var("NORD_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true),
+            extra_headers: extra_headers_from_env("NORD_EXTRA_HEADERS"),
+        }
     }
     pub fn ws_url(&self) -> &str { MAINNET_WS_URL }
 }
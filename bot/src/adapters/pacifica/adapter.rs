@@ -1,7 +1,7 @@
 //! Pacifica Adapter — BBO (best bid/offer) channel, ping every 30s
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -59,7 +59,7 @@ impl PacificaAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_url();
         tracing::info!("Connecting to Pacifica WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream = crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers).await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -73,13 +73,11 @@ impl PacificaAdapter {
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
         let shared_best_prices = Arc::clone(&self.shared_best_prices);
         let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        let health = self.connection_health.clone_refs();
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, last_pong, last_data, reader_alive).await;
+            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, health).await;
         });
         self.reader_handle = Some(handle);
         Ok(())
@@ -104,17 +102,15 @@ impl PacificaAdapter {
         shared_orderbooks: SharedOrderbooks,
         shared_best_prices: SharedBestPrices,
         orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        health: ConnectionHealth,
     ) {
         tracing::info!("Pacifica message_reader_loop started");
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
         let mut msg_count: u64 = 0;
 
         while let Some(msg_result) = ws_receiver.next().await {
-            last_data.store(current_time_ms(), Ordering::Relaxed);
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
@@ -123,7 +119,7 @@ impl PacificaAdapter {
                         tracing::info!(exchange = "pacifica", msg_count, raw = %text.chars().take(300).collect::<String>(), "RAW WS message");
                     }
                     // Try to parse as flexible response
-                    if let Ok(msg) = serde_json::from_str::<PacificaWsResponse>(&text) {
+                    if let Ok(msg) = health.parse_stats.observe(serde_json::from_str::<PacificaWsResponse>(&text)) {
                         // Skip error/ack messages
                         if msg.err.is_some() {
                             if msg_count <= 5 {
@@ -183,7 +179,7 @@ impl PacificaAdapter {
                 Err(e) => { tracing::error!("Pacifica WS error: {}", e); break; }
             }
         }
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("Pacifica message reader loop ended");
     }
 
@@ -224,12 +220,13 @@ impl ExchangeAdapter for PacificaAdapter {
         self.spawn_heartbeat_task();
         self.subscribe_to_bbo().await?;
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "pacifica", "Pacifica WebSocket connected");
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        { let mut state = self.connection_health.state.write().await; *state = ConnectionState::Disconnected; }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
         if let Some(h) = self.reader_handle.take() { h.abort(); }
         if let Some(h) = self.heartbeat_handle.take() { h.abort(); }
         if let Some(s) = self.ws_sender.take() { let mut s = s.lock().await; let _ = s.close().await; }
@@ -285,6 +282,7 @@ impl ExchangeAdapter for PacificaAdapter {
     fn get_shared_orderbooks(&self) -> SharedOrderbooks { Arc::clone(&self.shared_orderbooks) }
     fn get_shared_best_prices(&self) -> SharedBestPrices { Arc::clone(&self.shared_best_prices) }
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify) { self.orderbook_notify = Some(notify); }
+    fn connection_health(&self) -> &ConnectionHealth { &self.connection_health }
 }
 
 #[cfg(test)]
@@ -4,25 +4,26 @@
 //! Read-only market data via WebSocket (public orderbooks).
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 use async_trait::async_trait;
 use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{oneshot, Mutex, RwLock};
 use tokio_tungstenite::{tungstenite::protocol::Message, MaybeTlsStream, WebSocketStream};
 
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::shared::{backoff_delay_ms, ReconnectConfig};
 use crate::adapters::traits::ExchangeAdapter;
 use crate::adapters::types::{
-    create_http_client, next_subscription_id, Orderbook,
+    create_http_client_with_headers, Capabilities, ConnectionHealth, ConnectionState, MarketInfo, Orderbook,
 };
 
 // Import from our sub-modules
 use super::config::ParadexConfig;
-use super::types::ParadexWsMessage;
+use super::types::{ParadexMarketsResponse, ParadexOrderbookSnapshot, ParadexWsMessage};
 
 /// Get current time in milliseconds
 fn current_time_ms() -> u64 {
@@ -35,7 +36,155 @@ fn current_time_ms() -> u64 {
 /// Type alias for the WebSocket stream
 type WsStream = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
 
-// next_subscription_id() imported from crate::adapters::types (shared counter)
+// =============================================================================
+// JSON-RPC Id Space
+// =============================================================================
+
+/// JSON-RPC id reserved for the auth request; never handed out by [`RpcRouter`]
+pub(crate) const AUTH_RPC_ID: u64 = 0;
+
+/// A request awaiting its JSON-RPC response
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PendingRpc {
+    pub method: &'static str,
+    pub symbol: String,
+}
+
+/// Outcome of a JSON-RPC request: `Err` carries the server's error message
+pub(crate) type RpcOutcome = Result<(), String>;
+
+/// Per-connection JSON-RPC id allocator and response router
+///
+/// A fresh router is created for every WebSocket connection, so ids from a
+/// previous connection can never be confused with in-flight responses on
+/// the new one. Request ids start above [`AUTH_RPC_ID`].
+#[derive(Debug)]
+pub(crate) struct RpcRouter {
+    next_id: AtomicU64,
+    pending: std::sync::Mutex<HashMap<u64, PendingRpc>>,
+    /// Callers awaiting a response, by request id
+    waiters: std::sync::Mutex<HashMap<u64, oneshot::Sender<RpcOutcome>>>,
+}
+
+impl RpcRouter {
+    pub(crate) fn new() -> Self {
+        Self {
+            next_id: AtomicU64::new(AUTH_RPC_ID + 1),
+            pending: std::sync::Mutex::new(HashMap::new()),
+            waiters: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Like [`Self::register`], also returning a receiver for the response.
+    ///
+    /// Dropping the receiver is fine for fire-and-forget requests.
+    pub(crate) fn register_awaited(
+        &self,
+        method: &'static str,
+        symbol: &str,
+    ) -> (u64, oneshot::Receiver<RpcOutcome>) {
+        let id = self.register(method, symbol);
+        let (tx, rx) = oneshot::channel();
+        self.waiters.lock().unwrap_or_else(|e| e.into_inner()).insert(id, tx);
+        (id, rx)
+    }
+
+    /// Hand a response's outcome to whoever awaits request `id`
+    pub(crate) fn complete(&self, id: u64, outcome: RpcOutcome) {
+        let waiter = self.waiters.lock().unwrap_or_else(|e| e.into_inner()).remove(&id);
+        if let Some(waiter) = waiter {
+            let _ = waiter.send(outcome);
+        }
+    }
+
+    /// Allocate an id for `method` on `symbol` and track it until resolved
+    pub(crate) fn register(&self, method: &'static str, symbol: &str) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            id,
+            PendingRpc {
+                method,
+                symbol: symbol.to_string(),
+            },
+        );
+        id
+    }
+
+    /// Route a response by id, removing the pending entry
+    pub(crate) fn resolve(&self, id: u64) -> Option<PendingRpc> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).remove(&id)
+    }
+}
+
+/// Wait up to `timeout_ms` for the response to a subscribe on `symbol`
+pub(crate) async fn await_subscription(
+    response: oneshot::Receiver<RpcOutcome>,
+    symbol: &str,
+    timeout_ms: u64,
+) -> ExchangeResult<()> {
+    match tokio::time::timeout(Duration::from_millis(timeout_ms), response).await {
+        Ok(Ok(Ok(()))) => Ok(()),
+        Ok(Ok(Err(reason))) => Err(ExchangeError::SubscriptionFailed {
+            symbol: symbol.to_string(),
+            reason,
+        }),
+        Ok(Err(_)) => Err(ExchangeError::ConnectionFailed(
+            "Connection closed before the subscription was confirmed".into(),
+        )),
+        Err(_) => Err(ExchangeError::NetworkTimeout(timeout_ms)),
+    }
+}
+
+// =============================================================================
+// Orderbook Sequence Tracking
+// =============================================================================
+
+/// Last orderbook `seq_no` per market for the current connection
+///
+/// Like [`RpcRouter`], a fresh tracker is created for every WebSocket
+/// connection, since sequence numbers restart with each subscription.
+///
+/// The `snapshot@15@100ms` channel conflates updates, so consecutive
+/// messages skip sequence numbers as a matter of course; only a number
+/// that goes backwards or repeats means the feed is out of order.
+#[derive(Debug, Default)]
+pub(crate) struct SeqTracker {
+    last: std::sync::Mutex<HashMap<String, u64>>,
+}
+
+impl SeqTracker {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `seq_no` for `symbol`, returning the lowest acceptable seq
+    /// when it went backwards or repeated
+    ///
+    /// The first message for a symbol is always accepted, as is any jump
+    /// forward. On a fault the symbol is forgotten, so the snapshot after
+    /// re-subscribing starts a new sequence.
+    pub(crate) fn record(&self, symbol: &str, seq_no: u64) -> Option<u64> {
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        let expected = match last.get_mut(symbol) {
+            None => {
+                last.insert(symbol.to_string(), seq_no);
+                return None;
+            }
+            Some(prev) if seq_no > *prev => {
+                *prev = seq_no;
+                return None;
+            }
+            Some(prev) => prev.wrapping_add(1),
+        };
+        last.remove(symbol);
+        Some(expected)
+    }
+
+    /// Stop tracking `symbol` (after unsubscribing)
+    pub(crate) fn forget(&self, symbol: &str) {
+        self.last.lock().unwrap_or_else(|e| e.into_inner()).remove(symbol);
+    }
+}
 
 // =============================================================================
 // WebSocket Stream Types
@@ -47,6 +196,14 @@ type WsSink = SplitSink<WsStream, Message>;
 /// Type alias for WebSocket receiver (read half)
 type WsReader = SplitStream<WsStream>;
 
+/// The reader's side of the current connection's JSON-RPC session: it
+/// routes responses and re-subscribes markets whose sequence breaks
+struct ReaderRpc {
+    ws_sender: Arc<Mutex<WsSink>>,
+    router: Arc<RpcRouter>,
+    seq_tracker: Arc<SeqTracker>,
+}
+
 /// Shared orderbook storage for concurrent access (lock-free monitoring)
 pub use crate::core::channels::SharedOrderbooks;
 /// Lock-free atomic best prices for hot-path monitoring
@@ -54,6 +211,8 @@ use crate::core::channels::SharedBestPrices;
 use crate::core::channels::AtomicBestPrices;
 /// Event-driven orderbook notification (Axe 5)
 use crate::core::channels::OrderbookNotify;
+/// Coalesced writes into the shared orderbooks
+use crate::core::channels::{sleep_until_deadline, BestPricePublisher, ThrottledBookWriter};
 
 // =============================================================================
 // Paradex Adapter
@@ -79,12 +238,16 @@ pub struct ParadexAdapter {
     orderbooks: HashMap<String, Orderbook>,
     /// Active subscriptions by symbol
     subscriptions: Vec<String>,
-    /// Pending subscription IDs for confirmation tracking
-    pending_subscriptions: HashMap<u64, String>,
+    /// Symbols whose subscription could not be restored after the last reconnect
+    lost_subscriptions: Vec<String>,
+    /// JSON-RPC ids and pending requests for the current connection
+    rpc_router: Arc<RpcRouter>,
+    /// Last orderbook seq_no per market for the current connection
+    seq_tracker: Arc<SeqTracker>,
     /// Handle to message reader task (for cleanup)
     reader_handle: Option<tokio::task::JoinHandle<()>>,
     /// Connection health tracking
-    pub(crate) connection_health: crate::adapters::types::ConnectionHealth,
+    pub(crate) connection_health: ConnectionHealth,
     /// Handle to heartbeat task (for cleanup)
     heartbeat_handle: Option<tokio::task::JoinHandle<()>>,
 
@@ -92,14 +255,18 @@ pub struct ParadexAdapter {
     usdc_rate_cache: Option<Arc<crate::core::UsdcRateCache>>,
     /// Orderbook update notification (Axe 5 event-driven monitoring)
     orderbook_notify: Option<OrderbookNotify>,
+    /// Receives every book the reader writes (raw feed recording)
+    book_tap: Option<crate::core::channels::BookTap>,
+    /// Market metadata by symbol, fetched on first request
+    market_info: RwLock<HashMap<String, MarketInfo>>,
 }
 
 impl ParadexAdapter {
     /// Create a new ParadexAdapter with the given configuration
     pub fn new(config: ParadexConfig) -> Self {
         Self {
+            http_client: create_http_client_with_headers("Paradex", &config.extra_headers),
             config,
-            http_client: create_http_client("Paradex"),
             ws_stream: None,
             ws_sender: None,
             connected: false,
@@ -107,13 +274,17 @@ impl ParadexAdapter {
             shared_best_prices: Arc::new(AtomicBestPrices::new()),
             orderbooks: HashMap::new(),
             subscriptions: Vec::new(),
-            pending_subscriptions: HashMap::new(),
+            lost_subscriptions: Vec::new(),
+            rpc_router: Arc::new(RpcRouter::new()),
+            seq_tracker: Arc::new(SeqTracker::new()),
             reader_handle: None,
-            connection_health: crate::adapters::types::ConnectionHealth::new(),
+            connection_health: ConnectionHealth::new(),
             heartbeat_handle: None,
 
             usdc_rate_cache: None,
             orderbook_notify: None,
+            book_tap: None,
+            market_info: RwLock::new(HashMap::new()),
         }
     }
 
@@ -147,7 +318,9 @@ impl ParadexAdapter {
     /// Connect to WebSocket endpoint
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.config.ws_base_url();
-        let ws_stream = crate::adapters::shared::connect_tls(url).await?;
+        let ws_stream =
+            crate::adapters::shared::connect_tls_with_headers(url, &self.config.extra_headers)
+                .await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -166,23 +339,38 @@ impl ParadexAdapter {
         let (ws_sender, ws_receiver) = ws_stream.split();
 
         // Store sender in Arc<Mutex> for thread-safe access
-        self.ws_sender = Some(Arc::new(Mutex::new(ws_sender)));
+        let ws_sender = Arc::new(Mutex::new(ws_sender));
+        self.ws_sender = Some(Arc::clone(&ws_sender));
+
+        // Fresh id space and sequence tracking per connection
+        self.rpc_router = Arc::new(RpcRouter::new());
+        self.seq_tracker = Arc::new(SeqTracker::new());
+        let rpc = ReaderRpc {
+            ws_sender,
+            router: Arc::clone(&self.rpc_router),
+            seq_tracker: Arc::clone(&self.seq_tracker),
+        };
 
         // Clone Arc references for background tasks
-        let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
-        let shared_best_prices = Arc::clone(&self.shared_best_prices);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let book_writer = ThrottledBookWriter::new(
+            Arc::clone(&self.shared_orderbooks),
+            self.config.min_update_interval_ms,
+        )
+        .with_tap(self.book_tap.clone());
+        let best_prices = BestPricePublisher::new(
+            Arc::clone(&self.shared_best_prices),
+            self.orderbook_notify.clone(),
+            self.config.notify_every_update,
+        );
+        let health = self.connection_health.clone_refs();
         let usdc_rate_cache = self.usdc_rate_cache.clone();
-        let orderbook_notify = self.orderbook_notify.clone();
 
         // Initialize last_data to now so we don't immediately appear stale
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         // Spawn background reader with shared orderbooks, health tracking, and USDC rate
         let handle = tokio::spawn(async move {
-            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, last_data, reader_alive, usdc_rate_cache)
-                .await;
+            Self::message_reader_loop(ws_receiver, rpc, book_writer, best_prices, health, usdc_rate_cache).await;
         });
 
         self.reader_handle = Some(handle);
@@ -194,20 +382,39 @@ impl ParadexAdapter {
     /// Also updates connection health timestamps
     ///
     /// If `usdc_rate_cache` is provided, orderbook prices are converted from USD to USDC
+    ///
+    /// A `seq_no` that goes backwards or repeats on a market logs `SEQ_GAP`
+    /// and re-subscribes it, so the out-of-order book is replaced by a fresh
+    /// snapshot. Forward jumps are normal on the conflated snapshot channel.
+    ///
+    /// Best prices are published on every update (waking the monitoring
+    /// loop only when they change, unless `notify_every_update`); full books
+    /// go through `book_writer`, which may coalesce them (`min_update_interval_ms`).
     async fn message_reader_loop(
         mut ws_receiver: WsReader,
-        shared_orderbooks: SharedOrderbooks,
-        shared_best_prices: SharedBestPrices,
-        orderbook_notify: Option<OrderbookNotify>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        rpc: ReaderRpc,
+        mut book_writer: ThrottledBookWriter,
+        best_prices: BestPricePublisher,
+        health: ConnectionHealth,
         usdc_rate_cache: Option<Arc<crate::core::UsdcRateCache>>,
     ) {
-        reader_alive.store(true, Ordering::Relaxed);
+        let ReaderRpc { ws_sender, router: rpc_router, seq_tracker } = rpc;
+        health.reader_alive.store(true, Ordering::Relaxed);
         tracing::info!("Paradex message_reader_loop started");
-        while let Some(msg_result) = ws_receiver.next().await {
+        loop {
+            let msg_result = tokio::select! {
+                msg = ws_receiver.next() => match msg {
+                    Some(msg) => msg,
+                    None => break,
+                },
+                // Publish books held back by the throttle once they are due
+                _ = sleep_until_deadline(book_writer.flush_deadline()) => {
+                    book_writer.flush().await;
+                    continue;
+                }
+            };
             // Update last_data timestamp for any message received
-            last_data.store(current_time_ms(), Ordering::Relaxed);
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
@@ -219,6 +426,7 @@ impl ParadexAdapter {
                         Ok(v) => v,
                         Err(e) => {
                             tracing::debug!("Paradex WS: failed to parse JSON: {}", e);
+                            health.parse_stats.record_failed();
                             continue;
                         }
                     };
@@ -226,7 +434,7 @@ impl ParadexAdapter {
 
 
                     // Convert pre-parsed JSON to typed message (no re-parsing)
-                    match serde_json::from_value::<ParadexWsMessage>(json) {
+                    match health.parse_stats.observe(serde_json::from_value::<ParadexWsMessage>(json)) {
                         Ok(msg) => {
                             match msg {
                                 ParadexWsMessage::SubscriptionNotification(notif) => {
@@ -239,20 +447,19 @@ impl ParadexAdapter {
                                         levels = notif.params.data.inserts.len(),
                                         "Paradex subscription orderbook update received"
                                     );
+                                    if let Some(expected) = seq_tracker.record(&symbol, notif.params.data.seq_no) {
+                                        Self::resubscribe_after_gap(&ws_sender, &rpc_router, &symbol, expected, notif.params.data.seq_no).await;
+                                    }
 
                                     // Convert to orderbook (with USDâ†’USDC conversion if rate available)
                                     let usdc_rate = usdc_rate_cache.as_ref().map(|c| c.get_rate());
                                     match notif.params.data.to_orderbook(usdc_rate) {
                                         Ok(orderbook) => {
+                                            health.ingest_latency.record("paradex", orderbook.timestamp, current_time_ms());
                                             // Write atomic best prices FIRST (lock-free hot path)
-                                            shared_best_prices.store(
-                                                orderbook.best_bid().unwrap_or(0.0),
-                                                orderbook.best_ask().unwrap_or(0.0),
-                                            );
-                                            if let Some(ref n) = orderbook_notify { n.notify_waiters(); }
-                                            // Update shared orderbook (acquire lock briefly)
-                                            let mut books = shared_orderbooks.write().await;
-                                            books.insert(symbol.clone(), orderbook);
+                                            best_prices.publish(&orderbook);
+                                            // Update shared orderbook (lock taken at most once per interval)
+                                            book_writer.write(symbol.clone(), orderbook).await;
                                             tracing::trace!(symbol = %symbol, "Paradex orderbook updated from subscription");
                                         }
                                         Err(e) => {
@@ -269,20 +476,19 @@ impl ParadexAdapter {
                                         levels = orderbook_msg.data.inserts.len(),
                                         "Paradex orderbook update received (direct format)"
                                     );
+                                    if let Some(expected) = seq_tracker.record(&symbol, orderbook_msg.data.seq_no) {
+                                        Self::resubscribe_after_gap(&ws_sender, &rpc_router, &symbol, expected, orderbook_msg.data.seq_no).await;
+                                    }
 
                                     // Convert to orderbook (with USDâ†’USDC conversion if rate available)
                                     let usdc_rate = usdc_rate_cache.as_ref().map(|c| c.get_rate());
                                     match orderbook_msg.data.to_orderbook(usdc_rate) {
                                         Ok(orderbook) => {
+                                            health.ingest_latency.record("paradex", orderbook.timestamp, current_time_ms());
                                             // Write atomic best prices FIRST (lock-free hot path)
-                                            shared_best_prices.store(
-                                                orderbook.best_bid().unwrap_or(0.0),
-                                                orderbook.best_ask().unwrap_or(0.0),
-                                            );
-                                            if let Some(ref n) = orderbook_notify { n.notify_waiters(); }
-                                            // Update shared orderbook (acquire lock briefly)
-                                            let mut books = shared_orderbooks.write().await;
-                                            books.insert(symbol.clone(), orderbook);
+                                            best_prices.publish(&orderbook);
+                                            // Update shared orderbook (lock taken at most once per interval)
+                                            book_writer.write(symbol.clone(), orderbook).await;
                                             tracing::trace!(symbol = %symbol, "Paradex orderbook updated in shared storage");
                                         }
                                         Err(e) => {
@@ -291,15 +497,35 @@ impl ParadexAdapter {
                                     }
                                 }
                                 ParadexWsMessage::JsonRpc(rpc_resp) => {
-                                    // JSON-RPC response - subscription confirmation, etc.
+                                    // JSON-RPC response - routed to its request by id
+                                    let request = rpc_router.resolve(rpc_resp.id);
+                                    rpc_router.complete(
+                                        rpc_resp.id,
+                                        match &rpc_resp.error {
+                                            Some(err) => Err(format!("{}: {}", err.code, err.message)),
+                                            None => Ok(()),
+                                        },
+                                    );
                                     if let Some(err) = rpc_resp.error {
                                         tracing::warn!(
+                                            id = rpc_resp.id,
+                                            request = ?request,
                                             "JSON-RPC error {}: {}",
                                             err.code,
                                             err.message
                                         );
+                                    } else if let Some(request) = request {
+                                        tracing::debug!(
+                                            id = rpc_resp.id,
+                                            method = request.method,
+                                            symbol = %request.symbol,
+                                            "JSON-RPC request confirmed"
+                                        );
                                     } else {
-                                        tracing::debug!("JSON-RPC response: id={}", rpc_resp.id);
+                                        tracing::debug!(
+                                            "JSON-RPC response for unknown id={}",
+                                            rpc_resp.id
+                                        );
                                     }
                                 }
                             }
@@ -331,28 +557,90 @@ impl ParadexAdapter {
                 }
             }
         }
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("Paradex message reader loop ended â€” reader_alive set to false");
     }
 
-    /// Send a subscribe request for a symbol's orderbook
-    async fn send_subscribe_request(&self, symbol: &str) -> ExchangeResult<u64> {
-        let ws_sender = self
-            .ws_sender
-            .as_ref()
-            .ok_or_else(|| ExchangeError::ConnectionFailed("WebSocket not connected".into()))?;
+    /// Re-subscribe `symbol` after an out-of-order `seq_no` so the next
+    /// message is a fresh snapshot
+    async fn resubscribe_after_gap(
+        ws_sender: &Mutex<WsSink>,
+        rpc_router: &RpcRouter,
+        symbol: &str,
+        expected: u64,
+        received: u64,
+    ) {
+        tracing::warn!(
+            symbol = %symbol,
+            expected = expected,
+            received = received,
+            "SEQ_GAP: Paradex orderbook sequence out of order, re-subscribing"
+        );
+        for method in ["unsubscribe", "subscribe"] {
+            if let Err(e) = Self::send_channel_request(ws_sender, rpc_router, method, symbol).await {
+                tracing::warn!(symbol = %symbol, method = method, error = %e, "SEQ_GAP: re-subscribe failed");
+                return;
+            }
+        }
+    }
+
+    /// Re-subscribe `symbols` after a reconnect, retrying retryable failures with backoff
+    ///
+    /// A symbol the exchange rejects (a fatal error) or that still fails after
+    /// `retry.max_attempts` is reported as `SUBSCRIPTION_LOST` and left out of
+    /// the active subscriptions; its book was cleared on disconnect, so the
+    /// leg reads as missing rather than stale. Returns the lost symbols.
+    async fn restore_subscriptions(&mut self, symbols: &[String], retry: &ReconnectConfig) -> Vec<String> {
+        let mut lost = Vec::new();
+        for symbol in symbols {
+            tracing::info!("Paradex: Re-subscribing to {}", symbol);
+            let mut attempt = 0;
+            while let Err(e) = self.subscribe_orderbook(symbol).await {
+                attempt += 1;
+                if e.is_fatal() || attempt >= retry.max_attempts {
+                    tracing::warn!(
+                        event_type = "SUBSCRIPTION_LOST",
+                        exchange = "paradex",
+                        symbol = %symbol,
+                        attempts = attempt,
+                        error = %e,
+                        "Could not restore subscription after reconnect — leg is dead"
+                    );
+                    lost.push(symbol.clone());
+                    break;
+                }
+                let backoff_ms = backoff_delay_ms(retry, attempt - 1);
+                tracing::warn!(
+                    symbol = %symbol,
+                    attempt = attempt,
+                    retry_in_ms = backoff_ms,
+                    error = %e,
+                    "Paradex: Re-subscribe failed, retrying"
+                );
+                tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+            }
+        }
+        lost
+    }
 
-        let sub_id = next_subscription_id();
+    /// Send a JSON-RPC `method` (subscribe/unsubscribe) for a symbol's orderbook channel
+    async fn send_channel_request(
+        ws_sender: &Mutex<WsSink>,
+        rpc_router: &RpcRouter,
+        method: &'static str,
+        symbol: &str,
+    ) -> ExchangeResult<(u64, oneshot::Receiver<RpcOutcome>)> {
+        let (id, response) = rpc_router.register_awaited(method, symbol);
         // Paradex orderbook channel format: order_book.{symbol}.snapshot@15@100ms
         let channel = format!("order_book.{}.snapshot@15@100ms", symbol);
 
         let msg = serde_json::json!({
             "jsonrpc": "2.0",
-            "method": "subscribe",
+            "method": method,
             "params": {
                 "channel": channel
             },
-            "id": sub_id
+            "id": id
         });
 
         let mut sender = ws_sender.lock().await;
@@ -361,7 +649,19 @@ impl ParadexAdapter {
             .await
             .map_err(|e| ExchangeError::WebSocket(Box::new(e)))?;
 
-        Ok(sub_id)
+        Ok((id, response))
+    }
+
+    /// Send a subscribe request for a symbol's orderbook
+    async fn send_subscribe_request(
+        &self,
+        symbol: &str,
+    ) -> ExchangeResult<(u64, oneshot::Receiver<RpcOutcome>)> {
+        let ws_sender = self
+            .ws_sender
+            .as_ref()
+            .ok_or_else(|| ExchangeError::ConnectionFailed("WebSocket not connected".into()))?;
+        Self::send_channel_request(ws_sender, &self.rpc_router, "subscribe", symbol).await
     }
 
     /// Send an unsubscribe request for a symbol's orderbook
@@ -370,25 +670,8 @@ impl ParadexAdapter {
             .ws_sender
             .as_ref()
             .ok_or_else(|| ExchangeError::ConnectionFailed("WebSocket not connected".into()))?;
-
-        let unsub_id = next_subscription_id();
-        let channel = format!("order_book.{}.snapshot@15@100ms", symbol);
-
-        let msg = serde_json::json!({
-            "jsonrpc": "2.0",
-            "method": "unsubscribe",
-            "params": {
-                "channel": channel
-            },
-            "id": unsub_id
-        });
-
-        let mut sender = ws_sender.lock().await;
-        sender
-            .send(Message::Text(msg.to_string()))
-            .await
-            .map_err(|e| ExchangeError::WebSocket(Box::new(e)))?;
-
+        let (unsub_id, _) = Self::send_channel_request(ws_sender, &self.rpc_router, "unsubscribe", symbol).await?;
+        self.seq_tracker.forget(symbol);
         Ok(unsub_id)
     }
 
@@ -398,20 +681,20 @@ impl ParadexAdapter {
     ///
     /// Paradex uses native WebSocket PING/PONG which tokio-tungstenite handles automatically.
     /// This task monitors the last_data timestamp to detect stale connections.
-    /// If data is stale for more than STALE_THRESHOLD_MS, sets `reader_alive = false`
-    /// so that `is_stale()` detects the dead connection.
+    /// If data is stale for more than `config.stale_threshold_secs`, sets
+    /// `reader_alive = false` so that `is_stale()` detects the dead connection.
     fn spawn_heartbeat_task(&mut self) {
         let last_data = Arc::clone(&self.connection_health.last_data);
         let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let interval_secs = self.config.heartbeat_interval_secs;
+        let stale_threshold_ms = self.config.stale_threshold_ms();
 
         // Initialize last_data to now so we don't immediately appear stale
         last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            // Check every 30 seconds as per NFR20
-            let mut interval = tokio::time::interval(Duration::from_secs(
-                crate::adapters::types::WS_PING_INTERVAL_SECS,
-            ));
+            // Check every heartbeat_interval_secs (30s default as per NFR20)
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
             // Skip the first immediate tick
             interval.tick().await;
 
@@ -422,11 +705,11 @@ impl ParadexAdapter {
                 let now = current_time_ms();
                 let age_ms = now.saturating_sub(last);
 
-                if age_ms > crate::adapters::types::STALE_THRESHOLD_MS {
+                if age_ms > stale_threshold_ms {
                     tracing::warn!(
                         "Paradex heartbeat: data stale for {}ms (threshold {}ms) â€” signaling dead connection",
                         age_ms,
-                        crate::adapters::types::STALE_THRESHOLD_MS
+                        stale_threshold_ms
                     );
                     reader_alive.store(false, Ordering::Relaxed);
                     break;
@@ -440,7 +723,10 @@ impl ParadexAdapter {
         });
 
         self.heartbeat_handle = Some(handle);
-        tracing::info!("Paradex: Heartbeat monitoring started (30s interval)");
+        tracing::info!(
+            "Paradex: Heartbeat monitoring started ({}s interval)",
+            interval_secs
+        );
     }
 
     /// Warm up HTTP connection pool by making a lightweight request
@@ -505,6 +791,7 @@ impl ExchangeAdapter for ParadexAdapter {
         }
 
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!("Paradex adapter fully connected");
 
         Ok(())
@@ -515,10 +802,7 @@ impl ExchangeAdapter for ParadexAdapter {
         tracing::info!("Disconnecting from Paradex...");
 
         // Set state to Disconnected
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = crate::adapters::types::ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         // Cancel reader task
         if let Some(handle) = self.reader_handle.take() {
@@ -561,14 +845,16 @@ impl ExchangeAdapter for ParadexAdapter {
     }
 
     /// Subscribe to orderbook updates for a trading symbol
+    ///
+    /// Returns once the server confirms the subscription; a JSON-RPC error
+    /// or no response within `subscribe_timeout_ms` is an error.
     async fn subscribe_orderbook(&mut self, symbol: &str) -> ExchangeResult<()> {
         if !self.connected {
             return Err(ExchangeError::ConnectionFailed("Not connected".into()));
         }
 
-        let sub_id = self.send_subscribe_request(symbol).await?;
-        self.pending_subscriptions
-            .insert(sub_id, symbol.to_string());
+        let (sub_id, response) = self.send_subscribe_request(symbol).await?;
+        await_subscription(response, symbol, self.config.subscribe_timeout_ms).await?;
         self.subscriptions.push(symbol.to_string());
 
         tracing::info!(
@@ -634,9 +920,66 @@ impl ExchangeAdapter for ParadexAdapter {
         }
 
         let now = current_time_ms();
-        use crate::adapters::types::STALE_THRESHOLD_MS;
+        now.saturating_sub(last_data) > self.config.stale_threshold_ms()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            rest_snapshot: true,
+            market_info: true,
+            ..Capabilities::ORDERBOOK_ONLY
+        }
+    }
+
+    /// Fetch the current book via `GET /orderbook/{market}`
+    async fn fetch_orderbook_snapshot(&self, symbol: &str) -> ExchangeResult<Orderbook> {
+        let url = format!("{}/orderbook/{}", self.config.rest_base_url(), symbol);
 
-        now.saturating_sub(last_data) > STALE_THRESHOLD_MS
+        let response = self.http_client.get(&url).send().await.map_err(|e| {
+            ExchangeError::ConnectionFailed(format!("Orderbook snapshot request failed: {}", e))
+        })?;
+        if !response.status().is_success() {
+            return Err(ExchangeError::InvalidResponse(format!(
+                "Orderbook snapshot returned {}",
+                response.status()
+            )));
+        }
+
+        let snapshot: ParadexOrderbookSnapshot = response.json().await.map_err(|e| {
+            ExchangeError::InvalidResponse(format!("Invalid orderbook snapshot: {}", e))
+        })?;
+        let usdc_rate = self.usdc_rate_cache.as_ref().map(|c| c.get_rate());
+        snapshot.to_orderbook(usdc_rate)
+    }
+
+    /// Fetch trading rules via `GET /markets?market={market}`, cached per symbol
+    async fn fetch_market_info(&self, symbol: &str) -> ExchangeResult<MarketInfo> {
+        if let Some(info) = self.market_info.read().await.get(symbol) {
+            return Ok(info.clone());
+        }
+        let url = format!("{}/markets", self.config.rest_base_url());
+
+        let response = self.http_client.get(&url).query(&[("market", symbol)]).send().await.map_err(|e| {
+            ExchangeError::ConnectionFailed(format!("Markets request failed: {}", e))
+        })?;
+        if !response.status().is_success() {
+            return Err(ExchangeError::InvalidResponse(format!(
+                "Markets request returned {}",
+                response.status()
+            )));
+        }
+
+        let markets: ParadexMarketsResponse = response.json().await.map_err(|e| {
+            ExchangeError::InvalidResponse(format!("Invalid markets response: {}", e))
+        })?;
+        let info = markets
+            .results
+            .iter()
+            .find(|m| m.symbol == symbol)
+            .ok_or_else(|| ExchangeError::InvalidResponse(format!("Market {} not listed", symbol)))?
+            .to_market_info()?;
+        self.market_info.write().await.insert(symbol.to_string(), info.clone());
+        Ok(info)
     }
 
     async fn sync_orderbooks(&mut self) {
@@ -645,15 +988,10 @@ impl ExchangeAdapter for ParadexAdapter {
     }
 
     async fn reconnect(&mut self) -> ExchangeResult<()> {
-        use crate::adapters::types::ConnectionState;
-
         tracing::info!("Paradex: Initiating reconnection...");
 
         // Set state to Reconnecting
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Reconnecting;
-        }
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
         // Store current subscriptions before disconnecting
         let saved_subscriptions = self.subscriptions.clone();
@@ -661,45 +999,34 @@ impl ExchangeAdapter for ParadexAdapter {
         // Disconnect (cleans up resources but we'll override state)
         self.disconnect().await?;
 
-        // Exponential backoff retry loop
-        // Delays: 500ms, 1000ms, 2000ms, cap at 5000ms. Max 3 attempts.
-        const MAX_RECONNECT_ATTEMPTS: u32 = 3;
+        // Exponential backoff retry loop with full jitter
+        // Delays: up to 500ms, 1000ms, 2000ms, cap at 5000ms. Max 3 attempts.
+        let reconnect_config = ReconnectConfig::default();
         let mut last_error: Option<ExchangeError> = None;
 
-        for attempt in 0..MAX_RECONNECT_ATTEMPTS {
-            // Exponential backoff: 500ms * 2^attempt, capped at 5000ms
-            let backoff_ms = std::cmp::min(500 * (1u64 << attempt), 5000);
+        for attempt in 0..reconnect_config.max_attempts {
+            let backoff_ms = backoff_delay_ms(&reconnect_config, attempt);
             tracing::info!(
                 "Paradex: Reconnect attempt {} of {}, waiting {}ms...",
                 attempt + 1,
-                MAX_RECONNECT_ATTEMPTS,
+                reconnect_config.max_attempts,
                 backoff_ms
             );
 
             tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
 
             // Update state to Reconnecting for each attempt
-            {
-                let mut state = self.connection_health.state.write().await;
-                *state = ConnectionState::Reconnecting;
-            }
+            self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
             // Try to reconnect
             match self.connect().await {
                 Ok(()) => {
                     // Success! Set state to Connected
-                    {
-                        let mut state = self.connection_health.state.write().await;
-                        *state = ConnectionState::Connected;
-                    }
+                    self.connection_health.set_state(ConnectionState::Connected).await;
 
                     // Re-subscribe to all previously subscribed symbols
-                    for symbol in &saved_subscriptions {
-                        tracing::info!("Paradex: Re-subscribing to {}", symbol);
-                        if let Err(e) = self.subscribe_orderbook(symbol).await {
-                            tracing::warn!("Paradex: Failed to re-subscribe to {}: {}", symbol, e);
-                        }
-                    }
+                    self.lost_subscriptions =
+                        self.restore_subscriptions(&saved_subscriptions, &reconnect_config).await;
 
                     tracing::info!(
                         "Paradex: Reconnection complete with {} subscriptions restored",
@@ -708,6 +1035,12 @@ impl ExchangeAdapter for ParadexAdapter {
 
                     return Ok(());
                 }
+                Err(e) if e.is_maintenance() || e.is_fatal() => {
+                    // Not an attempt: the caller waits out the maintenance
+                    // window, or gives up on a fatal error
+                    self.connection_health.set_state(ConnectionState::Disconnected).await;
+                    return Err(e);
+                }
                 Err(e) => {
                     tracing::warn!("Paradex: Reconnect attempt {} failed: {}", attempt + 1, e);
                     last_error = Some(e);
@@ -716,10 +1049,7 @@ impl ExchangeAdapter for ParadexAdapter {
         }
 
         // All attempts failed - set state to Disconnected
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         Err(last_error.unwrap_or_else(|| {
             ExchangeError::ConnectionFailed("Reconnection failed after max attempts".into())
@@ -745,6 +1075,19 @@ impl ExchangeAdapter for ParadexAdapter {
         self.orderbook_notify = Some(notify);
     }
 
+    fn set_book_tap(&mut self, tap: crate::core::channels::BookTap) -> bool {
+        self.book_tap = Some(tap);
+        true
+    }
+
+    fn lost_subscriptions(&self) -> Vec<String> {
+        self.lost_subscriptions.clone()
+    }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
+
 }
 
 // =============================================================================
@@ -778,6 +1121,226 @@ mod tests {
         assert_eq!(adapter.exchange_name(), "paradex");
     }
 
+    #[tokio::test]
+    async fn test_connection_events_ordered_through_disconnect() {
+        use crate::adapters::types::ConnectionEvent;
+
+        let mut adapter = ParadexAdapter::new(ParadexConfig::default());
+        let mut events = adapter.subscribe_connection_events();
+
+        // Final step of connect(), without the network round-trip
+        adapter.connection_health.set_state(ConnectionState::Connected).await;
+        adapter.disconnect().await.unwrap();
+
+        assert_eq!(events.try_recv().unwrap(), ConnectionEvent::Connected);
+        assert_eq!(events.try_recv().unwrap(), ConnectionEvent::Disconnected);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_rpc_ids_unique_per_connection_and_skip_auth_id() {
+        let first = RpcRouter::new();
+        let ids: Vec<u64> = ["BTC-USD-PERP", "ETH-USD-PERP", "SOL-USD-PERP"]
+            .iter()
+            .map(|s| first.register("subscribe", s))
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert!(!ids.contains(&AUTH_RPC_ID));
+
+        // A new connection gets its own id space; stale ids don't route there
+        let second = RpcRouter::new();
+        assert!(second.resolve(2).is_none());
+        assert_eq!(second.register("subscribe", "BTC-USD-PERP"), 1);
+        assert!(second.resolve(AUTH_RPC_ID).is_none());
+    }
+
+    #[test]
+    fn test_rpc_response_routed_by_id() {
+        let router = RpcRouter::new();
+        let sub = router.register("subscribe", "BTC-USD-PERP");
+        let unsub = router.register("unsubscribe", "ETH-USD-PERP");
+
+        let resolved = router.resolve(unsub).expect("unsubscribe pending");
+        assert_eq!(resolved.method, "unsubscribe");
+        assert_eq!(resolved.symbol, "ETH-USD-PERP");
+        assert_eq!(router.resolve(sub).unwrap().method, "subscribe");
+        // Each response resolves exactly once
+        assert!(router.resolve(sub).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_waits_for_matching_confirmation() {
+        let router = Arc::new(RpcRouter::new());
+        let (other, _other_response) = router.register_awaited("subscribe", "ETH-USD-PERP");
+        let (id, response) = router.register_awaited("subscribe", "BTC-USD-PERP");
+
+        // Reader loop sees the responses shortly after the request went out
+        let reader = Arc::clone(&router);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            reader.complete(other, Err("-32600: other request".into()));
+            reader.complete(id, Ok(()));
+        });
+
+        await_subscription(response, "BTC-USD-PERP", 1_000).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_rejection_and_timeout_are_errors() {
+        let router = RpcRouter::new();
+
+        let (id, response) = router.register_awaited("subscribe", "FOO-USD-PERP");
+        router.complete(id, Err("-32602: unknown channel".into()));
+        let err = await_subscription(response, "FOO-USD-PERP", 1_000).await.unwrap_err();
+        assert!(matches!(err, ExchangeError::SubscriptionFailed { .. }), "{err}");
+
+        let (_id, response) = router.register_awaited("subscribe", "BTC-USD-PERP");
+        let err = await_subscription(response, "BTC-USD-PERP", 30).await.unwrap_err();
+        assert!(matches!(err, ExchangeError::NetworkTimeout(30)), "{err}");
+    }
+
+    /// Paradex-like WS server confirming every subscribe except for `reject`;
+    /// counts the subscribe requests per channel
+    async fn spawn_mock_ws(reject: &'static str) -> (String, Arc<std::sync::Mutex<HashMap<String, u32>>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = Arc::new(std::sync::Mutex::new(HashMap::new()));
+        let counts = Arc::clone(&requests);
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = ws.next().await {
+                let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                let channel = request["params"]["channel"].as_str().unwrap().to_string();
+                *counts.lock().unwrap().entry(channel.clone()).or_insert(0) += 1;
+                let response = if channel.contains(reject) {
+                    serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "error": {"code": -32602, "message": "unknown channel"}})
+                } else {
+                    serde_json::json!({"jsonrpc": "2.0", "id": request["id"], "result": {"channel": channel}})
+                };
+                ws.send(Message::Text(response.to_string())).await.unwrap();
+            }
+        });
+        (format!("ws://{}", addr), requests)
+    }
+
+    #[tokio::test]
+    async fn test_restore_subscriptions_reports_rejected_channel_lost() {
+        let (url, requests) = spawn_mock_ws("ETH-USD-PERP").await;
+        let (stream, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let mut adapter = ParadexAdapter::new(ParadexConfig::default());
+        adapter.ws_stream = Some(Mutex::new(stream));
+        adapter.split_and_spawn_reader().unwrap();
+        adapter.connected = true;
+
+        let retry = ReconnectConfig { max_attempts: 3, initial_delay_ms: 5, max_delay_ms: 20, jitter: false };
+        let saved = vec!["BTC-USD-PERP".to_string(), "ETH-USD-PERP".to_string()];
+        let lost = adapter.restore_subscriptions(&saved, &retry).await;
+
+        assert_eq!(lost, vec!["ETH-USD-PERP".to_string()]);
+        assert_eq!(adapter.subscriptions, vec!["BTC-USD-PERP".to_string()]);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests["order_book.BTC-USD-PERP.snapshot@15@100ms"], 1);
+        assert_eq!(requests["order_book.ETH-USD-PERP.snapshot@15@100ms"], 1, "a rejected channel is not retried");
+    }
+
+    #[tokio::test]
+    async fn test_reader_counts_parsed_and_malformed_messages() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            for id in 1..=3 {
+                let response = serde_json::json!({"jsonrpc": "2.0", "id": id, "result": {}});
+                ws.send(Message::Text(response.to_string())).await.unwrap();
+            }
+            // Not JSON, then JSON of no known shape
+            ws.send(Message::Text("not json".into())).await.unwrap();
+            ws.send(Message::Text(r#"{"unexpected":true}"#.into())).await.unwrap();
+            ws.close(None).await.unwrap();
+        });
+        let (stream, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let mut adapter = ParadexAdapter::new(ParadexConfig::default());
+        adapter.ws_stream = Some(Mutex::new(stream));
+        adapter.split_and_spawn_reader().unwrap();
+        tokio::time::timeout(Duration::from_secs(2), adapter.reader_handle.take().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(adapter.parse_stats(), (3, 2));
+    }
+
+    #[test]
+    fn test_seq_gap_detected_only_when_sequence_goes_back() {
+        let tracker = SeqTracker::new();
+        let btc = "BTC-USD-PERP";
+
+        // First message, in-order updates and conflated jumps are accepted
+        assert_eq!(tracker.record(btc, 100), None);
+        assert_eq!(tracker.record(btc, 101), None);
+        assert_eq!(tracker.record(btc, 104), None);
+        // Markets are tracked independently
+        assert_eq!(tracker.record("ETH-USD-PERP", 7), None);
+
+        // Older message: anything after 104 was acceptable
+        assert_eq!(tracker.record(btc, 99), Some(105));
+        // The re-subscribed snapshot starts a new sequence
+        assert_eq!(tracker.record(btc, 10), None);
+        // Duplicates are out of order too
+        assert_eq!(tracker.record(btc, 10), Some(11));
+
+        assert_eq!(tracker.record("ETH-USD-PERP", 8), None);
+    }
+
+    #[tokio::test]
+    async fn test_conflated_snapshot_sequence_does_not_resubscribe() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            for seq_no in [1, 4, 9] {
+                let notification = serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "subscription",
+                    "params": {
+                        "channel": "order_book.BTC-USD-PERP.snapshot@15@100ms",
+                        "data": {
+                            "market": "BTC-USD-PERP",
+                            "inserts": [
+                                {"price": "42000", "size": "1", "side": "BID"},
+                                {"price": "42010", "size": "1", "side": "ASK"}
+                            ],
+                            "last_updated_at": 1700000000000u64 + seq_no,
+                            "seq_no": seq_no
+                        }
+                    }
+                });
+                ws.send(Message::Text(notification.to_string())).await.unwrap();
+            }
+            // Count re-subscribe requests coming back
+            let mut requests = 0;
+            while let Ok(Some(Ok(Message::Text(_)))) =
+                tokio::time::timeout(Duration::from_millis(200), ws.next()).await
+            {
+                requests += 1;
+            }
+            requests
+        });
+        let (stream, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        let mut adapter = ParadexAdapter::new(ParadexConfig::default());
+        adapter.ws_stream = Some(Mutex::new(stream));
+        adapter.split_and_spawn_reader().unwrap();
+
+        assert_eq!(server.await.unwrap(), 0, "no SEQ_GAP re-subscribe");
+        assert_eq!(adapter.parse_stats(), (3, 0));
+    }
+
     /// Test exchange name returns "paradex"
     #[test]
     fn test_exchange_name() {
@@ -794,6 +1357,27 @@ mod tests {
         assert!(!adapter.is_connected());
     }
 
+    /// Test is_stale() honours the configured stale threshold
+    #[test]
+    fn test_is_stale_uses_configured_threshold() {
+        let config = ParadexConfig {
+            stale_threshold_secs: 60,
+            ..Default::default()
+        };
+        let mut adapter = ParadexAdapter::new(config);
+        adapter.connected = true;
+        adapter.connection_health.reader_alive.store(true, Ordering::Relaxed);
+
+        // Just under the threshold — still fresh
+        let now = current_time_ms();
+        adapter.connection_health.last_data.store(now - 59_000, Ordering::Relaxed);
+        assert!(!adapter.is_stale());
+
+        // Just over the threshold — stale
+        adapter.connection_health.last_data.store(now - 61_000, Ordering::Relaxed);
+        assert!(adapter.is_stale());
+    }
+
     /// Test warm_up_http() functionality
     /// Unit test for connection warm-up functionality
     /// Note: warm_up_http() uses the HTTP client which works independently of WS connection
@@ -2,15 +2,44 @@
 //!
 //! Configuration structures for Paradex exchange connection.
 
+use std::collections::HashMap;
+
+use crate::adapters::shared::parse_header_list;
+use crate::adapters::types::{STALE_THRESHOLD_MS, WS_PING_INTERVAL_SECS};
+
 // =============================================================================
 // Configuration
 // =============================================================================
 
+/// Default wait for a subscription confirmation (ms)
+pub const DEFAULT_SUBSCRIBE_TIMEOUT_MS: u64 = 5_000;
+
 /// Configuration for Paradex exchange connection (public data only)
 #[derive(Debug, Clone)]
 pub struct ParadexConfig {
     /// Use production endpoints (true) or testnet (false)
     pub production: bool,
+    /// How often the heartbeat task checks data freshness (seconds)
+    pub heartbeat_interval_secs: u64,
+    /// Silence after which the connection is considered stale (seconds).
+    ///
+    /// Used by both the heartbeat task (which marks the reader dead) and
+    /// `is_stale()` (which reports staleness between heartbeat ticks), so a
+    /// stale connection is detected at most `heartbeat_interval_secs` late.
+    pub stale_threshold_secs: u64,
+    /// Extra headers sent on every outbound HTTP request and the WS handshake
+    /// (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+    /// Override for the REST base URL (regional proxies, local mocks)
+    pub rest_url: Option<String>,
+    /// Minimum interval between writes to the shared orderbooks (ms).
+    /// Updates arriving faster are coalesced; 0 writes every update.
+    pub min_update_interval_ms: u64,
+    /// Wake the monitoring loop on every book update, not only when the
+    /// best bid/ask changes (debugging)
+    pub notify_every_update: bool,
+    /// How long `subscribe_orderbook` waits for the server to confirm (ms)
+    pub subscribe_timeout_ms: u64,
 }
 
 impl ParadexConfig {
@@ -20,12 +49,52 @@ impl ParadexConfig {
             .map(|v| v == "true" || v == "1")
             .unwrap_or(true);
 
-        Self { production }
+        let heartbeat_interval_secs = std::env::var("PARADEX_HEARTBEAT_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(WS_PING_INTERVAL_SECS);
+        let stale_threshold_secs = std::env::var("PARADEX_STALE_THRESHOLD_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(STALE_THRESHOLD_MS / 1000);
+        let extra_headers = std::env::var("PARADEX_EXTRA_HEADERS")
+            .map(|v| parse_header_list(&v))
+            .unwrap_or_default();
+        let rest_url = std::env::var("PARADEX_REST_URL").ok();
+        let min_update_interval_ms = std::env::var("PARADEX_MIN_UPDATE_INTERVAL_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+        let notify_every_update = std::env::var("PARADEX_NOTIFY_EVERY_UPDATE")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+        let subscribe_timeout_ms = std::env::var("PARADEX_SUBSCRIBE_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_SUBSCRIBE_TIMEOUT_MS);
+
+        Self {
+            production,
+            heartbeat_interval_secs,
+            stale_threshold_secs,
+            extra_headers,
+            rest_url,
+            min_update_interval_ms,
+            notify_every_update,
+            subscribe_timeout_ms,
+        }
+    }
+
+    /// Stale threshold in milliseconds
+    pub fn stale_threshold_ms(&self) -> u64 {
+        self.stale_threshold_secs * 1000
     }
 
     /// Get REST API base URL
-    pub fn rest_base_url(&self) -> &'static str {
-        if self.production {
+    pub fn rest_base_url(&self) -> &str {
+        if let Some(url) = &self.rest_url {
+            url
+        } else if self.production {
             "https://api.prod.paradex.trade/v1"
         } else {
             "https://api.testnet.paradex.trade/v1"
@@ -44,7 +113,16 @@ impl ParadexConfig {
 
 impl Default for ParadexConfig {
     fn default() -> Self {
-        Self { production: true }
+        Self {
+            production: true,
+            heartbeat_interval_secs: WS_PING_INTERVAL_SECS,
+            stale_threshold_secs: STALE_THRESHOLD_MS / 1000,
+            extra_headers: HashMap::new(),
+            rest_url: None,
+            min_update_interval_ms: 0,
+            notify_every_update: false,
+            subscribe_timeout_ms: DEFAULT_SUBSCRIBE_TIMEOUT_MS,
+        }
     }
 }
 
@@ -58,14 +136,25 @@ mod tests {
         assert!(config.production);
     }
 
+    #[test]
+    fn test_paradex_config_default_heartbeat() {
+        let config = ParadexConfig::default();
+        assert_eq!(config.heartbeat_interval_secs, 30);
+        assert_eq!(config.stale_threshold_secs, 30);
+        assert_eq!(config.stale_threshold_ms(), 30_000);
+    }
+
     #[test]
     fn test_paradex_config_urls() {
-        let prod_config = ParadexConfig { production: true };
+        let prod_config = ParadexConfig { production: true, ..Default::default() };
         assert!(prod_config.rest_base_url().contains("prod"));
         assert!(prod_config.ws_base_url().contains("prod"));
 
-        let test_config = ParadexConfig { production: false };
+        let test_config = ParadexConfig { production: false, ..Default::default() };
         assert!(test_config.rest_base_url().contains("testnet"));
         assert!(test_config.ws_base_url().contains("testnet"));
+
+        let proxied = ParadexConfig { rest_url: Some("http://127.0.0.1:8080".into()), ..Default::default() };
+        assert_eq!(proxied.rest_base_url(), "http://127.0.0.1:8080");
     }
 }
@@ -15,4 +15,6 @@ mod types;
 // Re-export public items
 pub use adapter::ParadexAdapter;
 pub use config::ParadexConfig;
-pub use types::{ParadexOrderbookData, ParadexOrderbookLevel, ParadexOrderbookMessage};
+pub use types::{
+    ParadexOrderbookData, ParadexOrderbookLevel, ParadexOrderbookMessage, ParadexOrderbookSnapshot,
+};
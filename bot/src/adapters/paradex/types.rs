@@ -6,7 +6,7 @@
 use serde::Deserialize;
 
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
-use crate::adapters::types::{Orderbook, OrderbookLevel};
+use crate::adapters::types::{MarketInfo, Orderbook, OrderbookLevel};
 
 // =============================================================================
 // WebSocket Message Types
@@ -202,6 +202,81 @@ impl ParadexOrderbookData {
     }
 }
 
+/// REST orderbook snapshot (`GET /orderbook/{market}`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParadexOrderbookSnapshot {
+    /// Market symbol
+    pub market: String,
+    /// Bid levels as `[price, size]`
+    #[serde(default)]
+    pub bids: Vec<[String; 2]>,
+    /// Ask levels as `[price, size]`
+    #[serde(default)]
+    pub asks: Vec<[String; 2]>,
+    /// Timestamp in milliseconds
+    pub last_updated_at: u64,
+    /// Sequence number
+    pub seq_no: u64,
+}
+
+impl ParadexOrderbookSnapshot {
+    /// Convert to Orderbook the same way as WebSocket updates
+    /// (BBO only, optional USD→USDC conversion)
+    pub fn to_orderbook(&self, usdc_rate: Option<f64>) -> ExchangeResult<Orderbook> {
+        let level = |[price, size]: &[String; 2], side: &str| ParadexOrderbookLevel {
+            price: price.clone(),
+            size: size.clone(),
+            side: side.to_string(),
+        };
+        let inserts = self
+            .bids
+            .iter()
+            .map(|l| level(l, "BID"))
+            .chain(self.asks.iter().map(|l| level(l, "ASK")))
+            .collect();
+
+        ParadexOrderbookData {
+            market: self.market.clone(),
+            inserts,
+            last_updated_at: self.last_updated_at,
+            seq_no: self.seq_no,
+        }
+        .to_orderbook(usdc_rate)
+    }
+}
+
+/// REST markets listing (`GET /markets`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParadexMarketsResponse {
+    pub results: Vec<ParadexMarket>,
+}
+
+/// One market's trading rules (other fields ignored)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ParadexMarket {
+    pub symbol: String,
+    /// Minimum price increment, e.g. "0.1"
+    pub price_tick_size: String,
+    /// Minimum order size increment, e.g. "0.001"
+    pub order_size_increment: String,
+    /// Minimum order value in USD
+    #[serde(default)]
+    pub min_notional: Option<String>,
+}
+
+impl ParadexMarket {
+    /// Convert to our canonical MarketInfo
+    pub fn to_market_info(&self) -> ExchangeResult<MarketInfo> {
+        let min_notional = match &self.min_notional {
+            Some(value) => value.parse::<f64>().map_err(|e| {
+                ExchangeError::InvalidResponse(format!("{} min_notional '{}': {}", self.symbol, value, e))
+            })?,
+            None => 0.0,
+        };
+        MarketInfo::from_increments(&self.symbol, &self.price_tick_size, &self.order_size_increment, min_notional)
+    }
+}
+
 /// Subscription confirmation response
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)] // Used by serde to parse subscription confirmations
@@ -338,4 +413,48 @@ mod tests {
             orderbook.asks[0].price
         );
     }
+
+    #[test]
+    fn test_rest_snapshot_to_orderbook() {
+        let json = r#"{
+            "market": "BTC-USD-PERP",
+            "seq_no": 42,
+            "last_updated_at": 1700000000000,
+            "bids": [["41990.0", "0.5"], ["42000.0", "1.2"]],
+            "asks": [["42020.0", "0.8"], ["42010.0", "0.3"]]
+        }"#;
+        let snapshot: ParadexOrderbookSnapshot = serde_json::from_str(json).unwrap();
+        let orderbook = snapshot.to_orderbook(None).unwrap();
+
+        assert_eq!(orderbook.best_bid(), Some(42000.0));
+        assert_eq!(orderbook.best_ask(), Some(42010.0));
+        assert_eq!(orderbook.timestamp, 1700000000000);
+    }
+
+    #[test]
+    fn test_markets_response_to_market_info() {
+        let json = r#"{
+            "results": [{
+                "symbol": "BTC-USD-PERP",
+                "base_currency": "BTC",
+                "quote_currency": "USD",
+                "settlement_currency": "USDC",
+                "order_size_increment": "0.00001",
+                "price_tick_size": "0.1",
+                "min_notional": "100",
+                "open_at": 0,
+                "expiry_at": 0,
+                "asset_kind": "PERP",
+                "max_funding_rate": "0.05"
+            }]
+        }"#;
+        let response: ParadexMarketsResponse = serde_json::from_str(json).unwrap();
+        let info = response.results[0].to_market_info().unwrap();
+
+        assert_eq!(info.symbol, "BTC-USD-PERP");
+        assert_eq!(info.tick_size, 0.1);
+        assert_eq!(info.size_step, 0.00001);
+        assert_eq!(info.min_notional, 100.0);
+        assert_eq!((info.price_decimals, info.size_decimals), (1, 5));
+    }
 }
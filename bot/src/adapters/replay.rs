@@ -0,0 +1,352 @@
+//! Record and replay orderbook feeds
+//!
+//! `RecordingLayer` appends orderbook updates to a JSONL file, one
+//! [`RecordedBook`] per line. `ReplayAdapter` reads such a file back and
+//! feeds one exchange's books through the regular `ExchangeAdapter`
+//! interface, so the whole pipeline can be re-run offline against a
+//! captured session — either at the recorded cadence or as fast as possible.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+use tracing::info;
+
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::factory::SUPPORTED_EXCHANGES;
+use crate::adapters::traits::ExchangeAdapter;
+use crate::adapters::types::{ConnectionHealth, ConnectionState, Orderbook};
+use crate::core::channels::{
+    AtomicBestPrices, BestPricePublisher, OrderbookNotify, SharedBestPrices, SharedOrderbooks,
+};
+use crate::core::events::FileEventSink;
+use crate::core::types::current_time_ms;
+
+// =============================================================================
+// Recording
+// =============================================================================
+
+/// One orderbook state as seen by an adapter
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedBook {
+    /// When the book was recorded (Unix ms)
+    pub timestamp_ms: u64,
+    /// Exchange name (e.g. "vest")
+    pub exchange: String,
+    /// Exchange-specific symbol (e.g. "BTC-PERP")
+    pub symbol: String,
+    pub orderbook: Orderbook,
+}
+
+/// Appends orderbook updates to a JSONL file for later replay
+pub struct RecordingLayer {
+    sink: FileEventSink,
+}
+
+impl RecordingLayer {
+    /// Open (or create) `path` for appending.
+    pub async fn new(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self { sink: FileEventSink::new(path).await? })
+    }
+
+    /// Append `orderbook` for `exchange`/`symbol`, stamped with the current time.
+    pub async fn record(&self, exchange: &str, symbol: &str, orderbook: &Orderbook) -> std::io::Result<()> {
+        self.record_at(current_time_ms(), exchange, symbol, orderbook).await
+    }
+
+    /// Append `orderbook` for `exchange`/`symbol`, received at `timestamp_ms`.
+    pub async fn record_at(
+        &self,
+        timestamp_ms: u64,
+        exchange: &str,
+        symbol: &str,
+        orderbook: &Orderbook,
+    ) -> std::io::Result<()> {
+        let record = RecordedBook {
+            timestamp_ms,
+            exchange: exchange.to_string(),
+            symbol: symbol.to_string(),
+            orderbook: orderbook.clone(),
+        };
+        self.sink.write_record(&record).await
+    }
+
+    /// Flush any buffered records to disk.
+    pub async fn flush(&self) -> std::io::Result<()> {
+        self.sink.flush().await
+    }
+}
+
+/// Read the records for `exchange` from a recording, in file order.
+pub async fn load_recording(path: &Path, exchange: &str) -> ExchangeResult<Vec<RecordedBook>> {
+    let contents = tokio::fs::read_to_string(path).await.map_err(|e| {
+        ExchangeError::InvalidInput(format!("cannot read recording {}: {}", path.display(), e))
+    })?;
+
+    let mut records = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: RecordedBook = serde_json::from_str(line).map_err(|e| {
+            ExchangeError::InvalidInput(format!("{} line {}: {}", path.display(), index + 1, e))
+        })?;
+        if record.exchange == exchange {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+// =============================================================================
+// Replay
+// =============================================================================
+
+/// How fast a recording is played back
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReplayPace {
+    /// Wait the recorded gap between consecutive books
+    #[default]
+    Recorded,
+    /// Emit books back to back, yielding between them
+    Unthrottled,
+}
+
+/// Adapter that plays back one exchange's books from a recording
+///
+/// `connect` loads the file and starts playback; once the recording is
+/// exhausted the last books stay in place and the adapter stays connected.
+pub struct ReplayAdapter {
+    exchange: &'static str,
+    path: PathBuf,
+    pace: ReplayPace,
+    orderbooks: HashMap<String, Orderbook>,
+    shared_orderbooks: SharedOrderbooks,
+    shared_best_prices: SharedBestPrices,
+    orderbook_notify: Option<OrderbookNotify>,
+    connection_health: ConnectionHealth,
+    player: Option<JoinHandle<()>>,
+    connected: bool,
+}
+
+impl ReplayAdapter {
+    /// Replay `exchange`'s books from the recording at `path`.
+    pub fn new(exchange: &str, path: impl Into<PathBuf>, pace: ReplayPace) -> ExchangeResult<Self> {
+        let exchange = SUPPORTED_EXCHANGES
+            .iter()
+            .find(|name| **name == exchange)
+            .copied()
+            .ok_or_else(|| ExchangeError::UnknownExchange(exchange.to_string()))?;
+        Ok(Self {
+            exchange,
+            path: path.into(),
+            pace,
+            orderbooks: HashMap::new(),
+            shared_orderbooks: Arc::new(RwLock::new(HashMap::new())),
+            shared_best_prices: Arc::new(AtomicBestPrices::new()),
+            orderbook_notify: None,
+            connection_health: ConnectionHealth::new(),
+            player: None,
+            connected: false,
+        })
+    }
+
+    /// Whether playback has reached the end of the recording
+    pub fn is_finished(&self) -> bool {
+        self.player.as_ref().is_some_and(|player| player.is_finished())
+    }
+}
+
+/// Write each record into shared storage, pacing as requested
+async fn play(
+    records: Vec<RecordedBook>,
+    pace: ReplayPace,
+    books: SharedOrderbooks,
+    publisher: BestPricePublisher,
+    health: ConnectionHealth,
+    exchange: &'static str,
+) {
+    let total = records.len();
+    let mut previous_ms: Option<u64> = None;
+    for record in records {
+        match (pace, previous_ms) {
+            (ReplayPace::Recorded, Some(previous)) => {
+                let gap = record.timestamp_ms.saturating_sub(previous);
+                tokio::time::sleep(tokio::time::Duration::from_millis(gap)).await;
+            }
+            (ReplayPace::Recorded, None) => {}
+            (ReplayPace::Unthrottled, _) => tokio::task::yield_now().await,
+        }
+        previous_ms = Some(record.timestamp_ms);
+
+        // Shift the exchange time onto the replay clock, keeping how far it
+        // trailed the recorder, so freshness checks see the recorded age
+        let now = current_time_ms();
+        let mut orderbook = record.orderbook;
+        if orderbook.timestamp > 0 {
+            orderbook.timestamp = now.saturating_sub(record.timestamp_ms.saturating_sub(orderbook.timestamp));
+        }
+        health.last_data.store(now, Ordering::Relaxed);
+        books.write().await.insert(record.symbol, orderbook.clone());
+        publisher.publish(&orderbook);
+    }
+    info!(exchange = %exchange, books = total, "Replay finished");
+}
+
+#[async_trait]
+impl ExchangeAdapter for ReplayAdapter {
+    async fn connect(&mut self) -> ExchangeResult<()> {
+        let records = load_recording(&self.path, self.exchange).await?;
+        info!(
+            exchange = %self.exchange,
+            path = %self.path.display(),
+            books = records.len(),
+            pace = ?self.pace,
+            "Replaying recorded orderbooks"
+        );
+
+        let publisher = BestPricePublisher::new(
+            self.shared_best_prices.clone(),
+            self.orderbook_notify.clone(),
+            false,
+        );
+        self.player = Some(tokio::spawn(play(
+            records,
+            self.pace,
+            self.shared_orderbooks.clone(),
+            publisher,
+            self.connection_health.clone_refs(),
+            self.exchange,
+        )));
+        self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> ExchangeResult<()> {
+        if let Some(player) = self.player.take() {
+            player.abort();
+        }
+        self.connected = false;
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
+        Ok(())
+    }
+
+    /// Every book in the recording is replayed; subscriptions only select
+    /// which of them the manager reads.
+    async fn subscribe_orderbook(&mut self, _symbol: &str) -> ExchangeResult<()> {
+        Ok(())
+    }
+
+    async fn unsubscribe_orderbook(&mut self, _symbol: &str) -> ExchangeResult<()> {
+        Ok(())
+    }
+
+    fn get_orderbook(&self, symbol: &str) -> Option<&Orderbook> {
+        self.orderbooks.get(symbol)
+    }
+
+    fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    /// A finished recording is quiet, not stale.
+    fn is_stale(&self) -> bool {
+        false
+    }
+
+    async fn sync_orderbooks(&mut self) {
+        let books = self.shared_orderbooks.read().await;
+        self.orderbooks = books.clone();
+    }
+
+    async fn reconnect(&mut self) -> ExchangeResult<()> {
+        if self.connected {
+            return Ok(());
+        }
+        self.connect().await
+    }
+
+    fn exchange_name(&self) -> &'static str {
+        self.exchange
+    }
+
+    fn get_shared_orderbooks(&self) -> SharedOrderbooks {
+        self.shared_orderbooks.clone()
+    }
+
+    fn get_shared_best_prices(&self) -> SharedBestPrices {
+        self.shared_best_prices.clone()
+    }
+
+    fn set_orderbook_notify(&mut self, notify: OrderbookNotify) {
+        self.orderbook_notify = Some(notify);
+    }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_replay_reproduces_recorded_books() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("books.jsonl");
+
+        let recorder = RecordingLayer::new(&path).await.unwrap();
+        let book = |bid: f64, ask: f64| Orderbook::from_levels(&[(bid, 1.0), (bid - 1.0, 2.0)], &[(ask, 1.5)]);
+        recorder.record("vest", "BTC-PERP", &book(42000.0, 42010.0)).await.unwrap();
+        recorder.record("paradex", "BTC-USD-PERP", &book(42005.0, 42015.0)).await.unwrap();
+        let latest_btc = book(42020.0, 42030.0);
+        recorder.record("vest", "BTC-PERP", &latest_btc).await.unwrap();
+        recorder.record("vest", "ETH-PERP", &book(2200.0, 2201.0)).await.unwrap();
+        recorder.flush().await.unwrap();
+
+        let records = load_recording(&path, "vest").await.unwrap();
+        let symbols: Vec<&str> = records.iter().map(|r| r.symbol.as_str()).collect();
+        assert_eq!(symbols, vec!["BTC-PERP", "BTC-PERP", "ETH-PERP"]);
+
+        let mut adapter = ReplayAdapter::new("vest", &path, ReplayPace::Unthrottled).unwrap();
+        adapter.connect().await.unwrap();
+        while !adapter.is_finished() {
+            tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
+        }
+        adapter.sync_orderbooks().await;
+
+        assert!(adapter.is_connected());
+        assert_eq!(adapter.exchange_name(), "vest");
+        let btc = adapter.get_orderbook("BTC-PERP").expect("BTC replayed");
+        assert_eq!(btc.bids, latest_btc.bids);
+        assert_eq!(btc.asks, latest_btc.asks);
+        assert_eq!(adapter.get_orderbook("ETH-PERP").unwrap().best_ask(), Some(2201.0));
+        assert!(adapter.get_orderbook("BTC-USD-PERP").is_none(), "other exchanges are skipped");
+        assert_eq!(adapter.get_shared_best_prices().load(), (2200.0, 2201.0));
+    }
+
+    #[tokio::test]
+    async fn test_replay_rejects_malformed_recording() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("books.jsonl");
+        std::fs::write(&path, "not json\n").unwrap();
+
+        // Fatal, so the manager stops the adapter instead of retrying forever
+        let mut adapter = ReplayAdapter::new("vest", &path, ReplayPace::Recorded).unwrap();
+        let err = adapter.connect().await.unwrap_err();
+        assert!(matches!(err, ExchangeError::InvalidInput(_)) && err.is_fatal());
+        let mut missing = ReplayAdapter::new("vest", dir.path().join("missing.jsonl"), ReplayPace::Recorded).unwrap();
+        assert!(missing.connect().await.unwrap_err().is_fatal());
+        assert!(matches!(
+            ReplayAdapter::new("nasdaq", &path, ReplayPace::Recorded),
+            Err(ExchangeError::UnknownExchange(_))
+        ));
+    }
+}
@@ -1,13 +1,21 @@
 //! Reya Configuration
+use std::collections::HashMap;
+
+use crate::adapters::shared::extra_headers_from_env;
+
 const MAINNET_WS_URL: &str = "wss://ws.reya.xyz";
 
 #[derive(Debug, Clone)]
-pub struct ReyaConfig { pub production: bool }
-impl Default for ReyaConfig { fn default() -> Self { Self { production: true } } }
+pub struct ReyaConfig {
+    pub production: bool,
+    /// Extra headers sent on the WS handshake (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+}
+impl Default for ReyaConfig { fn default() -> Self { Self { production: true, extra_headers: HashMap::new() } } }
 impl ReyaConfig {
     pub fn from_env() -> Self {
         let production = std::env::var("REYA_PRODUCTION").unwrap_or_else(|_| "true".to_string()).parse::<bool>().unwrap_or(true);
-        Self { production }
+        Self { production, extra_headers: extra_headers_from_env("REYA_EXTRA_HEADERS") }
     }
     pub fn ws_url(&self) -> &str { MAINNET_WS_URL }
 }
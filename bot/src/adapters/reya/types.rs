@@ -28,8 +28,15 @@ pub(crate) enum ReyaWsMessage {
         #[serde(default)]
         data: Vec<ReyaPriceItem>,
     },
+    #[serde(rename = "unsubscribed")]
+    Unsubscribed,
     #[serde(rename = "pong")]
     Pong,
+    #[serde(rename = "error")]
+    Error {
+        #[serde(default)]
+        message: Option<String>,
+    },
 }
 
 /// Map Reya's symbol strings to canonical symbols.
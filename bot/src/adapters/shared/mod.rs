@@ -6,5 +6,11 @@
 pub mod reconnect;
 pub mod websocket;
 
-pub use reconnect::{reconnect_with_backoff, ReconnectConfig};
-pub use websocket::{connect_tls, connect_tls_with_request};
+pub use reconnect::{
+    backoff_delay_ms, reconnect_with_backoff, MaintenanceBackoff, ReconnectConfig, ReconnectStagger,
+};
+pub use websocket::{
+    add_headers, build_ws_request, connect_timeout, connect_tls, connect_tls_with_headers,
+    connect_tls_with_request, connect_tls_with_timeout, extra_headers_from_env, parse_header_list,
+    tls_connector, DEFAULT_CONNECT_TIMEOUT_SECS,
+};
@@ -1,7 +1,12 @@
 //! Shared reconnection logic with exponential backoff
 //!
 //! Provides a generic reconnection helper used by all exchange adapters.
-//! Implements exponential backoff with jitter to prevent thundering herd issues.
+//! Implements exponential backoff with full jitter to prevent thundering herd issues.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::Instant;
 
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
 
@@ -14,6 +19,8 @@ pub struct ReconnectConfig {
     pub initial_delay_ms: u64,
     /// Maximum delay cap in milliseconds
     pub max_delay_ms: u64,
+    /// Randomize each delay in `[0, backoff]` (full jitter); disable for deterministic tests
+    pub jitter: bool,
 }
 
 impl Default for ReconnectConfig {
@@ -22,16 +29,122 @@ impl Default for ReconnectConfig {
             max_attempts: 3,
             initial_delay_ms: 500,
             max_delay_ms: 5000,
+            jitter: true,
+        }
+    }
+}
+
+/// Fixed delay used instead of exponential backoff while an exchange is in
+/// a maintenance window
+///
+/// Maintenance can last many minutes; waiting a fixed, long interval avoids
+/// burning reconnect attempts against an exchange that will refuse them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaintenanceBackoff {
+    /// Delay before retrying after a maintenance response (ms)
+    pub delay_ms: u64,
+}
+
+impl MaintenanceBackoff {
+    /// Default wait between reconnects during maintenance
+    pub const DEFAULT_DELAY_MS: u64 = 60_000;
+
+    /// Read `MAINTENANCE_BACKOFF_MS`, falling back to the default
+    pub fn from_env() -> Self {
+        let delay_ms = std::env::var("MAINTENANCE_BACKOFF_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(Self::DEFAULT_DELAY_MS);
+        Self { delay_ms }
+    }
+
+    /// Delay before the next reconnect if `error` signals maintenance;
+    /// `None` means use the normal backoff
+    pub fn delay_for(&self, error: &ExchangeError) -> Option<Duration> {
+        error
+            .is_maintenance()
+            .then(|| Duration::from_millis(self.delay_ms))
+    }
+}
+
+impl Default for MaintenanceBackoff {
+    fn default() -> Self {
+        Self {
+            delay_ms: Self::DEFAULT_DELAY_MS,
         }
     }
 }
 
+/// Spaces adapters' reconnect starts at least `gap` apart
+///
+/// Feeds that drop together (a network blip) would otherwise all reconnect
+/// at once and leave every leg down at the same time. Cloned into each
+/// adapter task; each reconnect starts no sooner than `gap` after the
+/// previous one started, however long that one takes.
+#[derive(Debug, Clone)]
+pub struct ReconnectStagger {
+    gap: Duration,
+    last_start: Arc<Mutex<Option<Instant>>>,
+}
+
+impl ReconnectStagger {
+    /// Space reconnect starts at least `gap` apart
+    pub fn new(gap: Duration) -> Self {
+        Self { gap, last_start: Arc::new(Mutex::new(None)) }
+    }
+
+    /// Space reconnect starts by `config.initial_delay_ms`
+    pub fn from_config(config: &ReconnectConfig) -> Self {
+        Self::new(Duration::from_millis(config.initial_delay_ms))
+    }
+
+    /// Claim the next reconnect start slot and return how long to wait for it.
+    ///
+    /// Nothing is held once this returns, so a slow or failing reconnect
+    /// does not block the other adapters; only the starts are spaced.
+    pub fn reserve_start(&self) -> Duration {
+        let now = Instant::now();
+        let mut last_start = self.last_start.lock().unwrap_or_else(|e| e.into_inner());
+        let start = match *last_start {
+            Some(at) => (at + self.gap).max(now),
+            None => now,
+        };
+        *last_start = Some(start);
+        start - now
+    }
+}
+
+impl Default for ReconnectStagger {
+    fn default() -> Self {
+        Self::from_config(&ReconnectConfig::default())
+    }
+}
+
+/// Compute the delay before reconnect attempt `attempt` (0-based)
+///
+/// The exponential backoff is `initial_delay_ms * 2^attempt`, capped at
+/// `max_delay_ms`. With `jitter` enabled, the delay is drawn uniformly from
+/// `[0, backoff]` ("full jitter") so connections that dropped together do
+/// not retry in lockstep.
+pub fn backoff_delay_ms(config: &ReconnectConfig, attempt: u32) -> u64 {
+    let backoff_ms = config
+        .initial_delay_ms
+        .saturating_mul(1u64.checked_shl(attempt).unwrap_or(u64::MAX))
+        .min(config.max_delay_ms);
+
+    if config.jitter {
+        rand::random::<u64>() % (backoff_ms + 1)
+    } else {
+        backoff_ms
+    }
+}
+
 /// Reconnect with exponential backoff and jitter
 ///
 /// This helper encapsulates the common reconnection pattern used across adapters.
 /// It implements exponential backoff (500ms, 1000ms, 2000ms...) capped at max_delay_ms,
-/// with random jitter (0-199ms) to prevent thundering herd when multiple connections
-/// reconnect simultaneously.
+/// with full jitter (see [`backoff_delay_ms`]) to prevent thundering herd when multiple
+/// connections reconnect simultaneously.
 ///
 /// # Type Parameters
 /// * `F` - Async closure that attempts connection, returns `ExchangeResult<()>`
@@ -43,7 +156,9 @@ impl Default for ReconnectConfig {
 ///
 /// # Returns
 /// * `Ok(())` - Reconnection successful
-/// * `Err(ExchangeError)` - All attempts failed
+/// * `Err(ExchangeError)` - All attempts failed, or the exchange reported
+///   maintenance (returned at once; see [`MaintenanceBackoff`]), or the
+///   error is fatal (returned at once; see [`ExchangeError::is_fatal`])
 ///
 /// # Example
 /// ```ignore
@@ -65,12 +180,8 @@ where
     let mut last_error: Option<ExchangeError> = None;
 
     for attempt in 0..config.max_attempts {
-        // D5: Jitter anti-thundering herd (0-199ms random)
-        let jitter = rand::random::<u64>() % 200;
-        let backoff_ms = std::cmp::min(
-            config.initial_delay_ms * (1u64 << attempt),
-            config.max_delay_ms,
-        ) + jitter;
+        // D5: Full jitter anti-thundering herd
+        let backoff_ms = backoff_delay_ms(&config, attempt);
 
         tracing::info!(
             "{}: Reconnect attempt {} of {}, waiting {}ms...",
@@ -84,6 +195,12 @@ where
 
         match connect_fn().await {
             Ok(()) => return Ok(()),
+            // Retrying on the normal schedule would only burn attempts
+            Err(e) if e.is_maintenance() => return Err(e),
+            Err(e) if e.is_fatal() => {
+                tracing::error!("{}: Reconnect failed with a fatal error: {}", exchange_name, e);
+                return Err(e);
+            }
             Err(e) => {
                 tracing::warn!(
                     "{}: Reconnect attempt {} failed: {}",
@@ -112,6 +229,7 @@ mod tests {
             max_attempts,
             initial_delay_ms: 10,
             max_delay_ms: 100,
+            jitter: false,
         }
     }
 
@@ -183,7 +301,7 @@ mod tests {
     #[tokio::test]
     async fn test_reconnect_backoff_increases() {
         // With initial_delay_ms=10, attempt 0 base = 10ms, attempt 1 base = 20ms
-        // Verify that elapsed time is at least sum of minimum delays (jitter adds extra)
+        // Verify that elapsed time is at least sum of minimum delays (jitter disabled)
         let start = std::time::Instant::now();
 
         let _ = reconnect_with_backoff(fast_config(3), "Test", || async {
@@ -192,9 +310,7 @@ mod tests {
         .await;
 
         let elapsed = start.elapsed().as_millis();
-        // 3 attempts: base delays 10 + 20 + 40 = 70ms minimum (without jitter cap)
-        // With jitter (0-199ms each) total could be up to 70 + 3*199 = 667ms
-        // Just verify elapsed >= minimum 70ms (base delays only)
+        // 3 attempts: base delays 10 + 20 + 40 = 70ms
         assert!(
             elapsed >= 30, // being lenient due to scheduling variance
             "Backoff should take at least 30ms, took {}ms",
@@ -215,6 +331,7 @@ mod tests {
             max_attempts: 6,
             initial_delay_ms: 10,
             max_delay_ms: 100,
+            jitter: false,
         };
 
         let start = std::time::Instant::now();
@@ -226,17 +343,112 @@ mod tests {
         let elapsed = start.elapsed().as_millis();
         // Without cap: 10+20+40+80+160+320 = 630ms base
         // With cap: 10+20+40+80+100+100 = 350ms base
-        // With up to 6*199 = 1194ms jitter, total <= 350+1194 = 1544ms
-        // Max time without cap would be 630+1194 = 1824ms
         // Just verify it ran (elapsed > 0)
         assert!(elapsed > 0, "Should have some delay");
     }
 
+    #[tokio::test]
+    async fn test_maintenance_stops_retries_without_using_attempts() {
+        let call_count = Arc::new(AtomicU32::new(0));
+        let cc = call_count.clone();
+
+        let result = reconnect_with_backoff(fast_config(3), "Test", || {
+            let cc = cc.clone();
+            async move {
+                cc.fetch_add(1, Ordering::SeqCst);
+                Err(ExchangeError::Maintenance("HTTP 503".into()))
+            }
+        })
+        .await;
+
+        assert!(result.unwrap_err().is_maintenance());
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_does_not_retry_fatal_error() {
+        let call_count = Arc::new(AtomicU32::new(0));
+        let cc = call_count.clone();
+
+        let result = reconnect_with_backoff(fast_config(3), "Test", || {
+            let cc = cc.clone();
+            async move {
+                cc.fetch_add(1, Ordering::SeqCst);
+                Err(ExchangeError::NotSupported("test".into()))
+            }
+        })
+        .await;
+
+        assert!(result.unwrap_err().is_fatal());
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_maintenance_backoff_only_applies_to_maintenance() {
+        let backoff = MaintenanceBackoff::default();
+        assert_eq!(
+            backoff.delay_for(&ExchangeError::Maintenance("HTTP 503".into())),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(backoff.delay_for(&ExchangeError::ConnectionFailed("refused".into())), None);
+    }
+
     #[test]
     fn test_reconnect_config_default() {
         let config = ReconnectConfig::default();
         assert_eq!(config.max_attempts, 3);
         assert_eq!(config.initial_delay_ms, 500);
         assert_eq!(config.max_delay_ms, 5000);
+        assert!(config.jitter);
+    }
+
+    #[test]
+    fn test_backoff_delay_without_jitter_is_deterministic() {
+        let config = fast_config(6);
+        let delays: Vec<u64> = (0..6).map(|a| backoff_delay_ms(&config, a)).collect();
+        assert_eq!(delays, vec![10, 20, 40, 80, 100, 100]);
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_bound() {
+        let config = ReconnectConfig::default();
+        for attempt in 0..8 {
+            let bound = std::cmp::min(500 * (1u64 << attempt), 5000);
+            for _ in 0..500 {
+                let delay = backoff_delay_ms(&config, attempt);
+                assert!(delay <= bound, "attempt {}: {} > {}", attempt, delay, bound);
+            }
+        }
+    }
+
+    #[test]
+    fn test_backoff_jitter_spreads_delays() {
+        let config = ReconnectConfig::default();
+        let samples: std::collections::HashSet<u64> =
+            (0..200).map(|_| backoff_delay_ms(&config, 3)).collect();
+        assert!(samples.len() > 1, "Jitter should produce varying delays");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_stagger_spaces_reconnect_starts() {
+        let stagger = ReconnectStagger::new(Duration::from_millis(500));
+        assert_eq!(stagger.reserve_start(), Duration::ZERO);
+
+        // Two more legs drop at the same moment: each gets the next slot
+        let other = stagger.clone();
+        assert_eq!(other.reserve_start(), Duration::from_millis(500));
+        assert_eq!(stagger.reserve_start(), Duration::from_millis(1_000));
+
+        // Nothing is held while a reconnect runs; only its start counts
+        tokio::time::advance(Duration::from_millis(1_600)).await;
+        assert_eq!(other.reserve_start(), Duration::ZERO);
+        tokio::time::advance(Duration::from_millis(200)).await;
+        assert_eq!(stagger.reserve_start(), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_backoff_huge_attempt_does_not_overflow() {
+        let config = ReconnectConfig { jitter: false, ..Default::default() };
+        assert_eq!(backoff_delay_ms(&config, 200), 5000);
     }
 }
@@ -1,54 +1,251 @@
 //! Shared WebSocket connection helpers
 //!
 //! Provides TLS-enabled WebSocket connection utilities used by all adapters.
+//! Every connection attempt is bounded by [`connect_timeout`], so one
+//! unreachable exchange cannot hang startup or a reconnect.
 
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::{HeaderName, HeaderValue, Request, StatusCode};
 use tokio_tungstenite::{
     connect_async_tls_with_config, Connector, MaybeTlsStream, WebSocketStream,
 };
 
-use crate::adapters::errors::ExchangeError;
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
 
 /// Type alias for the WebSocket stream with TLS
 pub type TlsWebSocketStream = WebSocketStream<MaybeTlsStream<tokio::net::TcpStream>>;
 
-/// Connect to a WebSocket endpoint with TLS (TLSv1.2 minimum)
+/// Default bound on TCP connect + TLS + WebSocket handshake (seconds)
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Bound on establishing a WebSocket connection
 ///
-/// This helper centralizes the TLS configuration for all exchange adapters,
-/// ensuring consistent security settings across connections.
+/// Read once from `WS_CONNECT_TIMEOUT_SECS`, falling back to
+/// [`DEFAULT_CONNECT_TIMEOUT_SECS`].
+pub fn connect_timeout() -> Duration {
+    static TIMEOUT: OnceLock<Duration> = OnceLock::new();
+    *TIMEOUT.get_or_init(|| {
+        let secs = std::env::var("WS_CONNECT_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_CONNECT_TIMEOUT_SECS);
+        Duration::from_secs(secs)
+    })
+}
+
+/// TLS connector shared by every WebSocket connection (TLSv1.2 minimum)
+///
+/// The single place the TLS configuration lives, so all exchange adapters
+/// get consistent security settings.
+pub fn tls_connector() -> ExchangeResult<Connector> {
+    let tls = native_tls::TlsConnector::builder()
+        .min_protocol_version(Some(native_tls::Protocol::Tlsv12))
+        .build()
+        .map_err(|e| ExchangeError::ConnectionFailed(format!("TLS error: {}", e)))?;
+    Ok(Connector::NativeTls(tls))
+}
+
+/// Connect to a WebSocket endpoint with TLS (TLSv1.2 minimum)
 ///
 /// # Arguments
 /// * `url` - WebSocket URL to connect to (wss://)
 ///
 /// # Returns
 /// * `Ok(TlsWebSocketStream)` - Connected WebSocket stream
-/// * `Err(ExchangeError)` - Connection or TLS error
-pub async fn connect_tls(url: &str) -> Result<TlsWebSocketStream, ExchangeError> {
-    let tls = native_tls::TlsConnector::builder()
-        .min_protocol_version(Some(native_tls::Protocol::Tlsv12))
-        .build()
-        .map_err(|e| ExchangeError::ConnectionFailed(format!("TLS error: {}", e)))?;
+/// * `Err(ExchangeError)` - `ConnectionFailed` for a malformed URL or TLS
+///   setup error, `WebSocket` for handshake/transport errors
+pub async fn connect_tls(url: &str) -> ExchangeResult<TlsWebSocketStream> {
+    let request = url
+        .into_client_request()
+        .map_err(|e| ExchangeError::ConnectionFailed(format!("Invalid WebSocket URL: {}", e)))?;
+    connect_tls_with_request(request).await
+}
 
-    let (ws_stream, _response) =
-        connect_async_tls_with_config(url, None, false, Some(Connector::NativeTls(tls)))
-            .await
-            .map_err(|e| ExchangeError::WebSocket(Box::new(e)))?;
+/// Connect to a WebSocket endpoint with TLS, adding `extra_headers` to the handshake
+///
+/// Falls back to [`connect_tls`] when no extra headers are configured.
+pub async fn connect_tls_with_headers(
+    url: &str,
+    extra_headers: &HashMap<String, String>,
+) -> Result<TlsWebSocketStream, ExchangeError> {
+    if extra_headers.is_empty() {
+        return connect_tls(url).await;
+    }
+    let request = build_ws_request(url, extra_headers)?;
+    connect_tls_with_request(request).await
+}
 
-    Ok(ws_stream)
+/// Build a WebSocket handshake request for `url` carrying `extra_headers`
+pub fn build_ws_request(
+    url: &str,
+    extra_headers: &HashMap<String, String>,
+) -> Result<Request<()>, ExchangeError> {
+    let mut request = url
+        .into_client_request()
+        .map_err(|e| ExchangeError::ConnectionFailed(format!("Invalid WebSocket URL: {}", e)))?;
+    add_headers(&mut request, extra_headers)?;
+    Ok(request)
+}
+
+/// Add `extra_headers` to an already built handshake `request`, replacing
+/// headers of the same name
+pub fn add_headers(
+    request: &mut Request<()>,
+    extra_headers: &HashMap<String, String>,
+) -> Result<(), ExchangeError> {
+    for (name, value) in extra_headers {
+        let name = HeaderName::from_bytes(name.as_bytes()).map_err(|e| {
+            ExchangeError::ConnectionFailed(format!("Invalid header name '{}': {}", name, e))
+        })?;
+        let value = HeaderValue::from_str(value).map_err(|e| {
+            ExchangeError::ConnectionFailed(format!("Invalid value for header '{}': {}", name, e))
+        })?;
+        request.headers_mut().insert(name, value);
+    }
+    Ok(())
+}
+
+/// Parse a `Name:Value,Name2:Value2` header list (as found in `*_EXTRA_HEADERS` env vars)
+///
+/// Entries without a `:` are skipped; names and values are trimmed.
+pub fn parse_header_list(raw: &str) -> HashMap<String, String> {
+    raw.split(',')
+        .filter_map(|entry| {
+            let (name, value) = entry.split_once(':')?;
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            Some((name.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Headers listed in the env var `var` (see [`parse_header_list`]), empty when unset
+pub fn extra_headers_from_env(var: &str) -> HashMap<String, String> {
+    std::env::var(var).map(|v| parse_header_list(&v)).unwrap_or_default()
 }
 
 /// Connect with custom HTTP headers (for exchanges that require User-Agent, Origin, etc.)
+///
+/// A `503 Service Unavailable` handshake response is reported as
+/// `ExchangeError::Maintenance`; no connection within [`connect_timeout`]
+/// as `ExchangeError::NetworkTimeout`.
 pub async fn connect_tls_with_request(
     request: tokio_tungstenite::tungstenite::http::Request<()>,
 ) -> Result<TlsWebSocketStream, ExchangeError> {
-    let tls = native_tls::TlsConnector::builder()
-        .min_protocol_version(Some(native_tls::Protocol::Tlsv12))
-        .build()
-        .map_err(|e| ExchangeError::ConnectionFailed(format!("TLS error: {}", e)))?;
+    connect_tls_with_timeout(request, connect_timeout()).await
+}
 
-    let (ws_stream, _response) =
-        connect_async_tls_with_config(request, None, false, Some(Connector::NativeTls(tls)))
-            .await
-            .map_err(|e| ExchangeError::WebSocket(Box::new(e)))?;
+/// Like [`connect_tls_with_request`], giving up after `timeout`
+pub async fn connect_tls_with_timeout(
+    request: tokio_tungstenite::tungstenite::http::Request<()>,
+    timeout: Duration,
+) -> Result<TlsWebSocketStream, ExchangeError> {
+    let connect = connect_async_tls_with_config(request, None, false, Some(tls_connector()?));
+    let (ws_stream, _response) = tokio::time::timeout(timeout, connect)
+        .await
+        .map_err(|_| ExchangeError::NetworkTimeout(timeout.as_millis() as u64))?
+        .map_err(handshake_error)?;
 
     Ok(ws_stream)
 }
+
+fn handshake_error(e: tokio_tungstenite::tungstenite::Error) -> ExchangeError {
+    match &e {
+        tokio_tungstenite::tungstenite::Error::Http(response)
+            if response.status() == StatusCode::SERVICE_UNAVAILABLE =>
+        {
+            ExchangeError::Maintenance("HTTP 503 on WebSocket handshake".to_string())
+        }
+        _ => ExchangeError::WebSocket(Box::new(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_ws_request_adds_extra_headers() {
+        let mut headers = HashMap::new();
+        headers.insert("X-Route".to_string(), "eu-1".to_string());
+        headers.insert("X-Trace-Id".to_string(), "abc123".to_string());
+
+        let request = build_ws_request("wss://example.com/ws", &headers).unwrap();
+        assert_eq!(request.headers().get("x-route").unwrap(), "eu-1");
+        assert_eq!(request.headers().get("x-trace-id").unwrap(), "abc123");
+        // Standard handshake headers are still present
+        assert!(request.headers().contains_key("sec-websocket-key"));
+    }
+
+    #[test]
+    fn test_build_ws_request_rejects_invalid_header() {
+        let mut headers = HashMap::new();
+        headers.insert("Bad Header".to_string(), "x".to_string());
+        assert!(build_ws_request("wss://example.com/ws", &headers).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_connect_tls_503_is_maintenance() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0u8; 1024];
+            let _ = socket.read(&mut buf).await;
+            let _ = socket
+                .write_all(b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n")
+                .await;
+        });
+
+        let err = connect_tls(&format!("ws://{}/ws", addr)).await.unwrap_err();
+        assert!(err.is_maintenance(), "got {err}");
+    }
+
+    #[tokio::test]
+    async fn test_connect_tls_hung_handshake_times_out() {
+        // Accepts the TCP connection but never answers the upgrade request
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let request = format!("ws://{}/ws", addr).into_client_request().unwrap();
+        let started = tokio::time::Instant::now();
+        let result = connect_tls_with_timeout(request, Duration::from_millis(200)).await;
+
+        assert!(matches!(result, Err(ExchangeError::NetworkTimeout(200))));
+        let elapsed = started.elapsed();
+        assert!(elapsed >= Duration::from_millis(200) && elapsed < Duration::from_secs(2), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_connect_tls_invalid_url_is_connection_error() {
+        for url in ["not a url", ""] {
+            match connect_tls(url).await {
+                Err(ExchangeError::ConnectionFailed(msg)) => {
+                    assert!(msg.contains("Invalid WebSocket URL"), "{url}: {msg}")
+                }
+                other => panic!("{url}: expected ConnectionFailed, got {:?}", other.map(|_| ())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_header_list() {
+        let headers = parse_header_list("X-Route: eu-1, X-Trace-Id:abc,garbage,:empty");
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers["X-Route"], "eu-1");
+        assert_eq!(headers["X-Trace-Id"], "abc");
+        assert!(parse_header_list("").is_empty());
+    }
+}
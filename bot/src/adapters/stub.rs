@@ -0,0 +1,134 @@
+//! In-memory adapter for tests
+//!
+//! `StubAdapter` answers every call without touching the network. Its books
+//! and best prices are plain shared handles a test writes to directly, and
+//! its disconnect/reconnect behaviour is picked with [`StubOutcome`], so the
+//! manager, latency wrapper and shutdown tests all drive the same stub.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::{Notify, RwLock};
+
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::traits::ExchangeAdapter;
+use crate::adapters::types::{ConnectionHealth, Orderbook};
+use crate::core::channels::{AtomicBestPrices, OrderbookNotify, SharedBestPrices, SharedOrderbooks};
+
+/// How [`StubAdapter`] answers `disconnect` and `reconnect`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) enum StubOutcome {
+    /// Both succeed
+    #[default]
+    Ok,
+    /// `disconnect` fails with `ConnectionFailed`
+    DisconnectError,
+    /// `disconnect` never returns
+    DisconnectHang,
+    /// `reconnect` fails at once with `ConnectionFailed`
+    Unreachable,
+}
+
+/// Adapter that is connected while its reader is alive
+/// (`health.reader_alive`) and reports `lost_on_reconnect` lost once it has
+/// reconnected. With a `reconnect_gate`, `reconnect` waits for a
+/// notification before connecting.
+pub(crate) struct StubAdapter {
+    pub outcome: StubOutcome,
+    pub lost_on_reconnect: Vec<String>,
+    pub reconnect_gate: Option<Arc<Notify>>,
+    /// Set once `disconnect` was called
+    pub disconnected: Arc<AtomicBool>,
+    pub reconnects: Arc<AtomicUsize>,
+    pub books: SharedOrderbooks,
+    pub best_prices: SharedBestPrices,
+    pub health: ConnectionHealth,
+}
+
+impl StubAdapter {
+    pub fn new() -> Self {
+        Self {
+            outcome: StubOutcome::Ok,
+            lost_on_reconnect: Vec::new(),
+            reconnect_gate: None,
+            disconnected: Arc::new(AtomicBool::new(false)),
+            reconnects: Arc::new(AtomicUsize::new(0)),
+            books: Arc::new(RwLock::new(HashMap::new())),
+            best_prices: Arc::new(AtomicBestPrices::new()),
+            health: ConnectionHealth::new(),
+        }
+    }
+
+    pub fn with_outcome(mut self, outcome: StubOutcome) -> Self {
+        self.outcome = outcome;
+        self
+    }
+}
+
+#[async_trait]
+impl ExchangeAdapter for StubAdapter {
+    async fn connect(&mut self) -> ExchangeResult<()> {
+        self.health.reader_alive.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+    async fn disconnect(&mut self) -> ExchangeResult<()> {
+        self.disconnected.store(true, Ordering::SeqCst);
+        self.health.reader_alive.store(false, Ordering::SeqCst);
+        match self.outcome {
+            StubOutcome::DisconnectError => Err(ExchangeError::ConnectionFailed("close frame rejected".into())),
+            StubOutcome::DisconnectHang => std::future::pending().await,
+            _ => Ok(()),
+        }
+    }
+    async fn subscribe_orderbook(&mut self, _symbol: &str) -> ExchangeResult<()> {
+        Ok(())
+    }
+    async fn unsubscribe_orderbook(&mut self, _symbol: &str) -> ExchangeResult<()> {
+        Ok(())
+    }
+    fn get_orderbook(&self, _symbol: &str) -> Option<&Orderbook> {
+        None
+    }
+    fn is_connected(&self) -> bool {
+        self.health.reader_alive.load(Ordering::SeqCst)
+    }
+    fn is_stale(&self) -> bool {
+        false
+    }
+    async fn fetch_orderbook_snapshot(&self, _symbol: &str) -> ExchangeResult<Orderbook> {
+        Ok(Orderbook::new())
+    }
+    async fn sync_orderbooks(&mut self) {}
+    async fn reconnect(&mut self) -> ExchangeResult<()> {
+        self.reconnects.fetch_add(1, Ordering::SeqCst);
+        if self.outcome == StubOutcome::Unreachable {
+            return Err(ExchangeError::ConnectionFailed("connection refused".into()));
+        }
+        if let Some(gate) = &self.reconnect_gate {
+            gate.notified().await;
+        }
+        self.connect().await
+    }
+    fn exchange_name(&self) -> &'static str {
+        "stub"
+    }
+    fn get_shared_orderbooks(&self) -> SharedOrderbooks {
+        self.books.clone()
+    }
+    fn get_shared_best_prices(&self) -> SharedBestPrices {
+        self.best_prices.clone()
+    }
+    fn set_orderbook_notify(&mut self, _notify: OrderbookNotify) {}
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.health
+    }
+    fn lost_subscriptions(&self) -> Vec<String> {
+        if self.reconnects.load(Ordering::SeqCst) > 0 {
+            self.lost_on_reconnect.clone()
+        } else {
+            Vec::new()
+        }
+    }
+}
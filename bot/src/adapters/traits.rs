@@ -4,10 +4,13 @@
 //! exchange adapters must implement for read-only market data access.
 
 use async_trait::async_trait;
+use tokio::sync::broadcast;
 
-use crate::adapters::errors::ExchangeResult;
-use crate::adapters::types::Orderbook;
-use crate::core::channels::{OrderbookNotify, SharedBestPrices, SharedOrderbooks};
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::types::{
+    Capabilities, ConnectionEvent, ConnectionHealth, LatencyPercentiles, MarketInfo, Orderbook,
+};
+use crate::core::channels::{BookTap, OrderbookNotify, SharedBestPrices, SharedOrderbooks};
 
 /// Common trait for all exchange adapters (read-only market data)
 ///
@@ -36,6 +39,31 @@ pub trait ExchangeAdapter: Send + Sync {
     /// Check if connection is stale (no data received in last 30 seconds)
     fn is_stale(&self) -> bool;
 
+    /// Optional features this adapter supports
+    ///
+    /// Adapters that override an optional method must report it here.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::ORDERBOOK_ONLY
+    }
+
+    /// Fetch a fresh orderbook for `symbol` over REST
+    ///
+    /// Used as a fallback while the WebSocket feed is stale. Adapters without
+    /// a REST orderbook endpoint return `ExchangeError::NotSupported`.
+    async fn fetch_orderbook_snapshot(&self, _symbol: &str) -> ExchangeResult<Orderbook> {
+        Err(ExchangeError::NotSupported(format!(
+            "{} REST orderbook snapshot",
+            self.exchange_name()
+        )))
+    }
+
+    /// Fetch `symbol`'s trading rules (tick size, size step, min notional)
+    ///
+    /// Adapters without a markets endpoint return `ExchangeError::NotSupported`.
+    async fn fetch_market_info(&self, _symbol: &str) -> ExchangeResult<MarketInfo> {
+        Err(ExchangeError::NotSupported(format!("{} market info", self.exchange_name())))
+    }
+
     /// Sync local orderbook cache from shared storage
     async fn sync_orderbooks(&mut self);
 
@@ -57,6 +85,45 @@ pub trait ExchangeAdapter: Send + Sync {
 
     /// Set the shared orderbook notification (event-driven monitoring)
     fn set_orderbook_notify(&mut self, notify: OrderbookNotify);
+
+    /// Send every book the reader writes to `tap`, for recording the raw
+    /// feed; takes effect on the next (re)connect. Returns `false` for
+    /// adapters without a tap point.
+    fn set_book_tap(&mut self, _tap: BookTap) -> bool {
+        false
+    }
+
+    /// Symbols whose subscription could not be restored by the last
+    /// `reconnect`; the manager reports those legs as unhealthy
+    fn lost_subscriptions(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Connection health tracker shared with the adapter's background tasks
+    fn connection_health(&self) -> &ConnectionHealth;
+
+    /// Smoothed WebSocket PING→PONG round-trip time (ms), `None` for
+    /// adapters relying on transport-level pings or before the first PONG
+    fn ws_rtt_ms(&self) -> Option<u64> {
+        self.connection_health().rtt.average_ms()
+    }
+
+    /// Exchange-timestamp → receive latency percentiles of recent book
+    /// updates, `None` for adapters whose feed carries no exchange timestamp
+    fn ingest_latency_ms(&self) -> Option<LatencyPercentiles> {
+        self.connection_health().ingest_latency.percentiles()
+    }
+
+    /// `(parsed, failed)` counts of inbound WebSocket messages
+    fn parse_stats(&self) -> (u64, u64) {
+        self.connection_health().parse_stats.counts()
+    }
+
+    /// Subscribe to connection state transitions (connected, disconnected,
+    /// reconnecting, stale), delivered in the order they happened
+    fn subscribe_connection_events(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.connection_health().subscribe_events()
+    }
 }
 
 
@@ -4,9 +4,11 @@
 //! orderbook representation and order management.
 
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
+
+use crate::adapters::errors::{ExchangeError, ExchangeResult};
 
 // =============================================================================
 // Shared Subscription ID Counter (Refactoring)
@@ -45,7 +47,35 @@ const HTTP_TCP_KEEPALIVE_SECS: u64 = 30;
 ///
 /// Connection pooling + TCP_NODELAY configured for latency optimization
 pub fn create_http_client(exchange_name: &str) -> reqwest::Client {
+    create_http_client_with_headers(exchange_name, &std::collections::HashMap::new())
+}
+
+/// Create an HTTP client that sends `extra_headers` on every request
+///
+/// Invalid header names/values are skipped with a warning.
+pub fn create_http_client_with_headers(
+    exchange_name: &str,
+    extra_headers: &std::collections::HashMap<String, String>,
+) -> reqwest::Client {
+    let mut default_headers = reqwest::header::HeaderMap::new();
+    for (name, value) in extra_headers {
+        match (
+            reqwest::header::HeaderName::from_bytes(name.as_bytes()),
+            reqwest::header::HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                default_headers.insert(name, value);
+            }
+            _ => tracing::warn!(
+                exchange = %exchange_name,
+                header = %name,
+                "Skipping invalid extra header"
+            ),
+        }
+    }
+
     let client = reqwest::Client::builder()
+        .default_headers(default_headers)
         .timeout(Duration::from_secs(HTTP_TIMEOUT_SECS))
         .pool_max_idle_per_host(HTTP_POOL_MAX_IDLE)
         .pool_idle_timeout(Duration::from_secs(HTTP_POOL_IDLE_TIMEOUT_SECS))
@@ -63,6 +93,7 @@ pub fn create_http_client(exchange_name: &str) -> reqwest::Client {
         pool_idle_timeout_s = HTTP_POOL_IDLE_TIMEOUT_SECS,
         tcp_keepalive_s = HTTP_TCP_KEEPALIVE_SECS,
         tcp_nodelay = true,
+        extra_headers = extra_headers.len(),
         "HTTP client configured"
     );
     client
@@ -81,6 +112,101 @@ pub const STALE_THRESHOLD_MS: u64 = 30_000;
 
 
 
+// =============================================================================
+// Adapter Capabilities
+// =============================================================================
+
+/// Optional features an adapter supports beyond the streaming orderbook
+///
+/// Lets callers skip unsupported calls up front instead of relying on
+/// `ExchangeError::NotSupported` on every attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// Streams orderbooks over WebSocket
+    pub orderbook: bool,
+    /// Serves `fetch_orderbook_snapshot` over REST
+    pub rest_snapshot: bool,
+    /// Serves `fetch_market_info` over REST
+    pub market_info: bool,
+}
+
+impl Capabilities {
+    /// Streaming orderbook only (the default for every adapter)
+    pub const ORDERBOOK_ONLY: Self = Self {
+        orderbook: true,
+        rest_snapshot: false,
+        market_info: false,
+    };
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::ORDERBOOK_ONLY
+    }
+}
+
+// =============================================================================
+// Market Metadata
+// =============================================================================
+
+/// Per-market trading rules from an exchange's markets endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketInfo {
+    /// Exchange-specific symbol (e.g. "BTC-USD-PERP")
+    pub symbol: String,
+    /// Minimum price increment
+    pub tick_size: f64,
+    /// Minimum order size increment
+    pub size_step: f64,
+    /// Smallest order value accepted, 0 if the exchange sets none
+    pub min_notional: f64,
+    pub price_decimals: u32,
+    pub size_decimals: u32,
+}
+
+impl MarketInfo {
+    /// Build from decimal-string increments (e.g. tick "0.1", step "0.001")
+    pub fn from_increments(
+        symbol: &str,
+        tick_size: &str,
+        size_step: &str,
+        min_notional: f64,
+    ) -> ExchangeResult<Self> {
+        let parse = |field: &str, value: &str| {
+            value.parse::<f64>().map_err(|e| {
+                ExchangeError::InvalidResponse(format!("{} {} '{}': {}", symbol, field, value, e))
+            })
+        };
+        Ok(Self {
+            symbol: symbol.to_string(),
+            tick_size: parse("tick size", tick_size)?,
+            size_step: parse("size step", size_step)?,
+            min_notional,
+            price_decimals: decimals(tick_size),
+            size_decimals: decimals(size_step),
+        })
+    }
+
+    /// Build from decimal counts, the increments being one unit in the last place
+    pub fn from_decimals(symbol: &str, price_decimals: u32, size_decimals: u32, min_notional: f64) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            tick_size: 10f64.powi(-(price_decimals as i32)),
+            size_step: 10f64.powi(-(size_decimals as i32)),
+            min_notional,
+            price_decimals,
+            size_decimals,
+        }
+    }
+}
+
+/// Significant fractional digits in a decimal string ("0.0010" → 3)
+fn decimals(value: &str) -> u32 {
+    value
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.trim_end_matches('0').len() as u32)
+}
+
 // =============================================================================
 // Connection Health Types
 // =============================================================================
@@ -97,6 +223,207 @@ pub enum ConnectionState {
     Reconnecting,
 }
 
+/// Connection state transition, broadcast to subscribers of an adapter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionEvent {
+    Connected,
+    Disconnected,
+    Reconnecting,
+    /// Connected but no data within the stale threshold
+    Stale,
+}
+
+impl From<ConnectionState> for ConnectionEvent {
+    fn from(state: ConnectionState) -> Self {
+        match state {
+            ConnectionState::Connected => ConnectionEvent::Connected,
+            ConnectionState::Disconnected => ConnectionEvent::Disconnected,
+            ConnectionState::Reconnecting => ConnectionEvent::Reconnecting,
+        }
+    }
+}
+
+/// Buffered connection events per subscriber before lagging
+const CONNECTION_EVENT_CAPACITY: usize = 32;
+
+/// Rolling WebSocket PING→PONG round-trip time
+///
+/// Only adapters that send application-level PINGs and see the matching
+/// PONG record samples; for the others (transport-level ping, e.g.
+/// Paradex) the RTT stays unavailable.
+#[derive(Debug, Clone)]
+pub struct RttTracker {
+    /// When the outstanding PING was sent (Unix ms, 0 = none in flight)
+    ping_sent_ms: Arc<AtomicU64>,
+    /// Smoothed RTT in ms ([`RttTracker::NO_SAMPLE`] until the first PONG)
+    average_ms: Arc<AtomicU64>,
+}
+
+impl RttTracker {
+    const NO_SAMPLE: u64 = u64::MAX;
+
+    /// Create a tracker with no samples
+    pub fn new() -> Self {
+        Self {
+            ping_sent_ms: Arc::new(AtomicU64::new(0)),
+            average_ms: Arc::new(AtomicU64::new(Self::NO_SAMPLE)),
+        }
+    }
+
+    /// Record that a PING went out at `now_ms`
+    pub fn record_ping(&self, now_ms: u64) {
+        self.ping_sent_ms.store(now_ms, Ordering::Relaxed);
+    }
+
+    /// Record the PONG for the outstanding PING, returning this sample's RTT.
+    ///
+    /// The average weighs the new sample 1/4. A PONG without an outstanding
+    /// PING is ignored.
+    pub fn record_pong(&self, now_ms: u64) -> Option<u64> {
+        let sent_ms = self.ping_sent_ms.swap(0, Ordering::Relaxed);
+        if sent_ms == 0 {
+            return None;
+        }
+        let rtt_ms = now_ms.saturating_sub(sent_ms);
+        let previous = self.average_ms.load(Ordering::Relaxed);
+        let average = if previous == Self::NO_SAMPLE {
+            rtt_ms
+        } else {
+            (previous * 3 + rtt_ms) / 4
+        };
+        self.average_ms.store(average, Ordering::Relaxed);
+        Some(rtt_ms)
+    }
+
+    /// Smoothed RTT in ms, `None` before the first sample
+    pub fn average_ms(&self) -> Option<u64> {
+        let average = self.average_ms.load(Ordering::Relaxed);
+        (average != Self::NO_SAMPLE).then_some(average)
+    }
+}
+
+impl Default for RttTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Inbound WebSocket messages that did / did not parse
+///
+/// A rising failure count on one exchange usually means its API changed
+/// under us.
+#[derive(Debug, Clone, Default)]
+pub struct ParseStats {
+    parsed: Arc<AtomicU64>,
+    failed: Arc<AtomicU64>,
+}
+
+impl ParseStats {
+    /// Tally a parse `result`, passing it through
+    ///
+    /// `result` must come from the exchange's full message type, acks,
+    /// heartbeats and errors included, so only frames that are not JSON or
+    /// match no known shape count as failed.
+    pub fn observe<T, E>(&self, result: Result<T, E>) -> Result<T, E> {
+        match result {
+            Ok(_) => self.parsed.fetch_add(1, Ordering::Relaxed),
+            Err(_) => self.failed.fetch_add(1, Ordering::Relaxed),
+        };
+        result
+    }
+
+    /// Count one message that failed to parse
+    pub fn record_failed(&self) {
+        self.failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// `(parsed, failed)` message counts
+    pub fn counts(&self) -> (u64, u64) {
+        (self.parsed.load(Ordering::Relaxed), self.failed.load(Ordering::Relaxed))
+    }
+}
+
+/// Ingestion latency percentiles in ms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    pub p50: u64,
+    pub p95: u64,
+    pub p99: u64,
+}
+
+/// Rolling exchange-timestamp → local-receive latency of book updates
+///
+/// Keeps the last [`IngestLatency::WINDOW`] samples in a lock-free ring,
+/// since it is fed from the readers' hot path. A negative latency
+/// (exchange clock ahead of ours) counts as 0; skew beyond
+/// [`IngestLatency::SKEW_WARN_MS`] is logged once per tracker.
+#[derive(Debug, Clone)]
+pub struct IngestLatency {
+    samples: Arc<[AtomicU64]>,
+    next: Arc<AtomicUsize>,
+    skew_warned: Arc<AtomicBool>,
+}
+
+impl Default for IngestLatency {
+    fn default() -> Self {
+        Self {
+            samples: (0..Self::WINDOW).map(|_| AtomicU64::new(Self::EMPTY)).collect(),
+            next: Arc::new(AtomicUsize::new(0)),
+            skew_warned: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+impl IngestLatency {
+    const WINDOW: usize = 512;
+    const SKEW_WARN_MS: u64 = 1_000;
+    /// Marks a ring slot not written yet
+    const EMPTY: u64 = u64::MAX;
+
+    /// Record one update stamped `exchange_ms` and received at `received_ms`,
+    /// returning the (clamped) latency
+    pub fn record(&self, exchange: &str, exchange_ms: u64, received_ms: u64) -> u64 {
+        if exchange_ms == 0 {
+            return 0;
+        }
+        let skew_ms = exchange_ms.saturating_sub(received_ms);
+        if skew_ms > Self::SKEW_WARN_MS && !self.skew_warned.swap(true, Ordering::Relaxed) {
+            tracing::warn!(
+                exchange = %exchange,
+                skew_ms,
+                "Exchange timestamps ahead of local clock, ingestion latency clamped to 0"
+            );
+        }
+        let latency_ms = received_ms.saturating_sub(exchange_ms);
+        let slot = self.next.fetch_add(1, Ordering::Relaxed) % Self::WINDOW;
+        self.samples[slot].store(latency_ms, Ordering::Relaxed);
+        latency_ms
+    }
+
+    /// Nearest-rank percentiles over the window, `None` before the first sample
+    pub fn percentiles(&self) -> Option<LatencyPercentiles> {
+        let mut sorted: Vec<u64> = self
+            .samples
+            .iter()
+            .map(|sample| sample.load(Ordering::Relaxed))
+            .filter(|&sample| sample != Self::EMPTY)
+            .collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_unstable();
+        let rank = |q: f64| {
+            let index = (q * sorted.len() as f64).ceil() as usize;
+            sorted[index.clamp(1, sorted.len()) - 1]
+        };
+        Some(LatencyPercentiles {
+            p50: rank(0.50),
+            p95: rank(0.95),
+            p99: rank(0.99),
+        })
+    }
+}
+
 /// Shared connection health state for tracking WebSocket health
 ///
 /// This struct contains atomic/lockable fields that can be shared
@@ -112,6 +439,16 @@ pub struct ConnectionHealth {
     /// Set to false when the WS reader loop exits (Close frame or error).
     /// Checked by is_stale() for immediate dead-connection detection.
     pub reader_alive: Arc<AtomicBool>,
+    /// PING→PONG round-trip time, for adapters that PING explicitly
+    pub rtt: RttTracker,
+    /// Exchange-timestamp → receive latency of book updates
+    pub ingest_latency: IngestLatency,
+    /// Parsed vs malformed inbound messages
+    pub parse_stats: ParseStats,
+    /// Broadcasts state transitions (see `set_state()` / `report_stale()`)
+    events: broadcast::Sender<ConnectionEvent>,
+    /// Whether `Stale` was already emitted for the current connection
+    stale_reported: Arc<AtomicBool>,
 }
 
 impl ConnectionHealth {
@@ -122,6 +459,11 @@ impl ConnectionHealth {
             last_pong: Arc::new(AtomicU64::new(0)),
             last_data: Arc::new(AtomicU64::new(0)),
             reader_alive: Arc::new(AtomicBool::new(false)),
+            rtt: RttTracker::new(),
+            ingest_latency: IngestLatency::default(),
+            parse_stats: ParseStats::default(),
+            events: broadcast::channel(CONNECTION_EVENT_CAPACITY).0,
+            stale_reported: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -132,8 +474,37 @@ impl ConnectionHealth {
             last_pong: Arc::clone(&self.last_pong),
             last_data: Arc::clone(&self.last_data),
             reader_alive: Arc::clone(&self.reader_alive),
+            rtt: self.rtt.clone(),
+            ingest_latency: self.ingest_latency.clone(),
+            parse_stats: self.parse_stats.clone(),
+            events: self.events.clone(),
+            stale_reported: Arc::clone(&self.stale_reported),
         }
     }
+
+    /// Update the connection state, emitting an event if it changed
+    pub async fn set_state(&self, new_state: ConnectionState) {
+        let mut state = self.state.write().await;
+        if new_state == ConnectionState::Connected {
+            self.stale_reported.store(false, Ordering::Relaxed);
+        }
+        if *state != new_state {
+            *state = new_state;
+            let _ = self.events.send(new_state.into());
+        }
+    }
+
+    /// Emit `Stale` once per connection (re-armed by the next `Connected`)
+    pub fn report_stale(&self) {
+        if !self.stale_reported.swap(true, Ordering::Relaxed) {
+            let _ = self.events.send(ConnectionEvent::Stale);
+        }
+    }
+
+    /// Subscribe to connection state transitions
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ConnectionEvent> {
+        self.events.subscribe()
+    }
 }
 
 impl Default for ConnectionHealth {
@@ -169,7 +540,7 @@ impl OrderbookLevel {
 }
 
 /// Orderbook snapshot with bid and ask levels
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct Orderbook {
     /// Bid levels sorted descending by price (best bid first)
     pub bids: Vec<OrderbookLevel>,
@@ -204,6 +575,83 @@ impl Orderbook {
     }
 }
 
+#[cfg(test)]
+impl Orderbook {
+    /// Book from `(price, quantity)` levels, best first, for tests
+    pub(crate) fn from_levels(bids: &[(f64, f64)], asks: &[(f64, f64)]) -> Self {
+        let levels = |side: &[(f64, f64)]| side.iter().map(|&(p, q)| OrderbookLevel::new(p, q)).collect();
+        Self { bids: levels(bids), asks: levels(asks), timestamp: 0 }
+    }
+}
+
+/// Minimum depth a book must show on both sides to be tradeable
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DepthRequirement {
+    /// Levels required per side
+    pub min_levels: usize,
+    /// Total `price × quantity` required per side, over the levels kept
+    /// (at most `MAX_ORDERBOOK_DEPTH`)
+    pub min_notional: f64,
+}
+
+impl DepthRequirement {
+    /// Whether both sides of `book` meet the level and notional minimums
+    pub fn is_met_by(&self, book: &Orderbook) -> bool {
+        let side_ok = |levels: &[OrderbookLevel]| {
+            let notional: f64 = levels.iter().map(|l| l.price * l.quantity).sum();
+            levels.len() >= self.min_levels && notional >= self.min_notional
+        };
+        side_ok(&book.bids) && side_ok(&book.asks)
+    }
+}
+
+/// What to do with a crossed quote (best bid above best ask)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrossedBookPolicy {
+    /// Drop the update; the exchange's previous quote stands
+    #[default]
+    Discard,
+    /// Replace both sides with their midpoint
+    Clamp,
+}
+
+impl CrossedBookPolicy {
+    /// The quote to use for `bid`/`ask`, `None` to drop it.
+    ///
+    /// Quotes that are not crossed (including locked ones) pass unchanged.
+    pub fn resolve(self, bid: f64, ask: f64) -> Option<(f64, f64)> {
+        if bid <= ask {
+            return Some((bid, ask));
+        }
+        match self {
+            CrossedBookPolicy::Discard => None,
+            CrossedBookPolicy::Clamp => {
+                let mid = (bid + ask) / 2.0;
+                Some((mid, mid))
+            }
+        }
+    }
+
+    /// The book to keep in shared storage in place of crossed `book`,
+    /// `None` to remove it.
+    ///
+    /// Discard puts back `last_good`, the exchange's previous uncrossed book.
+    /// Clamp keeps only the levels on the right side of the midpoint.
+    pub fn repair(self, book: &Orderbook, last_good: Option<&Orderbook>) -> Option<Orderbook> {
+        match self {
+            CrossedBookPolicy::Discard => last_good.cloned(),
+            CrossedBookPolicy::Clamp => {
+                let mid = book.mid_price()?;
+                let mut clamped = book.clone();
+                clamped.bids.retain(|level| level.price <= mid);
+                clamped.asks.retain(|level| level.price >= mid);
+                Some(clamped)
+            }
+        }
+    }
+}
+
 /// Orderbook update event for streaming
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderbookUpdate {
@@ -215,6 +663,58 @@ pub struct OrderbookUpdate {
     pub orderbook: Orderbook,
 }
 
+/// An orderbook level tagged with the exchange quoting it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConsolidatedLevel {
+    /// Exchange identifier (e.g., "vest", "paradex")
+    pub exchange: Arc<str>,
+    /// Price at this level
+    pub price: f64,
+    /// Quantity available at this price on `exchange`
+    pub quantity: f64,
+}
+
+/// Levels from several exchanges merged into one ladder
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsolidatedOrderbook {
+    /// Bid levels sorted descending by price (best bid first)
+    pub bids: Vec<ConsolidatedLevel>,
+    /// Ask levels sorted ascending by price (best ask first)
+    pub asks: Vec<ConsolidatedLevel>,
+}
+
+impl ConsolidatedOrderbook {
+    /// Merge per-exchange books into a single ladder.
+    ///
+    /// Levels at the same price keep the order the books were given in.
+    pub fn merge<'a>(books: impl IntoIterator<Item = (&'a str, &'a Orderbook)>) -> Self {
+        let mut merged = Self::default();
+        for (exchange, book) in books {
+            let exchange: Arc<str> = Arc::from(exchange);
+            let tag = |level: &OrderbookLevel| ConsolidatedLevel {
+                exchange: exchange.clone(),
+                price: level.price,
+                quantity: level.quantity,
+            };
+            merged.bids.extend(book.bids.iter().map(tag));
+            merged.asks.extend(book.asks.iter().map(tag));
+        }
+        merged.bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        merged.asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+        merged
+    }
+
+    /// Get the best bid across all exchanges
+    pub fn best_bid(&self) -> Option<&ConsolidatedLevel> {
+        self.bids.first()
+    }
+
+    /// Get the best ask across all exchanges
+    pub fn best_ask(&self) -> Option<&ConsolidatedLevel> {
+        self.asks.first()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,10 +809,129 @@ mod tests {
         assert_eq!(ob.mid_price(), None); // Can't calculate mid without both
     }
 
+    #[test]
+    fn test_consolidated_book_merges_and_tags_levels() {
+        let vest = Orderbook {
+            bids: vec![OrderbookLevel::new(42000.0, 1.0), OrderbookLevel::new(41990.0, 2.0)],
+            asks: vec![OrderbookLevel::new(42010.0, 1.0), OrderbookLevel::new(42030.0, 2.0)],
+            timestamp: 0,
+        };
+        let paradex = Orderbook {
+            bids: vec![OrderbookLevel::new(42005.0, 0.5), OrderbookLevel::new(41990.0, 3.0)],
+            asks: vec![OrderbookLevel::new(42020.0, 0.5)],
+            timestamp: 0,
+        };
+
+        let book = ConsolidatedOrderbook::merge([("vest", &vest), ("paradex", &paradex)]);
+
+        let bids: Vec<(&str, f64)> = book.bids.iter().map(|l| (&*l.exchange, l.price)).collect();
+        assert_eq!(
+            bids,
+            vec![("paradex", 42005.0), ("vest", 42000.0), ("vest", 41990.0), ("paradex", 41990.0)]
+        );
+        let asks: Vec<(&str, f64)> = book.asks.iter().map(|l| (&*l.exchange, l.price)).collect();
+        assert_eq!(asks, vec![("vest", 42010.0), ("paradex", 42020.0), ("vest", 42030.0)]);
+        assert_eq!(book.best_bid().unwrap().quantity, 0.5);
+    }
+
+    #[test]
+    fn test_depth_requirement_gates_thin_books() {
+        let book = Orderbook {
+            bids: vec![OrderbookLevel::new(100.0, 2.0), OrderbookLevel::new(99.0, 1.0)],
+            asks: vec![OrderbookLevel::new(101.0, 1.0), OrderbookLevel::new(102.0, 1.0)],
+            timestamp: 0,
+        };
+        // Bid notional 299, ask notional 203
+        assert!(DepthRequirement { min_levels: 2, min_notional: 200.0 }.is_met_by(&book));
+        assert!(!DepthRequirement { min_levels: 3, min_notional: 0.0 }.is_met_by(&book));
+        assert!(!DepthRequirement { min_levels: 0, min_notional: 250.0 }.is_met_by(&book), "thin ask side");
+        assert!(DepthRequirement::default().is_met_by(&book));
+        assert!(!DepthRequirement { min_levels: 1, min_notional: 0.0 }.is_met_by(&Orderbook::new()));
+    }
+
+    #[test]
+    fn test_crossed_book_policy() {
+        assert_eq!(CrossedBookPolicy::Discard.resolve(100.0, 101.0), Some((100.0, 101.0)));
+        assert_eq!(CrossedBookPolicy::Discard.resolve(100.0, 100.0), Some((100.0, 100.0)), "locked is not crossed");
+        assert_eq!(CrossedBookPolicy::Discard.resolve(102.0, 101.0), None);
+        assert_eq!(CrossedBookPolicy::Clamp.resolve(102.0, 101.0), Some((101.5, 101.5)));
+        assert_eq!(CrossedBookPolicy::Clamp.resolve(100.0, 101.0), Some((100.0, 101.0)));
+
+        let crossed = Orderbook {
+            bids: vec![OrderbookLevel::new(103.0, 1.0), OrderbookLevel::new(100.0, 1.0)],
+            asks: vec![OrderbookLevel::new(101.0, 1.0), OrderbookLevel::new(104.0, 1.0)],
+            timestamp: 0,
+        };
+        let previous = Orderbook { bids: vec![OrderbookLevel::new(100.0, 1.0)], ..Orderbook::new() };
+        assert_eq!(CrossedBookPolicy::Discard.repair(&crossed, Some(&previous)), Some(previous));
+        assert_eq!(CrossedBookPolicy::Discard.repair(&crossed, None), None);
+        // Mid 102: the 103 bid and the 101 ask are dropped
+        let clamped = CrossedBookPolicy::Clamp.repair(&crossed, None).unwrap();
+        assert_eq!((clamped.best_bid(), clamped.best_ask()), (Some(100.0), Some(104.0)));
+    }
+
+    #[test]
+    fn test_market_info_decimals() {
+        let info = MarketInfo::from_increments("BTC-USD-PERP", "0.10", "0.001", 100.0).unwrap();
+        assert_eq!((info.tick_size, info.size_step), (0.1, 0.001));
+        assert_eq!((info.price_decimals, info.size_decimals), (1, 3));
+        assert_eq!(MarketInfo::from_increments("X", "1", "1", 0.0).unwrap().price_decimals, 0);
+        assert!(MarketInfo::from_increments("X", "abc", "1", 0.0).is_err());
+
+        let info = MarketInfo::from_decimals("BTC-PERP", 2, 4, 0.0);
+        assert_eq!((info.tick_size, info.size_step), (0.01, 0.0001));
+    }
+
     // =========================================================================
     // Connection Health Tests
     // =========================================================================
 
+    #[test]
+    fn test_rtt_recorded_from_ping_pong_pairs() {
+        let health = ConnectionHealth::new();
+        let rtt = health.clone_refs().rtt;
+        assert_eq!(health.rtt.average_ms(), None);
+
+        // PONG without an outstanding PING is ignored
+        assert_eq!(rtt.record_pong(1_000), None);
+
+        rtt.record_ping(1_000);
+        assert_eq!(rtt.record_pong(1_040), Some(40));
+        assert_eq!(health.rtt.average_ms(), Some(40));
+
+        rtt.record_ping(2_000);
+        assert_eq!(rtt.record_pong(2_080), Some(80));
+        assert_eq!(health.rtt.average_ms(), Some(50));
+    }
+
+    #[test]
+    fn test_ingest_latency_percentiles() {
+        let health = ConnectionHealth::new();
+        let latency = health.clone_refs().ingest_latency;
+        assert_eq!(health.ingest_latency.percentiles(), None);
+
+        // Latencies 1..=100 ms
+        for ms in 1..=100u64 {
+            assert_eq!(latency.record("paradex", 10_000, 10_000 + ms), ms);
+        }
+        // Exchange clock ahead of ours: clamped to 0
+        assert_eq!(latency.record("paradex", 20_000, 15_000), 0);
+
+        assert_eq!(
+            health.ingest_latency.percentiles(),
+            Some(LatencyPercentiles { p50: 50, p95: 95, p99: 99 })
+        );
+
+        // Only the last WINDOW samples count once the ring wraps
+        for _ in 0..IngestLatency::WINDOW {
+            latency.record("paradex", 10_000, 10_007);
+        }
+        assert_eq!(
+            health.ingest_latency.percentiles(),
+            Some(LatencyPercentiles { p50: 7, p95: 7, p99: 7 })
+        );
+    }
+
     #[test]
     fn test_connection_state_default() {
         let state = ConnectionState::default();
@@ -455,6 +1074,37 @@ mod tests {
         assert_eq!(health.last_data.load(Ordering::Relaxed), 67890);
     }
 
+    #[tokio::test]
+    async fn test_set_state_emits_ordered_transitions() {
+        let health = ConnectionHealth::new();
+        let mut events = health.subscribe_events();
+
+        health.set_state(ConnectionState::Connected).await;
+        health.report_stale();
+        health.report_stale(); // only once per connection
+        health.set_state(ConnectionState::Reconnecting).await;
+        health.set_state(ConnectionState::Reconnecting).await; // no change, no event
+        health.set_state(ConnectionState::Connected).await;
+        health.report_stale(); // re-armed by Connected
+        health.set_state(ConnectionState::Disconnected).await;
+
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            received.push(event);
+        }
+        assert_eq!(
+            received,
+            vec![
+                ConnectionEvent::Connected,
+                ConnectionEvent::Stale,
+                ConnectionEvent::Reconnecting,
+                ConnectionEvent::Connected,
+                ConnectionEvent::Stale,
+                ConnectionEvent::Disconnected,
+            ]
+        );
+    }
+
     #[test]
     fn test_exponential_backoff_timing() {
         // Test the exponential backoff formula used in reconnect()
@@ -517,4 +1167,31 @@ mod tests {
         assert!(!health.reader_alive.load(Ordering::Relaxed));
     }
 
+    #[tokio::test]
+    async fn test_http_client_sends_extra_headers() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        // Minimal mock server that captures the raw request
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_lowercase()
+        });
+
+        let mut headers = std::collections::HashMap::new();
+        headers.insert("X-Route".to_string(), "eu-1".to_string());
+        let client = create_http_client_with_headers("test", &headers);
+        let response = client.get(format!("http://{}/ping", addr)).send().await.unwrap();
+        assert!(response.status().is_success());
+
+        let raw_request = server.await.unwrap();
+        assert!(raw_request.contains("x-route: eu-1"), "Got: {}", raw_request);
+    }
 }
@@ -4,7 +4,7 @@
 //! Read-only market data via WebSocket (public orderbooks).
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -18,14 +18,16 @@ use tokio::time::timeout;
 use tokio_tungstenite::tungstenite::Message;
 
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
+use crate::adapters::shared::{backoff_delay_ms, ReconnectConfig};
 use crate::adapters::traits::ExchangeAdapter;
 use crate::adapters::types::{
-    next_subscription_id, ConnectionHealth, ConnectionState, Orderbook,
+    create_http_client_with_headers, next_subscription_id, Capabilities, ConnectionHealth,
+    ConnectionState, MarketInfo, Orderbook, MAX_ORDERBOOK_DEPTH,
 };
 
 // Import from sub-modules
 use super::config::VestConfig;
-use super::types::VestWsMessage;
+use super::types::{VestDepthData, VestExchangeInfo, VestWsMessage};
 
 /// Get current time in milliseconds
 fn current_time_ms() -> u64 {
@@ -57,6 +59,8 @@ use crate::core::channels::SharedBestPrices;
 use crate::core::channels::AtomicBestPrices;
 /// Event-driven orderbook notification (Axe 5)
 use crate::core::channels::OrderbookNotify;
+/// Coalesced writes into the shared orderbooks
+use crate::core::channels::{sleep_until_deadline, BestPricePublisher, ThrottledBookWriter};
 
 // =============================================================================
 // VestAdapter Implementation
@@ -65,6 +69,8 @@ use crate::core::channels::OrderbookNotify;
 /// Vest Exchange Adapter implementing ExchangeAdapter trait
 pub struct VestAdapter {
     pub(crate) config: VestConfig,
+    /// HTTP client for REST orderbook snapshots
+    pub(crate) http_client: reqwest::Client,
     pub(crate) ws_stream: Option<Mutex<WsStream>>,
     pub(crate) ws_sender: Option<Arc<Mutex<WsWriter>>>,
     pub(crate) reader_handle: Option<JoinHandle<()>>,
@@ -78,13 +84,18 @@ pub struct VestAdapter {
     pub(crate) shared_best_prices: SharedBestPrices,
     /// Orderbook update notification (Axe 5 event-driven monitoring)
     pub(crate) orderbook_notify: Option<OrderbookNotify>,
+    /// Receives every book the reader writes (raw feed recording)
+    pub(crate) book_tap: Option<crate::core::channels::BookTap>,
     pub(crate) connection_health: ConnectionHealth,
+    /// Market metadata by symbol, fetched on first request
+    pub(crate) market_info: RwLock<HashMap<String, MarketInfo>>,
 }
 
 impl VestAdapter {
     /// Create a new VestAdapter with the given configuration
     pub fn new(config: VestConfig) -> Self {
         Self {
+            http_client: create_http_client_with_headers("Vest", &config.extra_headers),
             config,
             ws_stream: None,
             ws_sender: None,
@@ -97,7 +108,9 @@ impl VestAdapter {
             shared_orderbooks: Arc::new(RwLock::new(HashMap::new())),
             shared_best_prices: Arc::new(AtomicBestPrices::new()),
             orderbook_notify: None,
+            book_tap: None,
             connection_health: ConnectionHealth::default(),
+            market_info: RwLock::new(HashMap::new()),
         }
     }
 
@@ -133,7 +146,9 @@ impl VestAdapter {
     async fn connect_websocket(&mut self) -> ExchangeResult<()> {
         let url = self.build_public_ws_url();
         tracing::info!("Connecting to Vest public WebSocket: {}", url);
-        let ws_stream = crate::adapters::shared::connect_tls(&url).await?;
+        let ws_stream =
+            crate::adapters::shared::connect_tls_with_headers(&url, &self.config.extra_headers)
+                .await?;
         self.ws_stream = Some(Mutex::new(ws_stream));
         Ok(())
     }
@@ -147,6 +162,7 @@ impl VestAdapter {
 
         let mut stream = ws.lock().await;
 
+        self.connection_health.rtt.record_ping(current_time_ms());
         let ping_msg = serde_json::json!({
             "method": "PING",
             "params": [],
@@ -178,6 +194,7 @@ impl VestAdapter {
                             text
                         )));
                     }
+                    self.connection_health.rtt.record_pong(current_time_ms());
                 }
             }
             None => {
@@ -202,16 +219,19 @@ impl VestAdapter {
         self.ws_sender = Some(Arc::new(Mutex::new(ws_sender)));
 
         let shared_orderbooks = Arc::clone(&self.shared_orderbooks);
-        let shared_best_prices = Arc::clone(&self.shared_best_prices);
-        let orderbook_notify = self.orderbook_notify.clone();
-        let last_pong = Arc::clone(&self.connection_health.last_pong);
-        let last_data = Arc::clone(&self.connection_health.last_data);
-        let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let best_prices = BestPricePublisher::new(
+            Arc::clone(&self.shared_best_prices),
+            self.orderbook_notify.clone(),
+            self.config.notify_every_update,
+        );
+        let health = self.connection_health.clone_refs();
+        let book_writer = ThrottledBookWriter::new(shared_orderbooks, self.config.min_update_interval_ms)
+            .with_tap(self.book_tap.clone());
 
-        last_data.store(current_time_ms(), Ordering::Relaxed);
+        health.last_data.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            Self::message_reader_loop(ws_receiver, shared_orderbooks, shared_best_prices, orderbook_notify, last_pong, last_data, reader_alive).await;
+            Self::message_reader_loop(ws_receiver, book_writer, best_prices, health).await;
         });
 
         self.reader_handle = Some(handle);
@@ -222,28 +242,40 @@ impl VestAdapter {
     ///
     /// Sets `reader_alive` to `true` on entry and `false` on exit, so that
     /// `is_stale()` can detect a dead connection immediately.
+    ///
+    /// Best prices are published on every update (waking the monitoring
+    /// loop only when they change, unless `notify_every_update`); full books
+    /// go through `book_writer`, which may coalesce them (`min_update_interval_ms`).
     async fn message_reader_loop(
         mut ws_receiver: WsReader,
-        shared_orderbooks: SharedOrderbooks,
-        shared_best_prices: SharedBestPrices,
-        orderbook_notify: Option<OrderbookNotify>,
-        last_pong: Arc<AtomicU64>,
-        last_data: Arc<AtomicU64>,
-        reader_alive: Arc<AtomicBool>,
+        mut book_writer: ThrottledBookWriter,
+        best_prices: BestPricePublisher,
+        health: ConnectionHealth,
     ) {
         tracing::info!("Vest message_reader_loop started");
-        reader_alive.store(true, Ordering::Relaxed);
+        health.reader_alive.store(true, Ordering::Relaxed);
 
-        while let Some(msg_result) = ws_receiver.next().await {
-            last_data.store(current_time_ms(), Ordering::Relaxed);
+        loop {
+            let msg_result = tokio::select! {
+                msg = ws_receiver.next() => match msg {
+                    Some(msg) => msg,
+                    None => break,
+                },
+                // Publish books held back by the throttle once they are due
+                _ = sleep_until_deadline(book_writer.flush_deadline()) => {
+                    book_writer.flush().await;
+                    continue;
+                }
+            };
+            health.last_data.store(current_time_ms(), Ordering::Relaxed);
             // Any WS message proves the connection is alive -- reset PONG staleness timer
-            last_pong.store(current_time_ms(), Ordering::Relaxed);
+            health.last_pong.store(current_time_ms(), Ordering::Relaxed);
 
             match msg_result {
                 Ok(Message::Text(text)) => {
                     tracing::trace!("Raw WS message: {}", text);
 
-                    match serde_json::from_str::<VestWsMessage>(&text) {
+                    match health.parse_stats.observe(serde_json::from_str::<VestWsMessage>(&text)) {
                         Ok(msg) => match msg {
                             VestWsMessage::Depth(depth_msg) => {
                                 let symbol = depth_msg
@@ -262,13 +294,8 @@ impl VestAdapter {
                                 match depth_msg.data.to_orderbook() {
                                     Ok(orderbook) => {
                                         // Write atomic best prices FIRST (lock-free hot path)
-                                        shared_best_prices.store(
-                                            orderbook.best_bid().unwrap_or(0.0),
-                                            orderbook.best_ask().unwrap_or(0.0),
-                                        );
-                                        if let Some(ref n) = orderbook_notify { n.notify_waiters(); }
-                                        let mut books = shared_orderbooks.write().await;
-                                        books.insert(symbol.clone(), orderbook);
+                                        best_prices.publish(&orderbook);
+                                        book_writer.write(symbol.clone(), orderbook).await;
                                         tracing::trace!(symbol = %symbol, "Orderbook updated in shared storage");
                                     }
                                     Err(e) => {
@@ -280,8 +307,10 @@ impl VestAdapter {
                                 tracing::debug!("Subscription confirmed: id={}", sub_resp.id);
                             }
                             VestWsMessage::Pong { .. } => {
-                                last_pong.store(current_time_ms(), Ordering::Relaxed);
-                                tracing::debug!("Vest PONG received, updating last_pong timestamp");
+                                let now = current_time_ms();
+                                health.last_pong.store(now, Ordering::Relaxed);
+                                let rtt_ms = health.rtt.record_pong(now);
+                                tracing::debug!(rtt_ms = ?rtt_ms, "Vest PONG received, updating last_pong timestamp");
                             }
                         },
                         Err(parse_err) => {
@@ -307,7 +336,7 @@ impl VestAdapter {
                     Ok(text) => {
                         tracing::debug!("Binary->Text: {}", text);
                         if let Ok(VestWsMessage::Depth(depth_msg)) =
-                            serde_json::from_str::<VestWsMessage>(&text)
+                            health.parse_stats.observe(serde_json::from_str::<VestWsMessage>(&text))
                         {
                             let symbol = depth_msg
                                 .channel
@@ -316,13 +345,8 @@ impl VestAdapter {
                                 .to_string();
 
                             if let Ok(orderbook) = depth_msg.data.to_orderbook() {
-                                shared_best_prices.store(
-                                    orderbook.best_bid().unwrap_or(0.0),
-                                    orderbook.best_ask().unwrap_or(0.0),
-                                );
-                                if let Some(ref n) = orderbook_notify { n.notify_waiters(); }
-                                let mut books = shared_orderbooks.write().await;
-                                books.insert(symbol, orderbook);
+                                best_prices.publish(&orderbook);
+                                book_writer.write(symbol, orderbook).await;
                             }
                         }
                     }
@@ -340,7 +364,7 @@ impl VestAdapter {
             }
         }
 
-        reader_alive.store(false, Ordering::Relaxed);
+        health.reader_alive.store(false, Ordering::Relaxed);
         tracing::warn!("Vest message reader loop ended -- reader_alive set to false");
     }
 
@@ -403,12 +427,13 @@ impl VestAdapter {
 
         let last_pong = Arc::clone(&self.connection_health.last_pong);
         let reader_alive = Arc::clone(&self.connection_health.reader_alive);
+        let rtt = self.connection_health.rtt.clone();
+        let interval_secs = self.config.heartbeat_interval_secs;
+        let stale_threshold_ms = self.config.stale_threshold_ms();
         last_pong.store(current_time_ms(), Ordering::Relaxed);
 
         let handle = tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(
-                crate::adapters::types::WS_PING_INTERVAL_SECS,
-            ));
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
             interval.tick().await;
 
             loop {
@@ -422,6 +447,7 @@ impl VestAdapter {
 
                 {
                     let mut sender = ws_sender.lock().await;
+                    rtt.record_ping(current_time_ms());
                     if let Err(e) = sender.send(Message::Text(ping_msg.to_string())).await {
                         tracing::warn!("Vest heartbeat: Failed to send PING - {}", e);
                         reader_alive.store(false, Ordering::Relaxed);
@@ -436,7 +462,7 @@ impl VestAdapter {
                 let last = last_pong.load(Ordering::Relaxed);
                 let now = current_time_ms();
                 let pong_age_ms = now.saturating_sub(last);
-                if pong_age_ms > 30_000 {
+                if pong_age_ms > stale_threshold_ms {
                     tracing::warn!(
                         "Vest heartbeat: PONG stale ({}ms ago) - connection likely dead, setting reader_alive=false",
                         pong_age_ms
@@ -451,7 +477,10 @@ impl VestAdapter {
         });
 
         self.heartbeat_handle = Some(handle);
-        tracing::info!("Vest: Heartbeat monitoring started (30s interval)");
+        tracing::info!(
+            "Vest: Heartbeat monitoring started ({}s interval)",
+            interval_secs
+        );
     }
 }
 
@@ -468,16 +497,14 @@ impl ExchangeAdapter for VestAdapter {
         self.spawn_heartbeat_task();
 
         self.connected = true;
+        self.connection_health.set_state(ConnectionState::Connected).await;
         tracing::info!(exchange = "vest", "Vest WebSocket connected");
 
         Ok(())
     }
 
     async fn disconnect(&mut self) -> ExchangeResult<()> {
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         if let Some(handle) = self.reader_handle.take() {
             handle.abort();
@@ -571,9 +598,78 @@ impl ExchangeAdapter for VestAdapter {
         }
 
         let now = current_time_ms();
-        use crate::adapters::types::STALE_THRESHOLD_MS;
+        now.saturating_sub(last_data) > self.config.stale_threshold_ms()
+    }
 
-        now.saturating_sub(last_data) > STALE_THRESHOLD_MS
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            rest_snapshot: true,
+            market_info: true,
+            ..Capabilities::ORDERBOOK_ONLY
+        }
+    }
+
+    /// Fetch the current book via `GET /depth`
+    async fn fetch_orderbook_snapshot(&self, symbol: &str) -> ExchangeResult<Orderbook> {
+        let url = format!("{}/depth", self.config.rest_base_url());
+        let limit = MAX_ORDERBOOK_DEPTH.to_string();
+
+        let response = self
+            .http_client
+            .get(&url)
+            .query(&[("symbol", symbol), ("limit", limit.as_str())])
+            .header("xrestservermm", format!("restserver{}", self.config.account_group))
+            .send()
+            .await
+            .map_err(|e| {
+                ExchangeError::ConnectionFailed(format!("Orderbook snapshot request failed: {}", e))
+            })?;
+        if !response.status().is_success() {
+            return Err(ExchangeError::InvalidResponse(format!(
+                "Orderbook snapshot returned {}",
+                response.status()
+            )));
+        }
+
+        let depth: VestDepthData = response.json().await.map_err(|e| {
+            ExchangeError::InvalidResponse(format!("Invalid orderbook snapshot: {}", e))
+        })?;
+        depth.to_orderbook()
+    }
+
+    /// Fetch precision via `GET /exchangeInfo`, cached per symbol
+    async fn fetch_market_info(&self, symbol: &str) -> ExchangeResult<MarketInfo> {
+        if let Some(info) = self.market_info.read().await.get(symbol) {
+            return Ok(info.clone());
+        }
+        let url = format!("{}/exchangeInfo", self.config.rest_base_url());
+
+        let response = self
+            .http_client
+            .get(&url)
+            .query(&[("symbols", symbol)])
+            .header("xrestservermm", format!("restserver{}", self.config.account_group))
+            .send()
+            .await
+            .map_err(|e| ExchangeError::ConnectionFailed(format!("Exchange info request failed: {}", e)))?;
+        if !response.status().is_success() {
+            return Err(ExchangeError::InvalidResponse(format!(
+                "Exchange info returned {}",
+                response.status()
+            )));
+        }
+
+        let exchange_info: VestExchangeInfo = response.json().await.map_err(|e| {
+            ExchangeError::InvalidResponse(format!("Invalid exchange info: {}", e))
+        })?;
+        let info = exchange_info
+            .symbols
+            .iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| ExchangeError::InvalidResponse(format!("Market {} not listed", symbol)))?
+            .to_market_info();
+        self.market_info.write().await.insert(symbol.to_string(), info.clone());
+        Ok(info)
     }
 
     async fn sync_orderbooks(&mut self) {
@@ -584,39 +680,30 @@ impl ExchangeAdapter for VestAdapter {
     async fn reconnect(&mut self) -> ExchangeResult<()> {
         tracing::info!("Vest: Initiating reconnection...");
 
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Reconnecting;
-        }
+        self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
         let saved_subscriptions = self.subscriptions.clone();
         self.disconnect().await?;
 
-        const MAX_RECONNECT_ATTEMPTS: u32 = 3;
+        let reconnect_config = ReconnectConfig::default();
         let mut last_error: Option<ExchangeError> = None;
 
-        for attempt in 0..MAX_RECONNECT_ATTEMPTS {
-            let backoff_ms = std::cmp::min(500 * (1u64 << attempt), 5000);
+        for attempt in 0..reconnect_config.max_attempts {
+            let backoff_ms = backoff_delay_ms(&reconnect_config, attempt);
             tracing::info!(
                 "Vest: Reconnect attempt {} of {}, waiting {}ms...",
                 attempt + 1,
-                MAX_RECONNECT_ATTEMPTS,
+                reconnect_config.max_attempts,
                 backoff_ms
             );
 
             tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
 
-            {
-                let mut state = self.connection_health.state.write().await;
-                *state = ConnectionState::Reconnecting;
-            }
+            self.connection_health.set_state(ConnectionState::Reconnecting).await;
 
             match self.connect().await {
                 Ok(()) => {
-                    {
-                        let mut state = self.connection_health.state.write().await;
-                        *state = ConnectionState::Connected;
-                    }
+                    self.connection_health.set_state(ConnectionState::Connected).await;
 
                     for symbol in &saved_subscriptions {
                         tracing::info!("Vest: Re-subscribing to {}", symbol);
@@ -632,6 +719,12 @@ impl ExchangeAdapter for VestAdapter {
 
                     return Ok(());
                 }
+                Err(e) if e.is_maintenance() || e.is_fatal() => {
+                    // Not an attempt: the caller waits out the maintenance
+                    // window, or gives up on a fatal error
+                    self.connection_health.set_state(ConnectionState::Disconnected).await;
+                    return Err(e);
+                }
                 Err(e) => {
                     tracing::warn!("Vest: Reconnect attempt {} failed: {}", attempt + 1, e);
                     last_error = Some(e);
@@ -639,10 +732,7 @@ impl ExchangeAdapter for VestAdapter {
             }
         }
 
-        {
-            let mut state = self.connection_health.state.write().await;
-            *state = ConnectionState::Disconnected;
-        }
+        self.connection_health.set_state(ConnectionState::Disconnected).await;
 
         Err(last_error.unwrap_or_else(|| {
             ExchangeError::ConnectionFailed("Reconnection failed after max attempts".into())
@@ -664,6 +754,15 @@ impl ExchangeAdapter for VestAdapter {
     fn set_orderbook_notify(&mut self, notify: crate::core::channels::OrderbookNotify) {
         self.orderbook_notify = Some(notify);
     }
+
+    fn set_book_tap(&mut self, tap: crate::core::channels::BookTap) -> bool {
+        self.book_tap = Some(tap);
+        true
+    }
+
+    fn connection_health(&self) -> &ConnectionHealth {
+        &self.connection_health
+    }
 }
 
 // =============================================================================
@@ -682,4 +781,22 @@ mod tests {
         assert!(!adapter.connected);
         assert_eq!(adapter.exchange_name(), "vest");
     }
+
+    #[test]
+    fn test_is_stale_uses_configured_threshold() {
+        let config = VestConfig {
+            stale_threshold_secs: 90,
+            ..Default::default()
+        };
+        let mut adapter = VestAdapter::new(config);
+        adapter.connected = true;
+        adapter.connection_health.reader_alive.store(true, Ordering::Relaxed);
+
+        let now = current_time_ms();
+        adapter.connection_health.last_data.store(now - 89_000, Ordering::Relaxed);
+        assert!(!adapter.is_stale());
+
+        adapter.connection_health.last_data.store(now - 91_000, Ordering::Relaxed);
+        assert!(adapter.is_stale());
+    }
 }
@@ -2,6 +2,11 @@
 //!
 //! Configuration for Vest exchange connection (public data only).
 
+use std::collections::HashMap;
+
+use crate::adapters::shared::parse_header_list;
+use crate::adapters::types::{STALE_THRESHOLD_MS, WS_PING_INTERVAL_SECS};
+
 // =============================================================================
 // Configuration
 // =============================================================================
@@ -13,6 +18,24 @@ pub struct VestConfig {
     pub account_group: u8,
     /// Use production endpoints (true) or development (false)
     pub production: bool,
+    /// How often the heartbeat task sends PING (seconds)
+    pub heartbeat_interval_secs: u64,
+    /// Silence after which the connection is considered stale (seconds).
+    ///
+    /// The heartbeat task applies it to the last PONG, `is_stale()` applies
+    /// it to the last data message.
+    pub stale_threshold_secs: u64,
+    /// Extra headers sent on every outbound HTTP request and the WS handshake
+    /// (API routing, tracing IDs, auth proxies)
+    pub extra_headers: HashMap<String, String>,
+    /// Override for the REST base URL (regional proxies, local mocks)
+    pub rest_url: Option<String>,
+    /// Minimum interval between writes to the shared orderbooks (ms).
+    /// Updates arriving faster are coalesced; 0 writes every update.
+    pub min_update_interval_ms: u64,
+    /// Wake the monitoring loop on every book update, not only when the
+    /// best bid/ask changes (debugging)
+    pub notify_every_update: bool,
 }
 
 impl VestConfig {
@@ -26,9 +49,51 @@ impl VestConfig {
             .map(|v| v == "true" || v == "1")
             .unwrap_or(true);
 
+        let heartbeat_interval_secs = std::env::var("VEST_HEARTBEAT_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(WS_PING_INTERVAL_SECS);
+        let stale_threshold_secs = std::env::var("VEST_STALE_THRESHOLD_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(STALE_THRESHOLD_MS / 1000);
+        let extra_headers = std::env::var("VEST_EXTRA_HEADERS")
+            .map(|v| parse_header_list(&v))
+            .unwrap_or_default();
+        let rest_url = std::env::var("VEST_REST_URL").ok();
+        let min_update_interval_ms = std::env::var("VEST_MIN_UPDATE_INTERVAL_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+        let notify_every_update = std::env::var("VEST_NOTIFY_EVERY_UPDATE")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
         Self {
             account_group,
             production,
+            heartbeat_interval_secs,
+            stale_threshold_secs,
+            extra_headers,
+            rest_url,
+            min_update_interval_ms,
+            notify_every_update,
+        }
+    }
+
+    /// Stale threshold in milliseconds
+    pub fn stale_threshold_ms(&self) -> u64 {
+        self.stale_threshold_secs * 1000
+    }
+
+    /// Get REST API base URL
+    pub fn rest_base_url(&self) -> &str {
+        if let Some(url) = &self.rest_url {
+            url
+        } else if self.production {
+            "https://server-prod.hz.vestmarkets.com/v2"
+        } else {
+            "https://server-dev.hz.vestmarkets.com/v2"
         }
     }
 
@@ -47,6 +112,12 @@ impl Default for VestConfig {
         Self {
             account_group: 0,
             production: true,
+            heartbeat_interval_secs: WS_PING_INTERVAL_SECS,
+            stale_threshold_secs: STALE_THRESHOLD_MS / 1000,
+            extra_headers: HashMap::new(),
+            rest_url: None,
+            min_update_interval_ms: 0,
+            notify_every_update: false,
         }
     }
 }
@@ -60,14 +131,18 @@ mod tests {
         let config = VestConfig::default();
         assert!(config.production);
         assert_eq!(config.account_group, 0);
+        assert_eq!(config.heartbeat_interval_secs, 30);
+        assert_eq!(config.stale_threshold_ms(), 30_000);
     }
 
     #[test]
     fn test_vest_config_urls() {
         let config = VestConfig { production: true, ..Default::default() };
         assert!(config.ws_base_url().contains("prod"));
+        assert!(config.rest_base_url().contains("prod"));
 
         let config = VestConfig { production: false, ..Default::default() };
         assert!(config.ws_base_url().contains("dev"));
+        assert!(config.rest_base_url().contains("dev"));
     }
 }
@@ -5,7 +5,7 @@
 use serde::Deserialize;
 
 use crate::adapters::errors::{ExchangeError, ExchangeResult};
-use crate::adapters::types::{Orderbook, OrderbookLevel};
+use crate::adapters::types::{MarketInfo, Orderbook, OrderbookLevel};
 
 
 // =============================================================================
@@ -103,6 +103,32 @@ impl VestDepthData {
     }
 }
 
+// =============================================================================
+// REST Types
+// =============================================================================
+
+/// `GET /exchangeInfo` response
+#[derive(Debug, Clone, Deserialize)]
+pub struct VestExchangeInfo {
+    pub symbols: Vec<VestSymbolInfo>,
+}
+
+/// One market's precision (other fields ignored)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestSymbolInfo {
+    pub symbol: String,
+    pub price_decimals: u32,
+    pub size_decimals: u32,
+}
+
+impl VestSymbolInfo {
+    /// Convert to our canonical MarketInfo (Vest publishes no minimum notional)
+    pub fn to_market_info(&self) -> MarketInfo {
+        MarketInfo::from_decimals(&self.symbol, self.price_decimals, self.size_decimals, 0.0)
+    }
+}
+
 /// Subscription confirmation response
 #[derive(Debug, Deserialize)]
 pub(crate) struct VestSubscriptionResponse {
@@ -158,4 +184,31 @@ mod tests {
             Err(e) => panic!("Failed to parse PONG message: {}", e),
         }
     }
+
+    #[test]
+    fn test_exchange_info_to_market_info() {
+        let json = r#"{
+            "symbols": [{
+                "symbol": "BTC-PERP",
+                "displayName": "BTC-PERP",
+                "base": "BTC",
+                "quote": "USDC",
+                "sizeDecimals": 4,
+                "priceDecimals": 1,
+                "initMarginRatio": "0.05",
+                "maintMarginRatio": "0.025",
+                "takerFee": "0.0001",
+                "isolated": false
+            }],
+            "exchange": {"collateralDecimals": 6}
+        }"#;
+        let response: VestExchangeInfo = serde_json::from_str(json).unwrap();
+        let info = response.symbols[0].to_market_info();
+
+        assert_eq!(info.symbol, "BTC-PERP");
+        assert_eq!((info.price_decimals, info.size_decimals), (1, 4));
+        assert_eq!(info.tick_size, 0.1);
+        assert_eq!(info.size_step, 0.0001);
+        assert_eq!(info.min_notional, 0.0);
+    }
 }
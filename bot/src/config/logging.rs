@@ -1,6 +1,6 @@
 //! Logging configuration module for HFT Bot
 //!
-//! Provides configurable JSON/Pretty/TUI logging output
+//! Provides configurable JSON/Pretty/Compact/TUI logging output
 //!
 //! # Usage
 //! ```rust
@@ -9,32 +9,90 @@
 //! ```
 //!
 //! # Environment Variables
-//! - `LOG_FORMAT`: Output format - `json` (default), `pretty`, or `tui`
+//! - `LOG_FORMAT`: Output format - `json` (default), `pretty`, `compact`, or `tui`
 //! - `RUST_LOG`: Log level filter (default: `info`)
 
+use std::fmt;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields, MakeWriter};
+use tracing_subscriber::registry::LookupSpan;
 use tracing_subscriber::EnvFilter;
 
+/// Output format selected by `LOG_FORMAT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Machine-parseable JSON (default)
+    Json,
+    /// Multi-line human-readable output
+    Pretty,
+    /// Single-line human-readable output with colors
+    Compact,
+    /// Subscriber is set up by the caller
+    Tui,
+}
+
+impl LogFormat {
+    /// Parse a `LOG_FORMAT` value; anything unrecognized falls back to JSON
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "pretty" => Self::Pretty,
+            "compact" => Self::Compact,
+            "tui" => Self::Tui,
+            _ => Self::Json,
+        }
+    }
+
+    /// Format named by `LOG_FORMAT` (JSON when unset)
+    pub fn from_env() -> Self {
+        std::env::var("LOG_FORMAT")
+            .map(|v| Self::parse(&v))
+            .unwrap_or(Self::Json)
+    }
+}
+
 /// Initialize logging with configurable format
 ///
 /// Reads `LOG_FORMAT` from environment:
 /// - `json` (default): Machine-parseable JSON output for production
 /// - `pretty`: Human-readable output for development
+/// - `compact`: One colored line per event (see [`CompactFormatter`])
 /// - `tui`: Skip initialization (caller sets up TuiLayer manually)
 ///
 /// Also respects `RUST_LOG` for log level filtering (default: `info`)
 pub fn init_logging() {
-    let log_format = std::env::var("LOG_FORMAT").unwrap_or_else(|_| "json".to_string());
+    init_logging_to(std::io::stdout);
+}
+
+/// Same as `init_logging()`, writing log lines to `writer`
+///
+/// Used by one-shot scan mode to keep stdout free for its JSON report.
+pub fn init_logging_to<W>(writer: W)
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
     let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
 
-    match log_format.as_str() {
-        "pretty" => {
+    match LogFormat::from_env() {
+        LogFormat::Pretty => {
             // Human-readable for development
             tracing_subscriber::fmt()
                 .with_env_filter(env_filter)
+                .with_writer(writer)
                 .pretty()
                 .init();
         }
-        "tui" => {
+        LogFormat::Compact => {
+            // Concise single-line output for local debugging
+            tracing_subscriber::fmt()
+                .with_env_filter(env_filter)
+                .with_writer(writer)
+                .event_format(CompactFormatter)
+                .init();
+        }
+        LogFormat::Tui => {
             // TUI mode: do NOT initialize subscriber here.
             // main.rs sets up the subscriber with TuiLayer.
             // If this branch runs, ALL logs are silently dropped until
@@ -44,16 +102,107 @@ pub fn init_logging() {
                 "init_logging() called in TUI mode — subscriber must be set up by caller via TuiLayer"
             );
         }
-        _ => {
+        LogFormat::Json => {
             // JSON for production (default)
             tracing_subscriber::fmt()
                 .with_env_filter(env_filter)
+                .with_writer(writer)
                 .json()
                 .init();
         }
     }
 }
 
+/// Single-line event format for `LOG_FORMAT=compact`
+///
+/// `12:34:56.789  INFO [AWAITING_LEG] BTC message key=value ...` — the
+/// `event_type` and pair (`pair` or `symbol`) fields are pulled in front of
+/// the message, every other field follows inline. Levels and keys are
+/// colored when the writer supports ANSI.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CompactFormatter;
+
+impl<S, N> FormatEvent<S, N> for CompactFormatter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut fields = CompactFields::default();
+        event.record(&mut fields);
+        let ansi = writer.has_ansi_escapes();
+        let level = *event.metadata().level();
+
+        write!(writer, "{} ", chrono::Local::now().format("%H:%M:%S%.3f"))?;
+        if ansi {
+            write!(writer, "\x1b[{}m{:>5}\x1b[0m ", level_color(level), level)?;
+        } else {
+            write!(writer, "{:>5} ", level)?;
+        }
+        if let Some(event_type) = &fields.event_type {
+            write!(writer, "[{}] ", event_type)?;
+        }
+        if let Some(pair) = &fields.pair {
+            write!(writer, "{} ", pair)?;
+        }
+        write!(writer, "{}", fields.message)?;
+        for (key, value) in &fields.rest {
+            if ansi {
+                write!(writer, " \x1b[2m{}=\x1b[0m{}", key, value)?;
+            } else {
+                write!(writer, " {}={}", key, value)?;
+            }
+        }
+        writeln!(writer)
+    }
+}
+
+/// ANSI color code per level
+fn level_color(level: Level) -> u8 {
+    match level {
+        Level::ERROR => 31,
+        Level::WARN => 33,
+        Level::INFO => 32,
+        Level::DEBUG => 34,
+        Level::TRACE => 35,
+    }
+}
+
+/// Event fields split into the ones `CompactFormatter` places up front
+#[derive(Default)]
+struct CompactFields {
+    message: String,
+    event_type: Option<String>,
+    pair: Option<String>,
+    rest: Vec<(&'static str, String)>,
+}
+
+impl CompactFields {
+    fn record(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "message" => self.message = value,
+            "event_type" => self.event_type = Some(value),
+            "pair" | "symbol" if self.pair.is_none() => self.pair = Some(value),
+            name => self.rest.push((name, value)),
+        }
+    }
+}
+
+impl Visit for CompactFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format!("{:?}", value));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // NOTE: Unit testing `init_logging()` is not practical because:
@@ -97,6 +246,51 @@ mod tests {
         }
     }
 
+    /// Test that "compact" selects the compact formatter and others are unaffected
+    #[test]
+    fn test_compact_format_selected() {
+        use super::LogFormat;
+
+        assert_eq!(LogFormat::parse("compact"), LogFormat::Compact);
+        assert_eq!(LogFormat::parse("json"), LogFormat::Json);
+        assert_eq!(LogFormat::parse("pretty"), LogFormat::Pretty);
+        assert_eq!(LogFormat::parse("tui"), LogFormat::Tui);
+        assert_eq!(LogFormat::parse(""), LogFormat::Json);
+    }
+
+    /// Compact lines put event_type and pair in front, other fields inline
+    #[test]
+    fn test_compact_line_layout() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+        impl std::io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .event_format(super::CompactFormatter)
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(event_type = "AWAITING_LEG", symbol = "BTC", spread = 0.12, "Entry pending");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line = output.lines().next().unwrap();
+        assert!(line.ends_with(" INFO [AWAITING_LEG] BTC Entry pending spread=0.12"), "{line}");
+        assert_eq!(output.lines().count(), 1);
+    }
+
     /// Test RUST_LOG parsing fallback
     #[test]
     fn test_env_filter_fallback() {
@@ -7,13 +7,15 @@
 
 mod loader;
 pub mod logging;
+mod reload;
 mod types;
 
 // Re-export types
-pub use types::{AppConfig, DashboardConfig, Dex, TradingPair};
+pub use types::{AppConfig, BaseAsset, DashboardConfig, Dex, QuoteCurrency, SpreadBasis, TradingPair};
 
 // Re-export loader functions
 pub use loader::{load_config, load_config_from_str};
+pub use reload::{check_reloadable, reload_monitors};
 
 // Re-export logging functions
-pub use logging::init_logging;
+pub use logging::{init_logging, init_logging_to, CompactFormatter, LogFormat};
@@ -0,0 +1,137 @@
+//! Hot reload of monitor thresholds (SIGHUP)
+//!
+//! Only thresholds can change while running: `spread_entry` and
+//! `entry_confirm_ms`. Anything that would need different adapters or
+//! subscriptions (pair, exchanges, the monitor list itself) is rejected and
+//! the running configuration is kept. Other top-level settings are read once
+//! at startup and take effect on the next restart.
+
+use std::path::Path;
+
+use crate::error::AppError;
+
+use super::loader::load_config;
+use super::types::DashboardConfig;
+
+/// Load `path` and return its monitors if they only differ from `current`
+/// in hot-reloadable fields.
+pub fn reload_monitors(
+    path: &Path,
+    current: &[DashboardConfig],
+) -> Result<Vec<DashboardConfig>, AppError> {
+    let config = load_config(path)?;
+    check_reloadable(current, &config.bots)?;
+    Ok(config.bots)
+}
+
+/// Reject a reload that changes anything other than monitor thresholds.
+pub fn check_reloadable(current: &[DashboardConfig], new: &[DashboardConfig]) -> Result<(), AppError> {
+    if current.len() != new.len() {
+        return Err(AppError::Config(format!(
+            "Reload cannot add or remove monitors ({} → {}); restart required",
+            current.len(),
+            new.len()
+        )));
+    }
+
+    for old in current {
+        let Some(updated) = new.iter().find(|bot| bot.id == old.id) else {
+            return Err(AppError::Config(format!(
+                "Reload cannot remove monitor '{}'; restart required",
+                old.id
+            )));
+        };
+        let changed = if updated.pair != old.pair {
+            Some("pair")
+        } else if updated.dex_a != old.dex_a {
+            Some("dex_a")
+        } else if updated.dex_b != old.dex_b {
+            Some("dex_b")
+        } else {
+            None
+        };
+        if let Some(field) = changed {
+            return Err(AppError::Config(format!(
+                "Monitor '{}': {} cannot be hot-reloaded; restart required",
+                old.id, field
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::load_config_from_str;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const RUNNING_CONFIG_YAML: &str = r#"
+bots:
+  - id: btc_vest_paradex
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+"#;
+
+    fn running() -> Vec<DashboardConfig> {
+        load_config_from_str(RUNNING_CONFIG_YAML).unwrap().bots
+    }
+
+    #[test]
+    fn test_reload_picks_up_new_thresholds() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(
+            file,
+            r#"
+bots:
+  - id: btc_vest_paradex
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.15
+    entry_confirm_ms: 250
+"#
+        )
+        .unwrap();
+
+        let bots = reload_monitors(file.path(), &running()).unwrap();
+        assert_eq!(bots[0].spread_entry, 0.15);
+        assert_eq!(bots[0].entry_confirm_ms, Some(250));
+    }
+
+    #[test]
+    fn test_reload_rejects_exchange_change() {
+        let mut updated = running();
+        updated[0].dex_b = crate::config::Dex::Lighter;
+
+        let err = check_reloadable(&running(), &updated).unwrap_err();
+        assert!(err.to_string().contains("dex_b cannot be hot-reloaded"));
+    }
+
+    #[test]
+    fn test_reload_rejects_monitor_list_change() {
+        let mut renamed = running();
+        renamed[0].id = "eth_vest_paradex".to_string();
+        assert!(check_reloadable(&running(), &renamed)
+            .unwrap_err()
+            .to_string()
+            .contains("cannot remove monitor 'btc_vest_paradex'"));
+
+        assert!(check_reloadable(&running(), &[]).is_err());
+    }
+
+    #[test]
+    fn test_reload_keeps_running_config_on_invalid_file() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "bots: [").unwrap();
+        assert!(reload_monitors(file.path(), &running()).is_err());
+    }
+}
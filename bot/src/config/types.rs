@@ -3,47 +3,166 @@
 //! This module defines all configuration structs that are loaded from YAML
 //! and shared across the application via `Arc<RwLock<AppConfig>>`.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
+use crate::adapters::{CrossedBookPolicy, DepthRequirement, ReplayPace, SymbolOverrides};
 use crate::error::AppError;
 
 // ============================================================================
 // Enums
 // ============================================================================
 
-/// Supported trading pairs
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-pub enum TradingPair {
-    #[serde(rename = "BTC-PERP")]
-    BtcPerp,
-    #[serde(rename = "ETH-PERP")]
-    EthPerp,
-    #[serde(rename = "SOL-PERP")]
-    SolPerp,
+/// Base assets a trading pair can be built on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BaseAsset {
+    Btc,
+    Eth,
+    Sol,
+}
+
+impl BaseAsset {
+    /// Symbol used with `resolve_symbol()` (e.g. "BTC")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BaseAsset::Btc => "BTC",
+            BaseAsset::Eth => "ETH",
+            BaseAsset::Sol => "SOL",
+        }
+    }
+}
+
+/// Supported trading pairs: a base asset quoted in a [`QuoteCurrency`]
+///
+/// Deserialized through [`TradingPair::parse`], so config may spell a pair
+/// as `BTC-PERP`, `BTC/USD`, `btc-usdc-perp`, ...; it serializes canonically
+/// (`BTC-PERP` for USD, `BTC-USDC-PERP` otherwise).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct TradingPair {
+    base: BaseAsset,
+    quote: QuoteCurrency,
+}
+
+impl TryFrom<String> for TradingPair {
+    type Error = AppError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(&s)
+    }
+}
+
+impl From<TradingPair> for String {
+    fn from(pair: TradingPair) -> Self {
+        pair.to_string()
+    }
 }
 
 impl std::fmt::Display for TradingPair {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TradingPair::BtcPerp => write!(f, "BTC-PERP"),
-            TradingPair::EthPerp => write!(f, "ETH-PERP"),
-            TradingPair::SolPerp => write!(f, "SOL-PERP"),
+        match self.quote {
+            QuoteCurrency::Usd => write!(f, "{}-PERP", self.base()),
+            quote => write!(f, "{}-{}-PERP", self.base(), quote),
         }
     }
 }
 
 impl TradingPair {
+    /// `base` perpetual quoted in `quote`
+    pub fn new(base: BaseAsset, quote: QuoteCurrency) -> Self {
+        Self { base, quote }
+    }
+
+    /// `base` perpetual quoted in USD (e.g. `BTC-PERP`)
+    pub fn perp(base: BaseAsset) -> Self {
+        Self::new(base, QuoteCurrency::Usd)
+    }
+
+    /// Parse and normalize a pair string (case-insensitive).
+    ///
+    /// Accepts `BASE`, `BASE-PERP`, `BASE-QUOTE`, `BASE/QUOTE` and
+    /// `BASE-QUOTE-PERP` (`-`, `/` or `_` as separators). Without a quote
+    /// the pair is quoted in USD.
+    pub fn parse(s: &str) -> Result<Self, AppError> {
+        let upper = s.trim().to_ascii_uppercase();
+        let mut parts = upper.split(['-', '/', '_']);
+        let base = parts.next().unwrap_or_default();
+        let rest: Vec<&str> = parts.collect();
+
+        let base = match base {
+            "BTC" => BaseAsset::Btc,
+            "ETH" => BaseAsset::Eth,
+            "SOL" => BaseAsset::Sol,
+            _ => {
+                return Err(AppError::Config(format!(
+                    "Unknown trading pair '{}': base must be one of BTC, ETH, SOL (e.g. BTC-PERP)",
+                    s
+                )))
+            }
+        };
+
+        let quote = match rest.as_slice() {
+            [] | ["PERP"] => QuoteCurrency::Usd,
+            [quote] | [quote, "PERP"] => match *quote {
+                "USD" => QuoteCurrency::Usd,
+                "USDC" => QuoteCurrency::Usdc,
+                _ => {
+                    return Err(AppError::Config(format!(
+                        "Trading pair '{}': quote must be USD or USDC, not '{}'",
+                        s, quote
+                    )))
+                }
+            },
+            _ => {
+                return Err(AppError::Config(format!(
+                    "Malformed trading pair '{}': expected BASE-PERP, BASE/QUOTE or BASE-QUOTE-PERP",
+                    s
+                )))
+            }
+        };
+
+        Ok(Self::new(base, quote))
+    }
+
     /// Returns the base asset symbol (e.g. "BTC", "ETH", "SOL")
     /// for use with `resolve_symbol()`.
     pub fn base(&self) -> &'static str {
+        self.base.as_str()
+    }
+
+    /// Returns the quote currency the pair is priced in
+    pub fn quote(&self) -> QuoteCurrency {
+        self.quote
+    }
+}
+
+/// Quote currency of a trading pair
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum QuoteCurrency {
+    #[default]
+    Usd,
+    Usdc,
+}
+
+impl QuoteCurrency {
+    /// Symbol fragment used in exchange market names (e.g. "USD")
+    pub fn as_str(&self) -> &'static str {
         match self {
-            TradingPair::BtcPerp => "BTC",
-            TradingPair::EthPerp => "ETH",
-            TradingPair::SolPerp => "SOL",
+            QuoteCurrency::Usd => "USD",
+            QuoteCurrency::Usdc => "USDC",
         }
     }
 }
 
+impl std::fmt::Display for QuoteCurrency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Supported DEX exchanges
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
@@ -60,6 +179,7 @@ pub enum Dex {
     Nado,
     Nord,
     Ethereal,
+    Backpack,
 }
 
 impl std::fmt::Display for Dex {
@@ -77,10 +197,23 @@ impl std::fmt::Display for Dex {
             Dex::Nado => write!(f, "nado"),
             Dex::Nord => write!(f, "nord"),
             Dex::Ethereal => write!(f, "ethereal"),
+            Dex::Backpack => write!(f, "backpack"),
         }
     }
 }
 
+/// Which prices of each leg a spread is measured between
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpreadBasis {
+    /// Buy at one leg's ask, sell at the other's bid — what a trade captures
+    #[default]
+    Crossing,
+    /// Between the legs' mid prices — ignores each book's own bid-ask
+    /// spread, so it is less noisy; for signal research, not execution
+    Mid,
+}
+
 // ============================================================================
 // Configuration Structs
 // ============================================================================
@@ -98,41 +231,50 @@ pub struct DashboardConfig {
     pub dex_b: Dex,
     /// Spread alert threshold (percentage, e.g., 0.30 = 0.30%)
     pub spread_entry: f64,
+    /// Require the spread to hold above `spread_entry` this long before
+    /// alerting, instead of a tick count (milliseconds)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry_confirm_ms: Option<u64>,
 }
 
 impl DashboardConfig {
     /// Validate dashboard configuration rules
     pub fn validate(&self) -> Result<(), AppError> {
+        reject_violations(self.violations())
+    }
+
+    /// Every rule this monitor breaks, in a human-readable form
+    fn violations(&self) -> Vec<String> {
+        let mut violations = Vec::new();
+
         // Rule: ID cannot be empty
         if self.id.trim().is_empty() {
-            return Err(AppError::Config("Monitor ID cannot be empty".to_string()));
+            violations.push("Monitor ID cannot be empty".to_string());
         }
 
-        // Rule: spread_entry must be in valid range (0% to 100%)
-        if self.spread_entry <= 0.0 || self.spread_entry >= 100.0 {
-            return Err(AppError::Config(format!(
+        // Rule: no NaN or Infinity in numeric fields, and spread_entry in
+        // valid range (0% to 100%)
+        if !self.spread_entry.is_finite() {
+            violations.push(format!(
+                "Monitor '{}': spread_entry must be a finite number (got {})",
+                self.id, self.spread_entry
+            ));
+        } else if self.spread_entry <= 0.0 || self.spread_entry >= 100.0 {
+            violations.push(format!(
                 "Monitor '{}': spread_entry must be > 0 and < 100% (got {})",
                 self.id, self.spread_entry
-            )));
+            ));
         }
 
         // Rule: dex_a ≠ dex_b
         if self.dex_a == self.dex_b {
-            return Err(AppError::Config(format!(
+            violations.push(format!(
                 "Monitor '{}': dex_a and dex_b cannot be the same (both are {})",
                 self.id, self.dex_a
-            )));
-        }
-
-        // Rule: no NaN or Infinity in numeric fields
-        if !self.spread_entry.is_finite() {
-            return Err(AppError::Config(format!(
-                "Monitor '{}': spread_entry must be a finite number (got {})",
-                self.id, self.spread_entry
-            )));
+            ));
         }
 
-        Ok(())
+        violations
     }
 }
 
@@ -142,37 +284,266 @@ pub struct AppConfig {
     /// List of dashboard monitor configurations
     #[serde(alias = "monitors")]
     pub bots: Vec<DashboardConfig>,
+    /// Dead-man's switch timeout for the price pipeline (ms). Disabled when unset.
+    #[serde(default)]
+    pub deadman_timeout_ms: Option<u64>,
+    /// Report `SPREAD_STALL` when no cross-exchange spread has been computed
+    /// for this long (ms), even if every adapter looks healthy. Disabled when unset.
+    #[serde(default)]
+    pub spread_stall_ms: Option<u64>,
+    /// How long shutdown waits for tasks to stop before aborting them (ms).
+    /// Defaults to 5000 when unset.
+    #[serde(default)]
+    pub shutdown_timeout_ms: Option<u64>,
+    /// Append opportunities and exchange status changes to this file as
+    /// JSONL. Disabled when unset.
+    #[serde(default)]
+    pub event_log_path: Option<String>,
+    /// Also log at most one price tick per exchange and symbol this often
+    /// (ms); 0 logs every tick. Price ticks are left out of the event log
+    /// when unset.
+    #[serde(default)]
+    pub event_log_price_interval_ms: Option<u64>,
+    /// Smooth the spread with an EMA of this alpha before gating alerts. Disabled when unset.
+    #[serde(default)]
+    pub spread_ema_alpha: Option<f64>,
+    /// Taker fee per exchange in basis points, added to each monitor's
+    /// `spread_entry` before alerting. Exchanges not listed pay 0.
+    #[serde(default)]
+    pub taker_fee_bps: HashMap<Dex, f64>,
+    /// Capacity of the event channel feeding WS clients. A client that falls
+    /// this many events behind is resynced. Defaults to 1024 when unset.
+    #[serde(default)]
+    pub event_channel_capacity: Option<usize>,
+    /// Suppress alerts whose legs were quoted more than this far apart (ms).
+    /// Disabled when unset.
+    #[serde(default)]
+    pub max_quote_skew_ms: Option<u64>,
+    /// Collect opportunities across pairs for this long and mark the
+    /// best-scoring one `selected` (ms). Without it, only opportunities from
+    /// the same price update compete.
+    #[serde(default)]
+    pub dispatch_window_ms: Option<u64>,
+    /// Log but do not alert on opportunities for this long after startup,
+    /// while books fill in (ms). Disabled when unset.
+    #[serde(default)]
+    pub warmup_ms: Option<u64>,
+    /// Exchange symbols for bases listed outside the usual template
+    /// (e.g. `lighter: {PEPE: 1000PEPE}`). Unlisted bases use the template.
+    #[serde(default)]
+    pub symbol_overrides: HashMap<Dex, HashMap<String, String>>,
+    /// Record every orderbook change to this file as JSONL, for later
+    /// replay. Disabled when unset.
+    #[serde(default)]
+    pub record_path: Option<String>,
+    /// Replay orderbooks from this recording instead of connecting to the
+    /// exchanges. Disabled when unset.
+    #[serde(default)]
+    pub replay_path: Option<String>,
+    /// Replay as fast as possible rather than at the recorded cadence.
+    #[serde(default)]
+    pub replay_unthrottled: bool,
+    /// Prices the reported spread (history, `/metrics`, stall alarm) is
+    /// measured between: `crossing` (default) or `mid`. Opportunity
+    /// detection always uses crossing prices.
+    #[serde(default)]
+    pub spread_basis: SpreadBasis,
+    /// Skip opportunities where either leg's book has fewer levels per side.
+    /// Disabled when unset.
+    #[serde(default)]
+    pub min_book_levels: Option<usize>,
+    /// Skip opportunities where either leg's book holds less notional per
+    /// side over its top levels. Disabled when unset.
+    #[serde(default)]
+    pub min_book_notional: Option<f64>,
+    /// Price both legs at the VWAP of filling this quantity (base units),
+    /// walking the buy leg's asks and the sell leg's bids, and skip
+    /// opportunities whose spread at that size misses the entry threshold.
+    /// Disabled when unset.
+    #[serde(default)]
+    pub depth_quantity: Option<f64>,
+    /// Clamp each opportunity's size (`depth_quantity`, or the top of book
+    /// when unset) to what both legs fill with a VWAP within this percent
+    /// of their best price. Disabled when unset.
+    #[serde(default)]
+    pub max_slippage_pct: Option<f64>,
+    /// Skip opportunities where either leg's book leans against the trade
+    /// by more than this order-flow imbalance (0..=1 over the top 5 levels):
+    /// asks outweighing bids on the buy leg, or bids outweighing asks on the
+    /// sell leg. Disabled when unset.
+    #[serde(default)]
+    pub max_adverse_imbalance: Option<f64>,
+    /// Skip opportunities where either leg's price is further than this
+    /// (percent) from that exchange's own recent mid. Disabled when unset.
+    #[serde(default)]
+    pub max_price_deviation_pct: Option<f64>,
+    /// Quotes with best bid above best ask are dropped (`discard`, default)
+    /// or replaced by their midpoint (`clamp`)
+    #[serde(default)]
+    pub crossed_book_policy: CrossedBookPolicy,
 }
 
 impl AppConfig {
-    /// Validate all configuration rules
+    /// Validate all configuration rules, reporting every violation at once
     pub fn validate(&self) -> Result<(), AppError> {
+        let mut violations = Vec::new();
+
         // Rule: At least one monitor must be configured
         if self.bots.is_empty() {
-            return Err(AppError::Config(
-                "Configuration must contain at least one monitor".to_string(),
-            ));
+            violations.push("Configuration must contain at least one monitor".to_string());
         }
 
         // Rule: No duplicate monitor IDs
         let mut seen_ids = std::collections::HashSet::new();
         for bot in &self.bots {
             if !seen_ids.insert(&bot.id) {
-                return Err(AppError::Config(format!(
-                    "Duplicate monitor ID: '{}'",
-                    bot.id
-                )));
+                violations.push(format!("Duplicate monitor ID: '{}'", bot.id));
             }
         }
 
         // Validate each monitor configuration
         for bot in &self.bots {
-            bot.validate()?;
+            violations.extend(bot.violations());
         }
 
-        Ok(())
+        // Rule: dead-man's switch timeout must be non-zero when enabled
+        if self.deadman_timeout_ms == Some(0) {
+            violations.push(
+                "deadman_timeout_ms must be > 0 (omit it to disable the switch)".to_string(),
+            );
+        }
+
+        // Rule: the spread stall interval must be non-zero when enabled
+        if self.spread_stall_ms == Some(0) {
+            violations.push("spread_stall_ms must be > 0 (omit it to disable the alarm)".to_string());
+        }
+
+        // Rule: the event channel must hold at least one event
+        if self.event_channel_capacity == Some(0) {
+            violations.push("event_channel_capacity must be > 0".to_string());
+        }
+
+        // Rule: the dispatch window must be non-zero when enabled
+        if self.dispatch_window_ms == Some(0) {
+            violations.push("dispatch_window_ms must be > 0 (omit it to dispatch at once)".to_string());
+        }
+
+        // Rule: EMA alpha must be in (0, 1] when enabled
+        if let Some(alpha) = self.spread_ema_alpha {
+            if !(alpha > 0.0 && alpha <= 1.0) {
+                violations.push(format!("spread_ema_alpha must be in (0, 1] (got {})", alpha));
+            }
+        }
+
+        // Rule: taker fees must be finite and non-negative
+        for (dex, &fee) in &self.taker_fee_bps {
+            if !fee.is_finite() || fee < 0.0 {
+                violations.push(format!("taker_fee_bps for '{}' must be >= 0, got {}", dex, fee));
+            }
+        }
+
+        // Rule: symbol overrides need a base and a symbol
+        for (dex, bases) in &self.symbol_overrides {
+            for (base, symbol) in bases {
+                if base.trim().is_empty() || symbol.trim().is_empty() {
+                    violations.push(format!(
+                        "symbol_overrides for '{}': base and symbol must be non-empty (got '{}' → '{}')",
+                        dex, base, symbol
+                    ));
+                }
+            }
+        }
+
+        // Rule: the book notional minimum must be finite and non-negative
+        if let Some(notional) = self.min_book_notional {
+            if !notional.is_finite() || notional < 0.0 {
+                violations.push(format!("min_book_notional must be >= 0, got {}", notional));
+            }
+        }
+
+        // Rule: the depth-aware trade size must be finite and positive
+        if let Some(quantity) = self.depth_quantity {
+            if !quantity.is_finite() || quantity <= 0.0 {
+                violations.push(format!("depth_quantity must be > 0, got {}", quantity));
+            }
+        }
+
+        // Rule: the slippage budget must be finite and positive
+        if let Some(slippage) = self.max_slippage_pct {
+            if !slippage.is_finite() || slippage <= 0.0 {
+                violations.push(format!("max_slippage_pct must be > 0, got {}", slippage));
+            }
+        }
+
+        // Rule: an imbalance limit outside (0, 1) would skip every or no opportunity
+        if let Some(imbalance) = self.max_adverse_imbalance {
+            if !(imbalance > 0.0 && imbalance < 1.0) {
+                violations.push(format!("max_adverse_imbalance must be in (0, 1) (got {})", imbalance));
+            }
+        }
+
+        // Rule: the price band must be finite and positive
+        if let Some(deviation) = self.max_price_deviation_pct {
+            if !deviation.is_finite() || deviation <= 0.0 {
+                violations.push(format!("max_price_deviation_pct must be > 0, got {}", deviation));
+            }
+        }
+
+        // Rule: a replayed session cannot be recorded again
+        if self.record_path.is_some() && self.replay_path.is_some() {
+            violations.push("record_path and replay_path cannot both be set".to_string());
+        }
+
+        reject_violations(violations)
+    }
+
+    /// Playback speed for `replay_path`.
+    pub fn replay_pace(&self) -> ReplayPace {
+        if self.replay_unthrottled {
+            ReplayPace::Unthrottled
+        } else {
+            ReplayPace::Recorded
+        }
     }
 
+    /// Book depth both legs must show before an opportunity is emitted,
+    /// `None` when neither minimum is configured.
+    pub fn depth_requirement(&self) -> Option<DepthRequirement> {
+        if self.min_book_levels.is_none() && self.min_book_notional.is_none() {
+            return None;
+        }
+        Some(DepthRequirement {
+            min_levels: self.min_book_levels.unwrap_or(0),
+            min_notional: self.min_book_notional.unwrap_or(0.0),
+        })
+    }
+
+    /// Symbol overrides keyed by exchange name, as the adapters expect them.
+    pub fn symbol_overrides(&self) -> SymbolOverrides {
+        SymbolOverrides::new(
+            self.symbol_overrides
+                .iter()
+                .map(|(dex, bases)| (dex.to_string(), bases.clone()))
+                .collect(),
+        )
+    }
+
+    /// Taker fees keyed by exchange name, as the detector expects them.
+    pub fn detector_taker_fees(&self) -> HashMap<Arc<str>, f64> {
+        self.taker_fee_bps
+            .iter()
+            .map(|(dex, &fee)| (Arc::from(dex.to_string()), fee))
+            .collect()
+    }
+}
+
+/// Fold collected rule violations into a single config error.
+fn reject_violations(violations: Vec<String>) -> Result<(), AppError> {
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(AppError::Config(violations.join("; ")))
+    }
 }
 
 // ============================================================================
@@ -186,10 +557,11 @@ mod tests {
     fn create_valid_config() -> DashboardConfig {
         DashboardConfig {
             id: "test_monitor".to_string(),
-            pair: TradingPair::BtcPerp,
+            pair: TradingPair::perp(BaseAsset::Btc),
             dex_a: Dex::Vest,
             dex_b: Dex::Paradex,
             spread_entry: 0.30,
+            entry_confirm_ms: None,
         }
     }
 
@@ -245,7 +617,46 @@ bots:
     fn test_trading_pair_serde() {
         let yaml = "\"BTC-PERP\"";
         let pair: TradingPair = serde_yaml::from_str(yaml).unwrap();
-        assert_eq!(pair, TradingPair::BtcPerp);
+        assert_eq!(pair, TradingPair::perp(BaseAsset::Btc));
+        let eth = TradingPair::perp(BaseAsset::Eth);
+        assert_eq!(serde_yaml::to_string(&eth).unwrap().trim(), "ETH-PERP");
+        let eth_usdc = TradingPair::new(BaseAsset::Eth, QuoteCurrency::Usdc);
+        assert_eq!(serde_yaml::to_string(&eth_usdc).unwrap().trim(), "ETH-USDC-PERP");
+    }
+
+    #[test]
+    fn test_trading_pair_parse_accepts_common_forms() {
+        for s in ["BTC-PERP", "BTC/USD", "btc-usd-perp", " Btc_Usd ", "btc"] {
+            assert_eq!(TradingPair::parse(s).unwrap(), TradingPair::perp(BaseAsset::Btc), "{s}");
+        }
+        assert_eq!(TradingPair::parse("eth/usd").unwrap(), TradingPair::perp(BaseAsset::Eth));
+        assert_eq!(TradingPair::parse("SOL-USD-PERP").unwrap(), TradingPair::perp(BaseAsset::Sol));
+    }
+
+    #[test]
+    fn test_trading_pair_parses_usdc_quote() {
+        for s in ["BTC/USDC", "btc-usdc-perp", "BTC_USDC"] {
+            let pair = TradingPair::parse(s).unwrap();
+            assert_eq!(pair.base(), "BTC", "{s}");
+            assert_eq!(pair.quote(), QuoteCurrency::Usdc, "{s}");
+        }
+        assert_eq!(TradingPair::parse("BTC-PERP").unwrap().quote(), QuoteCurrency::Usd);
+        // Round-trips through its canonical form
+        let pair = TradingPair::parse("eth/usdc").unwrap();
+        assert_eq!(TradingPair::parse(&pair.to_string()).unwrap(), pair);
+    }
+
+    #[test]
+    fn test_trading_pair_parse_rejects_garbage() {
+        let err = |s: &str| TradingPair::parse(s).unwrap_err().to_string();
+        assert!(err("DOGE-PERP").contains("Unknown trading pair 'DOGE-PERP'"));
+        assert!(err("").contains("Unknown trading pair"));
+        assert!(err("BTC-USD-PERP-X").contains("Malformed trading pair"));
+        assert!(err("BTC/EUR").contains("quote must be USD or USDC, not 'EUR'"));
+
+        let yaml = "bots:\n  - id: m\n    pair: BTC/EUR\n    dex_a: vest\n    dex_b: paradex\n    spread_entry: 0.3\n";
+        let parse_err = serde_yaml::from_str::<AppConfig>(yaml).unwrap_err().to_string();
+        assert!(parse_err.contains("not 'EUR'"), "{parse_err}");
     }
 
     #[test]
@@ -284,7 +695,7 @@ bots:
 
     #[test]
     fn test_empty_bots_array_fails() {
-        let config = AppConfig { bots: vec![] };
+        let config = AppConfig { bots: vec![], ..Default::default() };
         let result = config.validate();
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("at least one monitor"));
@@ -306,6 +717,273 @@ bots:
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_deadman_timeout_deserialize() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+deadman_timeout_ms: 15000
+"#;
+        let config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.deadman_timeout_ms, Some(15_000));
+    }
+
+    #[test]
+    fn test_replay_config_deserialize() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+replay_path: books.jsonl
+replay_unthrottled: true
+"#;
+        let mut config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.replay_path.as_deref(), Some("books.jsonl"));
+        assert_eq!(config.replay_pace(), ReplayPace::Unthrottled);
+
+        config.record_path = Some("books.jsonl".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("record_path and replay_path"), "{}", err);
+    }
+
+    #[test]
+    fn test_depth_requirement_config() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+min_book_levels: 3
+"#;
+        let mut config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(
+            config.depth_requirement(),
+            Some(DepthRequirement { min_levels: 3, min_notional: 0.0 })
+        );
+
+        config.min_book_notional = Some(-1.0);
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("min_book_notional"), "{}", err);
+
+        assert_eq!(AppConfig::default().depth_requirement(), None);
+    }
+
+    #[test]
+    fn test_depth_quantity_config() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+depth_quantity: 0.5
+"#;
+        let mut config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.depth_quantity, Some(0.5));
+
+        config.depth_quantity = Some(0.0);
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("depth_quantity"), "{}", err);
+    }
+
+    #[test]
+    fn test_max_slippage_config() {
+        let config = AppConfig {
+            max_slippage_pct: Some(-0.1),
+            ..AppConfig::default()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("max_slippage_pct"), "{}", err);
+    }
+
+    #[test]
+    fn test_max_adverse_imbalance_config() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+max_adverse_imbalance: 0.6
+"#;
+        let mut config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        assert_eq!(config.max_adverse_imbalance, Some(0.6));
+
+        config.max_adverse_imbalance = Some(1.0);
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("max_adverse_imbalance"), "{}", err);
+    }
+
+    #[test]
+    fn test_price_band_config() {
+        let config = AppConfig {
+            max_price_deviation_pct: Some(0.0),
+            ..AppConfig::default()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("max_price_deviation_pct"), "{}", err);
+
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+max_price_deviation_pct: 2.0
+"#;
+        let mut config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+
+        // Legs are held against their own mids, not each other, so a band
+        // narrower than the entry spread is fine
+        config.max_price_deviation_pct = Some(0.25);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_spread_basis_deserialize() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+spread_basis: mid
+"#;
+        let config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.spread_basis, SpreadBasis::Mid);
+        assert_eq!(AppConfig::default().spread_basis, SpreadBasis::Crossing);
+    }
+
+    #[test]
+    fn test_crossed_book_policy_deserialize() {
+        let yaml = r#"
+monitors:
+  - id: test_monitor
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+crossed_book_policy: clamp
+"#;
+        let config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.crossed_book_policy, CrossedBookPolicy::Clamp);
+        assert_eq!(AppConfig::default().crossed_book_policy, CrossedBookPolicy::Discard);
+    }
+
+    #[test]
+    fn test_deadman_timeout_zero_fails() {
+        let config = AppConfig {
+            bots: vec![create_valid_config()],
+            deadman_timeout_ms: Some(0),
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("deadman_timeout_ms"));
+    }
+
+    #[test]
+    fn test_event_channel_capacity_zero_fails() {
+        let config = AppConfig {
+            bots: vec![create_valid_config()],
+            event_channel_capacity: Some(0),
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("event_channel_capacity"));
+    }
+
+    #[test]
+    fn test_spread_ema_alpha_out_of_range_fails() {
+        let config = AppConfig {
+            bots: vec![create_valid_config()],
+            spread_ema_alpha: Some(1.5),
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.unwrap_err().to_string().contains("alpha"));
+    }
+
+    #[test]
+    fn test_taker_fees_parse_and_convert() {
+        let yaml = r#"
+bots:
+  - id: btc_vest_paradex
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+taker_fee_bps:
+  vest: 2.5
+  paradex: 2.0
+"#;
+        let config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        let fees = config.detector_taker_fees();
+        assert_eq!(fees.get("vest"), Some(&2.5));
+        assert_eq!(fees.get("paradex"), Some(&2.0));
+        assert_eq!(fees.get("lighter"), None);
+    }
+
+    #[test]
+    fn test_symbol_overrides_parse_and_validate() {
+        let yaml = r#"
+bots:
+  - id: btc_vest_lighter
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: lighter
+    spread_entry: 0.30
+symbol_overrides:
+  lighter:
+    PEPE: 1000PEPE
+"#;
+        let mut config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+        let overrides = config.symbol_overrides();
+        assert_eq!(overrides.resolve("lighter", "PEPE").unwrap(), "1000PEPE");
+        assert_eq!(overrides.resolve("vest", "PEPE").unwrap(), "PEPE-PERP");
+
+        config
+            .symbol_overrides
+            .get_mut(&Dex::Lighter)
+            .unwrap()
+            .insert("BONK".to_string(), " ".to_string());
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("symbol_overrides for 'lighter'"), "{}", err);
+    }
+
+    #[test]
+    fn test_negative_taker_fee_fails() {
+        let config = AppConfig {
+            bots: vec![create_valid_config()],
+            taker_fee_bps: HashMap::from([(Dex::Vest, -1.0)]),
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(result.unwrap_err().to_string().contains("taker_fee_bps"));
+    }
+
     #[test]
     fn test_nan_spread_entry_fails() {
         let mut cfg = create_valid_config();
@@ -313,4 +991,23 @@ bots:
         let result = cfg.validate();
         assert!(result.is_err(), "NaN spread_entry should fail validation");
     }
+
+    #[test]
+    fn test_validate_reports_every_violation() {
+        let mut bad_monitor = create_valid_config();
+        bad_monitor.spread_entry = 0.0;
+        bad_monitor.dex_b = bad_monitor.dex_a.clone();
+        let config = AppConfig {
+            bots: vec![bad_monitor],
+            deadman_timeout_ms: Some(0),
+            spread_ema_alpha: Some(2.0),
+            ..Default::default()
+        };
+
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("spread_entry must be > 0"), "{}", err);
+        assert!(err.contains("dex_a and dex_b cannot be the same"), "{}", err);
+        assert!(err.contains("deadman_timeout_ms"), "{}", err);
+        assert!(err.contains("spread_ema_alpha"), "{}", err);
+    }
 }
@@ -7,7 +7,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::types::{AggregatedPrice, ExchangePrice, PriceData, current_time_ms};
+use crate::core::types::{AggregatedPrice, PriceData, current_time_ms};
 
 /// Default maximum age for prices (30 seconds).
 const DEFAULT_MAX_AGE_MS: u64 = 30_000;
@@ -65,6 +65,7 @@ impl PriceAggregator {
                     best_bid: None,
                     best_ask: None,
                     timestamp_ms: now,
+                    last_update_ms: 0,
                 };
             }
         };
@@ -76,58 +77,18 @@ impl PriceAggregator {
             .cloned()
             .collect();
 
-        // Find best bid (highest) and best ask (lowest)
-        let mut best_bid: Option<ExchangePrice> = None;
-        let mut best_ask: Option<ExchangePrice> = None;
-
-        for price in &valid_prices {
-            match &best_bid {
-                None => {
-                    best_bid = Some(ExchangePrice {
-                        exchange: price.exchange.clone(),
-                        price: price.bid,
-                    });
-                }
-                Some(current) if price.bid > current.price => {
-                    best_bid = Some(ExchangePrice {
-                        exchange: price.exchange.clone(),
-                        price: price.bid,
-                    });
-                }
-                _ => {}
-            }
-
-            match &best_ask {
-                None => {
-                    best_ask = Some(ExchangePrice {
-                        exchange: price.exchange.clone(),
-                        price: price.ask,
-                    });
-                }
-                Some(current) if price.ask < current.price => {
-                    best_ask = Some(ExchangePrice {
-                        exchange: price.exchange.clone(),
-                        price: price.ask,
-                    });
-                }
-                _ => {}
-            }
-        }
-
-        AggregatedPrice {
-            symbol: Arc::from(symbol),
-            prices: valid_prices,
-            best_bid,
-            best_ask,
-            timestamp_ms: now,
-        }
+        AggregatedPrice::from_prices(Arc::from(symbol), valid_prices, now)
     }
 
     /// Get aggregated prices for all symbols.
+    ///
+    /// Symbols whose every price is older than `max_age_ms` are left out,
+    /// even before `evict_stale()` removes them.
     pub fn get_all(&self) -> Vec<AggregatedPrice> {
         self.prices
             .keys()
             .map(|symbol| self.aggregate(symbol))
+            .filter(|agg| !agg.prices.is_empty())
             .collect()
     }
 
@@ -138,13 +99,24 @@ impl PriceAggregator {
 
     /// Remove stale prices from all symbols.
     pub fn cleanup(&mut self) {
-        let now = current_time_ms();
+        self.evict_stale(current_time_ms());
+    }
+
+    /// Drop prices older than `max_age_ms` as of `now`, and symbols left
+    /// without any. Returns the number of prices evicted.
+    ///
+    /// Called on a timer so an exchange that goes dark stops being served
+    /// even when no further updates arrive.
+    pub fn evict_stale(&mut self, now: u64) -> usize {
+        let before = self.price_count();
+        let max_age_ms = self.max_age_ms;
         self.prices.retain(|_, exchange_prices| {
             exchange_prices.retain(|_, price| {
-                now.saturating_sub(price.timestamp_ms) <= self.max_age_ms
+                now.saturating_sub(price.timestamp_ms) <= max_age_ms
             });
             !exchange_prices.is_empty()
         });
+        before - self.price_count()
     }
 
     /// Number of symbols currently tracked.
@@ -175,6 +147,7 @@ mod tests {
             bid,
             ask,
             timestamp_ms: current_time_ms(),
+            exchange_time_ms: None,
         }
     }
 
@@ -235,4 +208,30 @@ mod tests {
         agg.cleanup();
         assert_eq!(agg.symbol_count(), 0);
     }
+
+    #[test]
+    fn test_evict_stale_keeps_fresher_entries() {
+        let mut agg = PriceAggregator::with_max_age(1_000);
+        let now = current_time_ms();
+        let mut dark = make_price("vest", "BTC", 50000.0, 50010.0);
+        dark.timestamp_ms = now - 1_500;
+        let mut fresh = make_price("paradex", "BTC", 50020.0, 50030.0);
+        fresh.timestamp_ms = now - 500;
+        let mut dark_eth = make_price("vest", "ETH", 3000.0, 3001.0);
+        dark_eth.timestamp_ms = now - 2_000;
+        agg.update(dark);
+        agg.update(fresh);
+        agg.update(dark_eth);
+
+        // Stale symbols are hidden from get_all() before eviction runs
+        let all = agg.get_all();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].last_update_ms, now - 500);
+
+        assert_eq!(agg.evict_stale(now), 2);
+        assert!(agg.get_price("vest", "BTC").is_none());
+        assert!(agg.get_price("paradex", "BTC").is_some());
+        assert_eq!(agg.symbol_count(), 1);
+        assert_eq!(agg.price_count(), 1);
+    }
 }
@@ -4,11 +4,14 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
+use tokio::time::Instant;
 
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::adapters::Orderbook;
+use crate::core::types::current_time_ms;
 
 /// Type alias for shared orderbooks used across all modules
 ///
@@ -46,6 +49,14 @@ impl AtomicBestPrices {
         self.best_ask_bits.store(ask.to_bits(), Ordering::Release);
     }
 
+    /// Store best bid and ask prices, returning whether either changed
+    #[inline]
+    pub fn store_if_changed(&self, bid: f64, ask: f64) -> bool {
+        let old_bid = self.best_bid_bits.swap(bid.to_bits(), Ordering::AcqRel);
+        let old_ask = self.best_ask_bits.swap(ask.to_bits(), Ordering::AcqRel);
+        old_bid != bid.to_bits() || old_ask != ask.to_bits()
+    }
+
     /// Load best bid and ask prices (called by monitoring/exit loop)
     #[inline]
     pub fn load(&self) -> (f64, f64) {
@@ -71,11 +82,210 @@ pub type SharedBestPrices = Arc<AtomicBestPrices>;
 /// The monitoring loop `notified().await`s instead of polling on a fixed interval.
 pub type OrderbookNotify = Arc<tokio::sync::Notify>;
 
+/// Publishes a reader's best prices and wakes the monitoring loop
+///
+/// Only a change in best bid or ask notifies; updates deeper in the book
+/// would just cause a spurious recompute. `notify_every_update` restores
+/// waking on every book (for debugging).
+pub struct BestPricePublisher {
+    best_prices: SharedBestPrices,
+    notify: Option<OrderbookNotify>,
+    notify_every_update: bool,
+}
+
+impl BestPricePublisher {
+    pub fn new(best_prices: SharedBestPrices, notify: Option<OrderbookNotify>, notify_every_update: bool) -> Self {
+        Self {
+            best_prices,
+            notify,
+            notify_every_update,
+        }
+    }
+
+    /// Store `orderbook`'s best prices; returns whether waiters were notified
+    pub fn publish(&self, orderbook: &Orderbook) -> bool {
+        let changed = self.best_prices.store_if_changed(
+            orderbook.best_bid().unwrap_or(0.0),
+            orderbook.best_ask().unwrap_or(0.0),
+        );
+        match &self.notify {
+            Some(n) if changed || self.notify_every_update => {
+                n.notify_waiters();
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Receives `(received_ms, symbol, book)` for every book a reader writes,
+/// ahead of any throttling (see [`ThrottledBookWriter::with_tap`])
+pub type BookTap = tokio::sync::mpsc::UnboundedSender<(u64, String, Orderbook)>;
+
+/// Coalesces a reader's writes into `SharedOrderbooks`
+///
+/// At most one write lock is taken per `min_interval`: the first book after
+/// a quiet period is written through, later ones are held as pending (latest
+/// wins per symbol) until the interval has passed. Readers `select!` on
+/// `flush_deadline()` so the last update of a burst is still published when
+/// the feed goes quiet. A zero interval writes every book through.
+pub struct ThrottledBookWriter {
+    books: SharedOrderbooks,
+    min_interval: Duration,
+    pending: HashMap<String, Orderbook>,
+    last_write: Option<Instant>,
+    writes: u64,
+    tap: Option<BookTap>,
+}
+
+impl ThrottledBookWriter {
+    /// Write into `books` at most once per `min_interval_ms` (0 = no throttle)
+    pub fn new(books: SharedOrderbooks, min_interval_ms: u64) -> Self {
+        Self {
+            books,
+            min_interval: Duration::from_millis(min_interval_ms),
+            pending: HashMap::new(),
+            last_write: None,
+            writes: 0,
+            tap: None,
+        }
+    }
+
+    /// Also send every book passed to [`Self::write`] to `tap`, coalesced
+    /// or not (used to record the raw feed)
+    pub fn with_tap(mut self, tap: Option<BookTap>) -> Self {
+        self.tap = tap;
+        self
+    }
+
+    /// Queue `orderbook` for `symbol`, writing now if the interval has passed
+    pub async fn write(&mut self, symbol: String, orderbook: Orderbook) {
+        if let Some(tap) = &self.tap {
+            // The receiver goes away with the recording; nothing to do then
+            let _ = tap.send((current_time_ms(), symbol.clone(), orderbook.clone()));
+        }
+        self.pending.insert(symbol, orderbook);
+        if self.flush_deadline().is_some_and(|at| at <= Instant::now()) {
+            self.flush().await;
+        }
+    }
+
+    /// When pending books are due, or `None` if nothing is pending
+    pub fn flush_deadline(&self) -> Option<Instant> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        Some(match self.last_write {
+            Some(last) => last + self.min_interval,
+            None => Instant::now(),
+        })
+    }
+
+    /// Write all pending books under a single lock
+    pub async fn flush(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+        let mut books = self.books.write().await;
+        books.extend(self.pending.drain());
+        self.last_write = Some(Instant::now());
+        self.writes += 1;
+    }
+
+    /// Number of write locks taken so far
+    pub fn write_count(&self) -> u64 {
+        self.writes
+    }
+}
+
+/// Sleep until `deadline`, or forever when there is none (for `select!`)
+pub async fn sleep_until_deadline(deadline: Option<Instant>) {
+    match deadline {
+        Some(at) => tokio::time::sleep_until(at).await,
+        None => std::future::pending().await,
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::adapters::types::{Orderbook, OrderbookLevel, OrderbookUpdate};
     use tokio::sync::mpsc;
 
+    #[tokio::test]
+    async fn test_rapid_updates_coalesce_into_one_write() {
+        let shared: SharedOrderbooks = Arc::new(RwLock::new(HashMap::new()));
+        let (tap, mut taps) = mpsc::unbounded_channel();
+        let mut writer = ThrottledBookWriter::new(shared.clone(), 60_000).with_tap(Some(tap));
+
+        // First book after a quiet period goes straight through
+        writer.write("BTC".to_string(), Orderbook::from_levels(&[(100.0, 1.0)], &[(101.0, 1.0)])).await;
+        assert_eq!(writer.write_count(), 1);
+
+        // A burst within the interval only updates the pending book
+        for bid in [101.0, 102.0, 103.0] {
+            writer.write("BTC".to_string(), Orderbook::from_levels(&[(bid, 1.0)], &[(bid + 1.0, 1.0)])).await;
+        }
+        writer.write("ETH".to_string(), Orderbook::from_levels(&[(3000.0, 1.0)], &[(3001.0, 1.0)])).await;
+        assert_eq!(writer.write_count(), 1);
+        assert_eq!(shared.read().await["BTC"].best_bid(), Some(100.0));
+        assert!(writer.flush_deadline().unwrap() > Instant::now());
+
+        // The flush publishes the latest book per symbol under one lock
+        writer.flush().await;
+        assert_eq!(writer.write_count(), 2);
+        let books = shared.read().await;
+        assert_eq!(books["BTC"].best_bid(), Some(103.0));
+        assert_eq!(books["ETH"].best_bid(), Some(3000.0));
+        assert!(writer.flush_deadline().is_none());
+
+        // The tap saw every book, coalesced or not
+        let mut tapped = Vec::new();
+        while let Ok((_, symbol, book)) = taps.try_recv() {
+            tapped.push((symbol, book.best_bid().unwrap()));
+        }
+        let bids: Vec<f64> = tapped.iter().map(|(_, bid)| *bid).collect();
+        assert_eq!(bids, vec![100.0, 101.0, 102.0, 103.0, 3000.0]);
+    }
+
+    #[tokio::test]
+    async fn test_publisher_notifies_only_on_top_of_book_change() {
+        let notify: OrderbookNotify = Arc::new(tokio::sync::Notify::new());
+        let publisher = BestPricePublisher::new(Arc::new(AtomicBestPrices::new()), Some(notify.clone()), false);
+
+        let mut deep = Orderbook::from_levels(&[(100.0, 1.0)], &[(101.0, 1.0)]);
+        assert!(publisher.publish(&deep));
+
+        let waiter = notify.notified();
+        tokio::pin!(waiter);
+        waiter.as_mut().enable();
+
+        // Same best bid/ask, different second level
+        deep.bids.push(OrderbookLevel::new(99.0, 5.0));
+        assert!(!publisher.publish(&deep));
+        assert!(tokio::time::timeout(Duration::from_millis(20), waiter.as_mut()).await.is_err());
+
+        assert!(publisher.publish(&Orderbook::from_levels(&[(100.5, 1.0)], &[(101.5, 1.0)])));
+        tokio::time::timeout(Duration::from_millis(20), waiter)
+            .await
+            .expect("top-of-book change wakes the waiter");
+
+        let always = BestPricePublisher::new(Arc::new(AtomicBestPrices::new()), Some(notify.clone()), true);
+        assert!(always.publish(&deep));
+        assert!(always.publish(&deep));
+    }
+
+    #[tokio::test]
+    async fn test_zero_interval_writes_through() {
+        let shared: SharedOrderbooks = Arc::new(RwLock::new(HashMap::new()));
+        let mut writer = ThrottledBookWriter::new(shared.clone(), 0);
+        for bid in [100.0, 101.0, 102.0] {
+            writer.write("BTC".to_string(), Orderbook::from_levels(&[(bid, 1.0)], &[(bid + 1.0, 1.0)])).await;
+        }
+        assert_eq!(writer.write_count(), 3);
+        assert_eq!(shared.read().await["BTC"].best_bid(), Some(102.0));
+    }
+
     #[tokio::test]
     async fn test_orderbook_channel_send_receive() {
         let (tx, mut rx) = mpsc::channel::<OrderbookUpdate>(10);
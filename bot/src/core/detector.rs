@@ -2,13 +2,19 @@
 //!
 //! Port of the TypeScript `ArbitrageDetector` from arbi-v5.
 //! Implements freshness validation, sanity checks, confirmation logic,
-//! and per-symbol cooldowns.
+//! pre-emission gates, and per-symbol cooldowns.
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use tracing::warn;
+use tokio::time::Instant;
 
+use tracing::{info, warn};
+
+use crate::config::DashboardConfig;
+use crate::core::gate::SkipReason;
+use crate::core::spread::SpreadEma;
 use crate::core::types::{AggregatedPrice, ArbitrageOpportunity, current_time_ms};
 
 /// Configuration for the arbitrage detector.
@@ -22,8 +28,43 @@ pub struct DetectorConfig {
     pub max_realistic_spread: f64,
     /// Number of consecutive confirmations required (default 2)
     pub min_confirmations: u32,
+    /// Require the cross to hold continuously for this long instead of
+    /// `min_confirmations` ticks; takes precedence when set (default off)
+    pub confirm_window_ms: Option<u64>,
     /// Cooldown per symbol in ms (default 1000ms)
     pub cooldown_ms: u64,
+    /// Minimum interval between `AWAITING_LEG` / `QUOTE_SKEW_TOO_HIGH` / `WARMUP` /
+    /// gate skip logs per symbol (default 5000ms)
+    pub awaiting_leg_log_interval_ms: u64,
+    /// Gate the minimum threshold on an EMA of the spread with this alpha (default off)
+    pub ema_alpha: Option<f64>,
+    /// Taker fee per exchange in basis points; exchanges not listed pay 0
+    pub taker_fee_bps: HashMap<Arc<str>, f64>,
+    /// Suppress opportunities whose two legs were quoted further apart than
+    /// this (ms); a stale leg can fake a cross (default off)
+    pub max_quote_skew_ms: Option<u64>,
+    /// Compute spreads but suppress opportunities for this long after the
+    /// detector is created, while books and the EMA fill in (default off)
+    pub warmup_ms: Option<u64>,
+}
+
+impl DetectorConfig {
+    /// Spread (percent) a buy on `buy_exchange` / sell on `sell_exchange`
+    /// must reach: `min_spread_percent` plus both legs' taker fees.
+    pub fn entry_threshold_percent(&self, buy_exchange: &str, sell_exchange: &str) -> f64 {
+        let fee_bps = |exchange: &str| self.taker_fee_bps.get(exchange).copied().unwrap_or(0.0);
+        // 1 bps = 0.01%
+        self.min_spread_percent + (fee_bps(buy_exchange) + fee_bps(sell_exchange)) / 100.0
+    }
+
+    /// `self` with `monitor`'s thresholds applied.
+    pub fn for_monitor(&self, monitor: &DashboardConfig) -> DetectorConfig {
+        DetectorConfig {
+            min_spread_percent: monitor.spread_entry,
+            confirm_window_ms: monitor.entry_confirm_ms.or(self.confirm_window_ms),
+            ..self.clone()
+        }
+    }
 }
 
 impl Default for DetectorConfig {
@@ -33,7 +74,13 @@ impl Default for DetectorConfig {
             max_price_age_ms: 2_000,
             max_realistic_spread: 5.0,
             min_confirmations: 2,
+            confirm_window_ms: None,
             cooldown_ms: 1_000,
+            awaiting_leg_log_interval_ms: 5_000,
+            ema_alpha: None,
+            taker_fee_bps: HashMap::new(),
+            max_quote_skew_ms: None,
+            warmup_ms: None,
         }
     }
 }
@@ -43,6 +90,19 @@ struct PendingArb {
     buy_exchange: Arc<str>,
     sell_exchange: Arc<str>,
     count: u32,
+    /// When this pair first crossed the threshold (window mode)
+    since: Instant,
+}
+
+impl PendingArb {
+    fn new(buy_exchange: Arc<str>, sell_exchange: Arc<str>) -> Self {
+        Self {
+            buy_exchange,
+            sell_exchange,
+            count: 1,
+            since: Instant::now(),
+        }
+    }
 }
 
 /// Cross-exchange arbitrage detector with freshness and confirmation logic.
@@ -52,6 +112,18 @@ pub struct ArbitrageDetector {
     pending: HashMap<Arc<str>, PendingArb>,
     /// Cooldown tracking: symbol → last emission timestamp
     cooldowns: HashMap<Arc<str>, u64>,
+    /// Last `AWAITING_LEG` log per symbol (rate limiting)
+    awaiting_leg_logged: HashMap<Arc<str>, u64>,
+    /// Smoothed spread per symbol (EMA mode only)
+    spread_emas: HashMap<Arc<str>, SpreadEma>,
+    /// Last `QUOTE_SKEW_TOO_HIGH` log per symbol (rate limiting)
+    quote_skew_logged: HashMap<Arc<str>, u64>,
+    /// Last `WARMUP` log per symbol (rate limiting)
+    warmup_logged: HashMap<Arc<str>, u64>,
+    /// Last gate skip log per symbol (rate limiting)
+    skip_logged: HashMap<Arc<str>, u64>,
+    /// When the detector was created (warmup reference)
+    started: Instant,
 }
 
 impl ArbitrageDetector {
@@ -61,6 +133,12 @@ impl ArbitrageDetector {
             config: DetectorConfig::default(),
             pending: HashMap::new(),
             cooldowns: HashMap::new(),
+            awaiting_leg_logged: HashMap::new(),
+            spread_emas: HashMap::new(),
+            quote_skew_logged: HashMap::new(),
+            warmup_logged: HashMap::new(),
+            skip_logged: HashMap::new(),
+            started: Instant::now(),
         }
     }
 
@@ -70,6 +148,12 @@ impl ArbitrageDetector {
             config,
             pending: HashMap::new(),
             cooldowns: HashMap::new(),
+            awaiting_leg_logged: HashMap::new(),
+            spread_emas: HashMap::new(),
+            quote_skew_logged: HashMap::new(),
+            warmup_logged: HashMap::new(),
+            skip_logged: HashMap::new(),
+            started: Instant::now(),
         }
     }
 
@@ -77,18 +161,47 @@ impl ArbitrageDetector {
     ///
     /// Returns `Some(ArbitrageOpportunity)` if a confirmed opportunity is found.
     pub fn detect(&mut self, aggregated: &AggregatedPrice) -> Option<ArbitrageOpportunity> {
+        self.detect_with(aggregated, |_| Ok(()))
+    }
+
+    /// Like [`Self::detect`], but a confirmed opportunity must also pass
+    /// `gate` (see [`crate::core::gate::OpportunityGate`]).
+    ///
+    /// The gate runs before the cooldown starts: a skipped opportunity stays
+    /// confirmed and is emitted on the first tick the gate passes.
+    pub fn detect_with(
+        &mut self,
+        aggregated: &AggregatedPrice,
+        gate: impl Fn(&mut ArbitrageOpportunity) -> Result<(), SkipReason>,
+    ) -> Option<ArbitrageOpportunity> {
         let symbol = &aggregated.symbol;
         let now = current_time_ms();
 
-        // Need at least 2 exchanges
-        if aggregated.prices.len() < 2 {
+        // === BOTH LEGS REQUIRED ===
+        // During staggered startup one adapter publishes before the other;
+        // never evaluate a spread until two exchanges have a fresh, two-sided book.
+        let fresh_legs = aggregated
+            .prices
+            .iter()
+            .filter(|p| {
+                p.bid > 0.0
+                    && p.ask > 0.0
+                    && now.saturating_sub(p.timestamp_ms) <= self.config.max_price_age_ms
+            })
+            .count();
+        if fresh_legs < 2 {
             self.pending.remove(symbol.as_ref());
+            self.log_awaiting_leg(symbol, fresh_legs, now);
             return None;
         }
+        self.awaiting_leg_logged.remove(symbol.as_ref());
 
         let best_bid = aggregated.best_bid.as_ref()?;
         let best_ask = aggregated.best_ask.as_ref()?;
 
+        // Feed every tick (crossed or not) so the smoothed spread tracks the market
+        let gate_spread = self.smoothed_spread(symbol, best_bid.price, best_ask.price);
+
         // Best bid must exceed best ask (cross-exchange spread)
         if best_bid.price <= best_ask.price {
             self.pending.remove(symbol.as_ref());
@@ -121,6 +234,22 @@ impl ArbitrageDetector {
             return None;
         }
 
+        // === QUOTE SKEW CHECK ===
+        // Both legs can be individually fresh yet quoted far apart in time.
+        // Exchange stamps when both legs carry one (see
+        // `AggregatedPrice::refresh_quote_time`), receive times otherwise
+        let quote_skew_ms = match (bid_price.exchange_time_ms, ask_price.exchange_time_ms) {
+            (Some(bid_ts), Some(ask_ts)) => bid_ts.abs_diff(ask_ts),
+            _ => bid_price.timestamp_ms.abs_diff(ask_price.timestamp_ms),
+        };
+        if let Some(max_skew_ms) = self.config.max_quote_skew_ms {
+            if quote_skew_ms > max_skew_ms {
+                self.pending.remove(symbol.as_ref());
+                self.log_quote_skew(symbol, quote_skew_ms, max_skew_ms, now);
+                return None;
+            }
+        }
+
         // === SANITY CHECK ===
         if spread_percent > self.config.max_realistic_spread {
             warn!(
@@ -133,8 +262,13 @@ impl ArbitrageDetector {
             return None;
         }
 
-        // === MINIMUM THRESHOLD ===
-        if spread_percent < self.config.min_spread_percent {
+        // === MINIMUM THRESHOLD (fee-adjusted) ===
+        // EMA mode gates on the smoothed spread; the raw spread is still reported
+        let gate_spread = gate_spread.unwrap_or(spread_percent);
+        let entry_threshold_percent = self
+            .config
+            .entry_threshold_percent(&best_ask.exchange, &best_bid.exchange);
+        if gate_spread < entry_threshold_percent {
             self.pending.remove(symbol.as_ref());
             return None;
         }
@@ -147,51 +281,191 @@ impl ArbitrageDetector {
         }
 
         // === CONFIRMATION LOGIC ===
-        let confirmed = if let Some(pending) = self.pending.get_mut(symbol.as_ref()) {
-            let same_pair = pending.buy_exchange == best_ask.exchange
-                && pending.sell_exchange == best_bid.exchange;
-
-            if same_pair {
+        // Any tick below threshold removed the pending entry above, so both
+        // the tick count and the window restart after a dip.
+        let pending = match self.pending.get_mut(symbol.as_ref()) {
+            Some(pending)
+                if pending.buy_exchange == best_ask.exchange
+                    && pending.sell_exchange == best_bid.exchange =>
+            {
                 pending.count += 1;
-                pending.count >= self.config.min_confirmations
-            } else {
-                // New pair — reset tracking
-                *pending = PendingArb {
-                    buy_exchange: best_ask.exchange.clone(),
-                    sell_exchange: best_bid.exchange.clone(),
-                    count: 1,
-                };
-                self.config.min_confirmations <= 1
+                pending
             }
-        } else {
-            self.pending.insert(
-                symbol.clone(),
-                PendingArb {
-                    buy_exchange: best_ask.exchange.clone(),
-                    sell_exchange: best_bid.exchange.clone(),
-                    count: 1,
-                },
-            );
-            self.config.min_confirmations <= 1
+            // New pair — reset tracking
+            _ => {
+                let fresh = PendingArb::new(best_ask.exchange.clone(), best_bid.exchange.clone());
+                self.pending.insert(symbol.clone(), fresh);
+                self.pending.get_mut(symbol.as_ref())?
+            }
+        };
+        let confirmed = match self.config.confirm_window_ms {
+            Some(window_ms) => pending.since.elapsed() >= Duration::from_millis(window_ms),
+            None => pending.count >= self.config.min_confirmations,
         };
 
         if !confirmed {
             return None;
         }
 
-        // Confirmed — emit opportunity and set cooldown
-        self.cooldowns.insert(symbol.clone(), now);
-        self.pending.remove(symbol.as_ref());
+        // === WARMUP ===
+        // Confirmation ran as usual so the EMA is primed, but the pending
+        // entry is dropped: a cross must be confirmed again once warmup ends
+        // rather than firing on ticks seen while books were still settling.
+        if let Some(warmup_ms) = self.config.warmup_ms {
+            let elapsed = self.started.elapsed();
+            if elapsed < Duration::from_millis(warmup_ms) {
+                self.pending.remove(symbol.as_ref());
+                self.log_warmup(symbol, spread_percent, warmup_ms, elapsed, now);
+                return None;
+            }
+        }
 
-        Some(ArbitrageOpportunity {
+        let mut opportunity = ArbitrageOpportunity {
             symbol: symbol.clone(),
             buy_exchange: best_ask.exchange.clone(),
             sell_exchange: best_bid.exchange.clone(),
             buy_price: best_ask.price,
             sell_price: best_bid.price,
             spread_percent,
+            entry_threshold_percent,
+            quote_skew_ms,
             timestamp_ms: now,
-        })
+            monitor_id: None,
+            fillable_quantity: None,
+            fill_spread_percent: None,
+            imbalance_buy: None,
+            imbalance_sell: None,
+            selected: false,
+        };
+
+        // === GATES ===
+        if let Err(reason) = gate(&mut opportunity) {
+            self.log_skip(&opportunity, &reason, now);
+            return None;
+        }
+
+        // Confirmed — emit opportunity and set cooldown
+        self.cooldowns.insert(symbol.clone(), now);
+        self.pending.remove(symbol.as_ref());
+        self.skip_logged.remove(symbol.as_ref());
+
+        Some(opportunity)
+    }
+
+    /// Update and return the smoothed spread for `symbol` (None when EMA mode is off)
+    fn smoothed_spread(&mut self, symbol: &Arc<str>, bid: f64, ask: f64) -> Option<f64> {
+        let alpha = self.config.ema_alpha?;
+        if ask <= 0.0 {
+            return None;
+        }
+        let raw = ((bid - ask) / ask) * 100.0;
+        let ema = match self.spread_emas.get_mut(symbol.as_ref()) {
+            Some(ema) => ema,
+            None => {
+                let ema = SpreadEma::new(alpha).ok()?;
+                self.spread_emas.entry(symbol.clone()).or_insert(ema)
+            }
+        };
+        Some(ema.update(raw))
+    }
+
+    /// Log `AWAITING_LEG` for a symbol, at most once per configured interval.
+    fn log_awaiting_leg(&mut self, symbol: &Arc<str>, fresh_legs: usize, now: u64) {
+        if let Some(&last) = self.awaiting_leg_logged.get(symbol.as_ref()) {
+            if now.saturating_sub(last) < self.config.awaiting_leg_log_interval_ms {
+                return;
+            }
+        }
+        info!(
+            event_type = "AWAITING_LEG",
+            symbol = symbol.as_ref(),
+            fresh_legs = fresh_legs,
+            "Waiting for both legs to have a fresh book"
+        );
+        self.awaiting_leg_logged.insert(symbol.clone(), now);
+    }
+
+    /// Log `QUOTE_SKEW_TOO_HIGH` for a symbol, at most once per log interval.
+    fn log_quote_skew(&mut self, symbol: &Arc<str>, skew_ms: u64, max_skew_ms: u64, now: u64) {
+        if let Some(&last) = self.quote_skew_logged.get(symbol.as_ref()) {
+            if now.saturating_sub(last) < self.config.awaiting_leg_log_interval_ms {
+                return;
+            }
+        }
+        warn!(
+            event_type = "QUOTE_SKEW_TOO_HIGH",
+            symbol = symbol.as_ref(),
+            skew_ms = skew_ms,
+            max_skew_ms = max_skew_ms,
+            "Suppressing opportunity: leg quotes too far apart"
+        );
+        self.quote_skew_logged.insert(symbol.clone(), now);
+    }
+
+    /// Log a `WARMUP` skip for a symbol, at most once per log interval.
+    fn log_warmup(&mut self, symbol: &Arc<str>, spread: f64, warmup_ms: u64, elapsed: Duration, now: u64) {
+        if let Some(&last) = self.warmup_logged.get(symbol.as_ref()) {
+            if now.saturating_sub(last) < self.config.awaiting_leg_log_interval_ms {
+                return;
+            }
+        }
+        info!(
+            event_type = "WARMUP",
+            symbol = symbol.as_ref(),
+            spread = format!("{:.4}%", spread),
+            remaining_ms = warmup_ms.saturating_sub(elapsed.as_millis() as u64),
+            "Skipping opportunity during warmup"
+        );
+        self.warmup_logged.insert(symbol.clone(), now);
+    }
+
+    /// Log a gate skip for a symbol, at most once per log interval.
+    fn log_skip(&mut self, opportunity: &ArbitrageOpportunity, reason: &SkipReason, now: u64) {
+        let symbol = &opportunity.symbol;
+        if let Some(&last) = self.skip_logged.get(symbol.as_ref()) {
+            if now.saturating_sub(last) < self.config.awaiting_leg_log_interval_ms {
+                return;
+            }
+        }
+        match reason {
+            SkipReason::LegUnhealthy { exchange } => warn!(
+                event_type = reason.event_type(),
+                symbol = symbol.as_ref(),
+                exchange = exchange.as_ref(),
+                "Leg feed down — opportunity skipped"
+            ),
+            SkipReason::InsufficientDepth { exchange } => warn!(
+                event_type = reason.event_type(),
+                symbol = symbol.as_ref(),
+                exchange = exchange.as_ref(),
+                "Leg book too thin — opportunity skipped"
+            ),
+            SkipReason::AdverseImbalance { exchange, imbalance } => warn!(
+                event_type = reason.event_type(),
+                symbol = symbol.as_ref(),
+                exchange = exchange.as_ref(),
+                imbalance = *imbalance,
+                "Leg book leans against the trade — opportunity skipped"
+            ),
+            SkipReason::PriceProtection { exchange, price, reference, deviation_pct } => warn!(
+                event_type = reason.event_type(),
+                symbol = symbol.as_ref(),
+                exchange = exchange.as_ref(),
+                price = *price,
+                reference = *reference,
+                deviation_pct = *deviation_pct,
+                "Leg outside protection band around its recent mid — opportunity skipped"
+            ),
+            SkipReason::SpreadBelowThresholdAtDepth { quantity, spread_percent, threshold_percent } => warn!(
+                event_type = reason.event_type(),
+                symbol = symbol.as_ref(),
+                quantity = *quantity,
+                spread_percent = *spread_percent,
+                threshold_percent = *threshold_percent,
+                "Spread below threshold at configured size — opportunity skipped"
+            ),
+        }
+        self.skip_logged.insert(symbol.clone(), now);
     }
 
     /// Clean up stale pending/cooldown entries.
@@ -208,9 +482,98 @@ impl Default for ArbitrageDetector {
     }
 }
 
+/// Detector scoped to one configured monitor (one symbol × two exchanges).
+///
+/// Each monitor keeps its own confirmation/cooldown state and thresholds,
+/// only sees its two exchanges, and tags opportunities with its id.
+pub struct MonitorDetector {
+    /// Monitor id (from config)
+    pub id: Arc<str>,
+    /// Normalized symbol watched (e.g. "BTC")
+    pub symbol: Arc<str>,
+    /// The two exchanges compared
+    pub exchanges: [Arc<str>; 2],
+    detector: ArbitrageDetector,
+}
+
+impl MonitorDetector {
+    /// Create a monitor-scoped detector.
+    pub fn new(
+        id: impl Into<Arc<str>>,
+        symbol: impl Into<Arc<str>>,
+        exchange_a: impl Into<Arc<str>>,
+        exchange_b: impl Into<Arc<str>>,
+        config: DetectorConfig,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            symbol: symbol.into(),
+            exchanges: [exchange_a.into(), exchange_b.into()],
+            detector: ArbitrageDetector::with_config(config),
+        }
+    }
+
+    /// Detector for a configured monitor, alerting at its `spread_entry`.
+    ///
+    /// Freshness/confirmation settings come from `base`.
+    pub fn from_config(monitor: &DashboardConfig, base: &DetectorConfig) -> Self {
+        Self::new(
+            monitor.id.as_str(),
+            monitor.pair.base(),
+            monitor.dex_a.to_string(),
+            monitor.dex_b.to_string(),
+            base.for_monitor(monitor),
+        )
+    }
+
+    /// Detector thresholds for this monitor.
+    pub fn config(&self) -> &DetectorConfig {
+        &self.detector.config
+    }
+
+    /// Swap in new thresholds (hot reload).
+    ///
+    /// Pending confirmations restart under the new thresholds; cooldowns
+    /// and the smoothed spread carry over.
+    pub fn set_config(&mut self, config: DetectorConfig) {
+        self.detector.config = config;
+        self.detector.pending.clear();
+    }
+
+    /// Detect an opportunity between this monitor's two exchanges.
+    ///
+    /// Returns `None` for other symbols.
+    pub fn detect(&mut self, aggregated: &AggregatedPrice) -> Option<ArbitrageOpportunity> {
+        self.detect_with(aggregated, |_| Ok(()))
+    }
+
+    /// Like [`Self::detect`], gated by `gate` before the cooldown starts.
+    pub fn detect_with(
+        &mut self,
+        aggregated: &AggregatedPrice,
+        gate: impl Fn(&mut ArbitrageOpportunity) -> Result<(), SkipReason>,
+    ) -> Option<ArbitrageOpportunity> {
+        if aggregated.symbol != self.symbol {
+            return None;
+        }
+        let scoped = aggregated
+            .clone()
+            .retain_exchanges(|e| self.exchanges.iter().any(|x| x.as_ref() == e));
+        let mut opportunity = self.detector.detect_with(&scoped, gate)?;
+        opportunity.monitor_id = Some(self.id.clone());
+        Some(opportunity)
+    }
+
+    /// Clean up stale cooldown entries.
+    pub fn cleanup(&mut self) {
+        self.detector.cleanup();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::AppConfig;
     use crate::core::types::{ExchangePrice, PriceData};
 
     fn make_aggregated(
@@ -226,6 +589,7 @@ mod tests {
                 bid: *bid,
                 ask: *ask,
                 timestamp_ms: now,
+                exchange_time_ms: None,
             })
             .collect();
 
@@ -251,6 +615,7 @@ mod tests {
             best_bid,
             best_ask,
             timestamp_ms: now,
+            last_update_ms: now,
         }
     }
 
@@ -288,6 +653,127 @@ mod tests {
         assert!(opp.spread_percent > 0.0);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_confirm_window_resets_on_dip() {
+        let mut detector = ArbitrageDetector::with_config(DetectorConfig {
+            min_spread_percent: 0.01,
+            min_confirmations: 1, // ignored: the window takes precedence
+            confirm_window_ms: Some(60),
+            cooldown_ms: 0,
+            ..Default::default()
+        });
+        let crossed = || make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50020.0, 50030.0),
+        ]);
+        let flat = || make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50005.0, 50015.0),
+        ]);
+
+        assert!(detector.detect(&crossed()).is_none());
+        tokio::time::advance(Duration::from_millis(40)).await;
+        assert!(detector.detect(&crossed()).is_none());
+
+        // Dip below threshold mid-window restarts the timer
+        assert!(detector.detect(&flat()).is_none());
+        assert!(detector.detect(&crossed()).is_none());
+        tokio::time::advance(Duration::from_millis(40)).await;
+        assert!(detector.detect(&crossed()).is_none(), "only 40ms since the dip");
+
+        tokio::time::advance(Duration::from_millis(40)).await;
+        assert!(detector.detect(&crossed()).is_some(), "held 80ms continuously");
+    }
+
+    #[test]
+    fn test_quote_skew_gate() {
+        let config = DetectorConfig {
+            min_spread_percent: 0.01,
+            min_confirmations: 1,
+            cooldown_ms: 0,
+            max_quote_skew_ms: Some(250),
+            ..Default::default()
+        };
+        let skewed = |skew_ms: u64| {
+            let mut agg = make_aggregated("BTC", vec![
+                ("vest", 50000.0, 50010.0),
+                ("paradex", 50020.0, 50030.0),
+            ]);
+            agg.prices[0].timestamp_ms -= skew_ms;
+            agg
+        };
+
+        let mut detector = ArbitrageDetector::with_config(config.clone());
+        let opp = detector.detect(&skewed(250)).expect("skew at the limit passes");
+        assert_eq!(opp.quote_skew_ms, 250);
+
+        let mut detector = ArbitrageDetector::with_config(config.clone());
+        assert!(detector.detect(&skewed(251)).is_none(), "skew over the limit is suppressed");
+
+        // Exchange clocks 10s behind ours: both legs received just now stay
+        // fresh, and skew compares the exchange stamps
+        let stamped = |skew_ms: u64| {
+            let mut agg = skewed(0);
+            let exchange_now = current_time_ms() - 10_000;
+            agg.prices[0].exchange_time_ms = Some(exchange_now - skew_ms);
+            agg.prices[1].exchange_time_ms = Some(exchange_now);
+            agg
+        };
+        let mut detector = ArbitrageDetector::with_config(config.clone());
+        assert_eq!(detector.detect(&stamped(100)).expect("lagging clock is fresh").quote_skew_ms, 100);
+        let mut detector = ArbitrageDetector::with_config(config);
+        assert!(detector.detect(&stamped(300)).is_none());
+    }
+
+    #[test]
+    fn test_gate_skip_does_not_start_cooldown() {
+        let mut detector = ArbitrageDetector::with_config(DetectorConfig {
+            min_spread_percent: 0.01,
+            min_confirmations: 1,
+            cooldown_ms: 60_000,
+            ..Default::default()
+        });
+        let agg = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50020.0, 50030.0),
+        ]);
+        let leg_down = |_: &mut ArbitrageOpportunity| {
+            Err(SkipReason::LegUnhealthy { exchange: Arc::from("paradex") })
+        };
+
+        assert!(detector.detect_with(&agg, leg_down).is_none());
+        assert!(detector.detect_with(&agg, leg_down).is_none());
+        assert!(!detector.cooldowns.contains_key("BTC"));
+        assert!(detector.skip_logged.contains_key("BTC"), "skip logged once, then rate limited");
+
+        // The leg recovers: the opportunity goes out on the next tick
+        assert!(detector.detect_with(&agg, |_| Ok(())).is_some());
+        assert!(detector.cooldowns.contains_key("BTC"));
+        assert!(!detector.skip_logged.contains_key("BTC"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_warmup_suppresses_early_opportunities() {
+        let mut detector = ArbitrageDetector::with_config(DetectorConfig {
+            min_spread_percent: 0.01,
+            min_confirmations: 1,
+            cooldown_ms: 0,
+            warmup_ms: Some(80),
+            ..Default::default()
+        });
+        let crossed = || make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50020.0, 50030.0),
+        ]);
+
+        assert!(detector.detect(&crossed()).is_none(), "still warming up");
+        tokio::time::advance(Duration::from_millis(79)).await;
+        assert!(detector.detect(&crossed()).is_none(), "1ms of warmup left");
+        assert!(!detector.pending.contains_key("BTC"), "warmup drops the pending cross");
+        tokio::time::advance(Duration::from_millis(1)).await;
+        assert!(detector.detect(&crossed()).is_some(), "warmup over");
+    }
+
     #[test]
     fn test_single_exchange_no_opportunity() {
         let mut detector = ArbitrageDetector::new();
@@ -295,6 +781,136 @@ mod tests {
         assert!(detector.detect(&agg).is_none());
     }
 
+    #[test]
+    fn test_one_leg_populated_awaits_second_leg() {
+        let mut detector = ArbitrageDetector::with_config(DetectorConfig {
+            min_spread_percent: 0.01,
+            min_confirmations: 1,
+            ..Default::default()
+        });
+
+        // Paradex book still empty (startup) — vest alone must not evaluate
+        let agg = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 0.0, 0.0),
+        ]);
+        assert!(detector.detect(&agg).is_none());
+        assert!(detector.awaiting_leg_logged.contains_key("BTC"));
+
+        // Stale second leg is treated as missing too
+        let mut agg = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50020.0, 50030.0),
+        ]);
+        agg.prices[1].timestamp_ms = 0;
+        assert!(detector.detect(&agg).is_none());
+
+        // Once both legs are fresh, evaluation proceeds
+        let agg = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50020.0, 50030.0),
+        ]);
+        assert!(detector.detect(&agg).is_some());
+        assert!(!detector.awaiting_leg_logged.contains_key("BTC"));
+    }
+
+    #[test]
+    fn test_monitor_detector_scopes_exchanges_and_tags_id() {
+        let config = DetectorConfig {
+            min_spread_percent: 0.01,
+            min_confirmations: 1,
+            ..Default::default()
+        };
+        let mut monitor = MonitorDetector::new("btc_vest_paradex", "BTC", "vest", "paradex", config);
+
+        // Lighter has the widest cross but is not part of this monitor
+        let agg = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50020.0, 50030.0),
+            ("lighter", 50100.0, 50110.0),
+        ]);
+        let opp = monitor.detect(&agg).expect("vest/paradex cross");
+        assert_eq!(opp.buy_exchange.as_ref(), "vest");
+        assert_eq!(opp.sell_exchange.as_ref(), "paradex");
+        assert_eq!(opp.monitor_id.as_deref(), Some("btc_vest_paradex"));
+
+        // Other symbols are ignored
+        let eth = make_aggregated("ETH", vec![
+            ("vest", 3000.0, 3001.0),
+            ("paradex", 3010.0, 3011.0),
+        ]);
+        assert!(monitor.detect(&eth).is_none());
+    }
+
+    #[test]
+    fn test_ema_mode_gates_on_smoothed_spread() {
+        let mut detector = ArbitrageDetector::with_config(DetectorConfig {
+            min_spread_percent: 0.02,
+            min_confirmations: 1,
+            cooldown_ms: 0,
+            ema_alpha: Some(0.3),
+            ..Default::default()
+        });
+
+        // Quiet, uncrossed market builds a negative average
+        let quiet = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50010.0),
+            ("paradex", 50000.0, 50010.0),
+        ]);
+        for _ in 0..10 {
+            assert!(detector.detect(&quiet).is_none());
+        }
+
+        // A single 0.04% cross flickers above the threshold but the EMA stays below
+        let flicker = make_aggregated("BTC", vec![
+            ("vest", 50000.0, 50000.0),
+            ("paradex", 50020.0, 50030.0),
+        ]);
+        assert!(detector.detect(&flicker).is_none());
+
+        // Sustained cross lifts the EMA; raw spread is what gets reported
+        let opp = (0..20)
+            .find_map(|_| detector.detect(&flicker))
+            .expect("sustained spread should pass the smoothed gate");
+        assert!((opp.spread_percent - 0.04).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_taker_fees_raise_entry_threshold() {
+        let config = DetectorConfig {
+            min_spread_percent: 0.02,
+            min_confirmations: 1,
+            cooldown_ms: 0,
+            taker_fee_bps: HashMap::from([(Arc::from("vest"), 2.0), (Arc::from("paradex"), 1.0)]),
+            ..Default::default()
+        };
+        // 0.02% + (2 + 1) bps = 0.05%
+        assert!((config.entry_threshold_percent("vest", "paradex") - 0.05).abs() < 1e-12);
+        assert!((config.entry_threshold_percent("vest", "lighter") - 0.04).abs() < 1e-12);
+        let mut detector = ArbitrageDetector::with_config(config);
+
+        // Buy vest @ 50000, sell paradex: 0.048% is below breakeven
+        let below = make_aggregated("BTC", vec![
+            ("vest", 49990.0, 50000.0),
+            ("paradex", 50024.0, 50030.0),
+        ]);
+        assert!(detector.detect(&below).is_none());
+
+        // 0.052% clears it; the threshold travels with the opportunity
+        let above = make_aggregated("BTC", vec![
+            ("vest", 49990.0, 50000.0),
+            ("paradex", 50026.0, 50030.0),
+        ]);
+        let opp = detector.detect(&above).expect("spread above fee-adjusted threshold");
+        assert!((opp.entry_threshold_percent - 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unset_fees_leave_threshold_unchanged() {
+        let config = DetectorConfig::default();
+        assert_eq!(config.entry_threshold_percent("vest", "paradex"), config.min_spread_percent);
+    }
+
     #[test]
     fn test_unrealistic_spread_rejected() {
         let mut detector = ArbitrageDetector::with_config(DetectorConfig {
@@ -308,4 +924,51 @@ mod tests {
         ]);
         assert!(detector.detect(&agg).is_none());
     }
+
+    #[test]
+    fn test_three_monitors_get_independent_detectors() {
+        let yaml = r#"
+monitors:
+  - id: btc_vest_paradex
+    pair: BTC-PERP
+    dex_a: vest
+    dex_b: paradex
+    spread_entry: 0.30
+  - id: eth_vest_lighter
+    pair: ETH-PERP
+    dex_a: vest
+    dex_b: lighter
+    spread_entry: 0.15
+    entry_confirm_ms: 750
+  - id: sol_paradex_lighter
+    pair: SOL-PERP
+    dex_a: paradex
+    dex_b: lighter
+    spread_entry: 0.50
+"#;
+        let config: AppConfig = serde_yaml::from_str(yaml).unwrap();
+        assert!(config.validate().is_ok());
+
+        let base = DetectorConfig::default();
+        let monitors: Vec<MonitorDetector> =
+            config.bots.iter().map(|bot| MonitorDetector::from_config(bot, &base)).collect();
+        assert_eq!(monitors.len(), 3);
+
+        let summary: Vec<(&str, &str, f64)> = monitors
+            .iter()
+            .map(|m| (m.id.as_ref(), m.symbol.as_ref(), m.config().min_spread_percent))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("btc_vest_paradex", "BTC", 0.30),
+                ("eth_vest_lighter", "ETH", 0.15),
+                ("sol_paradex_lighter", "SOL", 0.50),
+            ]
+        );
+        assert_eq!(monitors[1].exchanges[1].as_ref(), "lighter");
+        assert_eq!(monitors[1].config().confirm_window_ms, Some(750));
+        assert_eq!(monitors[0].config().confirm_window_ms, None);
+        assert_eq!(monitors[2].config().max_price_age_ms, base.max_price_age_ms);
+    }
 }
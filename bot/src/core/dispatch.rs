@@ -0,0 +1,104 @@
+//! Cross-pair opportunity selection.
+//!
+//! Each price update re-evaluates a single symbol, so opportunities on
+//! different pairs never meet in one detection batch. The dispatcher collects
+//! them over a short window and marks the best-scoring one
+//! [`ArbitrageOpportunity::selected`]; the rest still go out, unselected, so
+//! clients see every live edge but know which one to act on.
+
+use std::time::Duration;
+
+use crate::core::types::{rank_opportunities, ArbitrageOpportunity};
+
+/// Picks the top-scoring opportunity per dispatch window, across symbols
+#[derive(Debug, Default)]
+pub struct OpportunityDispatcher {
+    window: Option<Duration>,
+    pending: Vec<ArbitrageOpportunity>,
+}
+
+impl OpportunityDispatcher {
+    /// Collect over `window` before selecting; `None` selects within each
+    /// detection batch as it arrives
+    pub fn new(window: Option<Duration>) -> Self {
+        Self { window, pending: Vec::new() }
+    }
+
+    /// How often [`Self::dispatch`] must be called, `None` when batches are
+    /// dispatched as they are offered
+    pub fn window(&self) -> Option<Duration> {
+        self.window
+    }
+
+    /// Queue one detection batch. Without a window it is dispatched at once;
+    /// otherwise nothing comes out until the next [`Self::dispatch`].
+    pub fn offer(&mut self, batch: Vec<ArbitrageOpportunity>) -> Vec<ArbitrageOpportunity> {
+        self.pending.extend(batch);
+        if self.window.is_some() {
+            return Vec::new();
+        }
+        self.dispatch()
+    }
+
+    /// Everything queued since the last dispatch, best first, with the best
+    /// marked selected
+    pub fn dispatch(&mut self) -> Vec<ArbitrageOpportunity> {
+        let mut opportunities = std::mem::take(&mut self.pending);
+        rank_opportunities(&mut opportunities);
+        if let Some(best) = opportunities.first_mut() {
+            best.selected = true;
+        }
+        opportunities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    fn opportunity(symbol: &str, spread: f64, quantity: f64) -> ArbitrageOpportunity {
+        ArbitrageOpportunity {
+            symbol: Arc::from(symbol),
+            buy_exchange: Arc::from("vest"),
+            sell_exchange: Arc::from("paradex"),
+            buy_price: 100.0,
+            sell_price: 100.0 + spread,
+            spread_percent: spread,
+            entry_threshold_percent: 0.1,
+            quote_skew_ms: 0,
+            timestamp_ms: 0,
+            monitor_id: None,
+            fillable_quantity: Some(quantity),
+            fill_spread_percent: Some(spread),
+            imbalance_buy: None,
+            imbalance_sell: None,
+            selected: false,
+        }
+    }
+
+    #[test]
+    fn test_window_selects_best_across_pairs() {
+        let mut dispatcher = OpportunityDispatcher::new(Some(Duration::from_millis(100)));
+
+        // Separate price updates, one symbol each
+        assert!(dispatcher.offer(vec![opportunity("BTC", 0.5, 1.0)]).is_empty());
+        assert!(dispatcher.offer(vec![opportunity("ETH", 0.2, 20.0)]).is_empty());
+        assert!(dispatcher.offer(vec![opportunity("SOL", 0.3, 2.0)]).is_empty());
+
+        // ETH's thin edge over 20 units beats BTC's wide one over 1
+        let dispatched = dispatcher.dispatch();
+        let order: Vec<(&str, bool)> = dispatched.iter().map(|o| (o.symbol.as_ref(), o.selected)).collect();
+        assert_eq!(order, vec![("ETH", true), ("BTC", false), ("SOL", false)]);
+        assert!(dispatcher.dispatch().is_empty(), "window starts over");
+    }
+
+    #[test]
+    fn test_without_window_batches_go_out_at_once() {
+        let mut dispatcher = OpportunityDispatcher::new(None);
+        let dispatched = dispatcher.offer(vec![opportunity("BTC", 0.3, 1.0), opportunity("BTC", 0.5, 1.0)]);
+        assert_eq!(dispatched.len(), 2);
+        assert!(dispatched[0].selected && dispatched[0].spread_percent == 0.5);
+        assert!(!dispatched[1].selected);
+    }
+}
@@ -0,0 +1,218 @@
+//! JSONL file sink for broadcast events.
+//!
+//! Appends [`SequencedEvent`]s (or any other serializable record, see
+//! [`FileEventSink::write_record`]) to a file as one JSON object per line
+//! for post-hoc analysis. Each line is serialized before taking the writer
+//! lock and written with a single `write_all`, so concurrent writers never
+//! interleave partial lines. [`EventLogFilter`] keeps price ticks from
+//! growing the log without bound.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::Mutex;
+
+use crate::core::types::{BroadcastEvent, SequencedEvent};
+
+/// Flush after this many buffered lines...
+const FLUSH_EVERY_LINES: usize = 64;
+/// ...or once this much time has passed since the last flush.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+struct SinkState {
+    writer: BufWriter<File>,
+    unflushed_lines: usize,
+    last_flush: Instant,
+}
+
+/// Append-only JSONL writer for [`SequencedEvent`]s.
+pub struct FileEventSink {
+    state: Mutex<SinkState>,
+}
+
+impl FileEventSink {
+    /// Open (or create) `path` for appending.
+    pub async fn new(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+        Ok(Self {
+            state: Mutex::new(SinkState {
+                writer: BufWriter::new(file),
+                unflushed_lines: 0,
+                last_flush: Instant::now(),
+            }),
+        })
+    }
+
+    /// Append one event as a JSON line, flushing periodically.
+    pub async fn write(&self, event: &SequencedEvent) -> std::io::Result<()> {
+        self.write_record(event).await
+    }
+
+    /// Append any serializable record as a JSON line, flushing periodically.
+    pub async fn write_record<T: Serialize>(&self, record: &T) -> std::io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+
+        let mut state = self.state.lock().await;
+        state.writer.write_all(&line).await?;
+        state.unflushed_lines += 1;
+
+        if state.unflushed_lines >= FLUSH_EVERY_LINES || state.last_flush.elapsed() >= FLUSH_INTERVAL {
+            Self::flush_locked(&mut state).await?;
+        }
+        Ok(())
+    }
+
+    /// Flush any buffered lines to disk.
+    pub async fn flush(&self) -> std::io::Result<()> {
+        let mut state = self.state.lock().await;
+        Self::flush_locked(&mut state).await
+    }
+
+    async fn flush_locked(state: &mut SinkState) -> std::io::Result<()> {
+        state.writer.flush().await?;
+        state.unflushed_lines = 0;
+        state.last_flush = Instant::now();
+        Ok(())
+    }
+}
+
+/// Which broadcast events go to the event log.
+///
+/// Opportunities and exchange status changes are always kept. Price ticks
+/// arrive for every top-of-book change on every exchange, so they are
+/// dropped, or sampled to one per exchange and symbol per `price_interval_ms`
+/// of exchange time.
+#[derive(Debug, Default)]
+pub struct EventLogFilter {
+    price_interval_ms: Option<u64>,
+    last_price_ms: HashMap<(Arc<str>, Arc<str>), u64>,
+}
+
+impl EventLogFilter {
+    pub fn new(price_interval_ms: Option<u64>) -> Self {
+        Self { price_interval_ms, last_price_ms: HashMap::new() }
+    }
+
+    /// Whether `event` should be written
+    pub fn admits(&mut self, event: &SequencedEvent) -> bool {
+        let BroadcastEvent::Price(price) = &event.event else {
+            return true;
+        };
+        let Some(interval_ms) = self.price_interval_ms else {
+            return false;
+        };
+        let key = (price.exchange.clone(), price.symbol.clone());
+        match self.last_price_ms.get(&key) {
+            Some(&last) if price.timestamp_ms.saturating_sub(last) < interval_ms => false,
+            _ => {
+                self.last_price_ms.insert(key, price.timestamp_ms);
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::PriceData;
+
+    fn price_event(exchange: &str, bid: f64) -> SequencedEvent {
+        price_event_at(exchange, bid, 1700000000000)
+    }
+
+    fn price_event_at(exchange: &str, bid: f64, timestamp_ms: u64) -> SequencedEvent {
+        SequencedEvent::new(BroadcastEvent::Price(PriceData {
+            exchange: Arc::from(exchange),
+            symbol: Arc::from("BTC"),
+            bid,
+            ask: bid + 10.0,
+            timestamp_ms,
+            exchange_time_ms: None,
+        }))
+    }
+
+    #[test]
+    fn test_filter_keeps_state_events_and_samples_prices() {
+        let status = SequencedEvent::new(BroadcastEvent::ExchangeStatus {
+            exchange: "vest".to_string(),
+            connected: false,
+        });
+
+        let mut off = EventLogFilter::new(None);
+        assert!(off.admits(&status));
+        assert!(!off.admits(&price_event("vest", 50_000.0)), "ticks left out by default");
+
+        let mut sampled = EventLogFilter::new(Some(1_000));
+        assert!(sampled.admits(&price_event_at("vest", 50_000.0, 10_000)));
+        assert!(!sampled.admits(&price_event_at("vest", 50_001.0, 10_999)));
+        assert!(sampled.admits(&price_event_at("paradex", 50_002.0, 10_999)), "sampled per exchange");
+        assert!(sampled.admits(&price_event_at("vest", 50_003.0, 11_000)));
+        assert!(sampled.admits(&status));
+
+        let mut every = EventLogFilter::new(Some(0));
+        assert!(every.admits(&price_event_at("vest", 50_000.0, 10_000)));
+        assert!(every.admits(&price_event_at("vest", 50_000.0, 10_000)));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_writes_produce_valid_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let sink = Arc::new(FileEventSink::new(&path).await.unwrap());
+
+        let writers: Vec<_> = ["vest", "paradex", "lighter"]
+            .iter()
+            .enumerate()
+            .map(|(i, exchange)| {
+                let sink = sink.clone();
+                let event = price_event(exchange, 50_000.0 + i as f64);
+                tokio::spawn(async move { sink.write(&event).await.unwrap() })
+            })
+            .collect();
+        for writer in writers {
+            writer.await.unwrap();
+        }
+        sink.flush().await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+
+        let mut exchanges: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                assert_eq!(value["type"], "price");
+                value["data"]["exchange"].as_str().unwrap().to_string()
+            })
+            .collect();
+        exchanges.sort();
+        assert_eq!(exchanges, vec!["lighter", "paradex", "vest"]);
+    }
+
+    #[tokio::test]
+    async fn test_sink_appends_to_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        std::fs::write(&path, "{\"existing\":true}\n").unwrap();
+
+        let sink = FileEventSink::new(&path).await.unwrap();
+        sink.write(&price_event("vest", 50_000.0)).await.unwrap();
+        sink.flush().await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 2);
+        assert!(contents.starts_with("{\"existing\":true}"));
+    }
+}
@@ -0,0 +1,494 @@
+//! Pre-emission gates for arbitrage opportunities.
+//!
+//! The detector confirms a cross from prices alone; these gates look at the
+//! legs behind it (feed health, book depth, spread at size, price sanity). The detector runs
+//! them before starting the symbol's cooldown, so a skipped opportunity does
+//! not hold back the next real one.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::adapters::types::{DepthRequirement, Orderbook};
+use crate::core::spread::{book_imbalance, max_size_within_slippage, vwap_fill_price, SpreadEma, IMBALANCE_LEVELS};
+use crate::core::types::{ArbitrageOpportunity, PriceBand, PriceData};
+
+/// Why a confirmed opportunity was not emitted
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipReason {
+    /// The leg's feed is reconnecting or stale, so its quote is old
+    LegUnhealthy { exchange: Arc<str> },
+    /// The leg's book is thinner than the configured depth, or cannot fill
+    /// the configured `depth_quantity`
+    InsufficientDepth { exchange: Arc<str> },
+    /// Priced at the VWAP of filling `quantity` (buy leg's asks, sell leg's
+    /// bids), the spread no longer reaches the entry threshold
+    SpreadBelowThresholdAtDepth {
+        quantity: f64,
+        spread_percent: f64,
+        threshold_percent: f64,
+    },
+    /// A leg's book leans against the trade by more than the configured
+    /// limit: asks outweigh bids where we buy, or bids outweigh asks where
+    /// we sell, so the price is likely to move against the fill
+    AdverseImbalance { exchange: Arc<str>, imbalance: f64 },
+    /// A leg's price sits outside the protection band around that leg's own
+    /// recent mid (`reference`): a jump that wide is more likely a corrupted
+    /// or stale book than a real edge
+    PriceProtection {
+        exchange: Arc<str>,
+        price: f64,
+        reference: f64,
+        deviation_pct: f64,
+    },
+}
+
+impl SkipReason {
+    /// `event_type` the skip is logged under
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            SkipReason::LegUnhealthy { .. } => "LEG_UNHEALTHY",
+            SkipReason::InsufficientDepth { .. } => "INSUFFICIENT_DEPTH",
+            SkipReason::SpreadBelowThresholdAtDepth { .. } => "SPREAD_BELOW_THRESHOLD_AT_DEPTH",
+            SkipReason::AdverseImbalance { .. } => "ADVERSE_IMBALANCE",
+            SkipReason::PriceProtection { .. } => "PRICE_PROTECTION_TRIGGERED",
+        }
+    }
+
+    /// Exchange of the leg that failed the gate, `None` when the skip is
+    /// about both legs together
+    pub fn exchange(&self) -> Option<&str> {
+        match self {
+            SkipReason::LegUnhealthy { exchange }
+            | SkipReason::InsufficientDepth { exchange }
+            | SkipReason::AdverseImbalance { exchange, .. }
+            | SkipReason::PriceProtection { exchange, .. } => Some(exchange),
+            SkipReason::SpreadBelowThresholdAtDepth { .. } => None,
+        }
+    }
+}
+
+/// One leg's feed as seen when the opportunity is gated
+#[derive(Debug, Clone, Default)]
+pub struct LegState {
+    /// Adapter connected and the leg's subscription in place
+    pub healthy: bool,
+    /// Current book, when depth gating needs it
+    pub book: Option<Orderbook>,
+    /// The leg's recent mid (see [`RecentMids`]), the price band's reference
+    pub reference_mid: Option<f64>,
+}
+
+/// Leg states keyed by exchange; a missing exchange counts as unhealthy
+pub type LegStates = HashMap<Arc<str>, LegState>;
+
+/// Slow moving average of each exchange's mid per symbol
+///
+/// The price band needs a reference the checked quote does not move: the
+/// other leg is no good, since the gap between the legs is the spread being
+/// judged. Each leg is instead held against where that same exchange has
+/// been trading, as of before its latest quote.
+#[derive(Debug, Default)]
+pub struct RecentMids {
+    mids: HashMap<(Arc<str>, Arc<str>), MidHistory>,
+}
+
+#[derive(Debug)]
+struct MidHistory {
+    ema: SpreadEma,
+    /// The average as it stood before the latest quote
+    before: Option<f64>,
+}
+
+impl RecentMids {
+    /// Weight of each new quote; ~20 quotes to follow a genuine move
+    const ALPHA: f64 = 0.05;
+
+    /// Fold `price`'s mid into its exchange's average
+    pub fn observe(&mut self, price: &PriceData) {
+        if price.bid <= 0.0 || price.ask <= 0.0 {
+            return;
+        }
+        let key = (price.exchange.clone(), price.symbol.clone());
+        let history = self.mids.entry(key).or_insert_with(|| MidHistory {
+            ema: SpreadEma::new(Self::ALPHA).expect("valid alpha"),
+            before: None,
+        });
+        history.before = history.ema.value();
+        history.ema.update((price.bid + price.ask) / 2.0);
+    }
+
+    /// `exchange`'s average `symbol` mid before its latest quote, `None`
+    /// until it has quoted twice
+    pub fn reference(&self, exchange: &Arc<str>, symbol: &Arc<str>) -> Option<f64> {
+        self.mids.get(&(exchange.clone(), symbol.clone())).and_then(|history| history.before)
+    }
+}
+
+/// Depth and price-band checks applied to every confirmed opportunity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OpportunityGate {
+    pub depth: Option<DepthRequirement>,
+    pub band: Option<PriceBand>,
+    /// Trade size (base units) the spread must hold at, see
+    /// [`SkipReason::SpreadBelowThresholdAtDepth`]
+    pub depth_quantity: Option<f64>,
+    /// Slippage budget (percent of each leg's best price) the opportunity's
+    /// size is clamped to, see [`max_size_within_slippage`]
+    pub max_slippage_pct: Option<f64>,
+    /// Largest imbalance (0..=1) either leg's book may lean against the
+    /// trade, see [`SkipReason::AdverseImbalance`]
+    pub max_adverse_imbalance: Option<f64>,
+}
+
+impl OpportunityGate {
+    pub fn new(depth: Option<DepthRequirement>, band: Option<PriceBand>) -> Self {
+        Self { depth, band, depth_quantity: None, max_slippage_pct: None, max_adverse_imbalance: None }
+    }
+
+    /// Also require the spread to reach the entry threshold when both legs
+    /// are priced at the VWAP of filling `quantity`
+    pub fn with_depth_quantity(mut self, quantity: Option<f64>) -> Self {
+        self.depth_quantity = quantity;
+        self
+    }
+
+    /// Clamp the opportunity's size to what both legs fill with a VWAP
+    /// within `max_slippage_pct` of their best price
+    pub fn with_max_slippage(mut self, max_slippage_pct: Option<f64>) -> Self {
+        self.max_slippage_pct = max_slippage_pct;
+        self
+    }
+
+    /// Skip opportunities whose legs' books lean against the trade by more
+    /// than `max_imbalance`
+    pub fn with_max_adverse_imbalance(mut self, max_imbalance: Option<f64>) -> Self {
+        self.max_adverse_imbalance = max_imbalance;
+        self
+    }
+
+    /// Whether [`LegState::book`] must be filled in for [`Self::check`]
+    pub fn needs_books(&self) -> bool {
+        self.depth.is_some()
+            || self.depth_quantity.is_some()
+            || self.max_slippage_pct.is_some()
+            || self.max_adverse_imbalance.is_some()
+    }
+
+    /// First gate `opportunity` fails, checked in order: health, depth and
+    /// spread at `depth_quantity` (clamped to the slippage budget), book
+    /// imbalance, then price band.
+    ///
+    /// When both legs' books are loaded, also sizes `opportunity` (see
+    /// [`ArbitrageOpportunity::fillable_quantity`]) so it can be scored, and
+    /// records each leg's book imbalance.
+    pub fn check(&self, opportunity: &mut ArbitrageOpportunity, legs: &LegStates) -> Result<(), SkipReason> {
+        let sides = [
+            (&opportunity.buy_exchange, opportunity.buy_price),
+            (&opportunity.sell_exchange, opportunity.sell_price),
+        ];
+        for (exchange, _) in sides {
+            if !legs.get(exchange).is_some_and(|leg| leg.healthy) {
+                return Err(SkipReason::LegUnhealthy { exchange: exchange.clone() });
+            }
+        }
+        if let Some(requirement) = self.depth {
+            for (exchange, _) in sides {
+                let book = legs.get(exchange).and_then(|leg| leg.book.as_ref());
+                if !book.is_some_and(|book| requirement.is_met_by(book)) {
+                    return Err(SkipReason::InsufficientDepth { exchange: exchange.clone() });
+                }
+            }
+        }
+        let book = |exchange: &Arc<str>| legs.get(exchange).and_then(|leg| leg.book.as_ref());
+        let (buy_book, sell_book) = (book(&opportunity.buy_exchange), book(&opportunity.sell_exchange));
+        // The binding leg caps the size both legs can trade within budget
+        let within_budget = match (self.max_slippage_pct, buy_book, sell_book) {
+            (Some(budget), Some(buy), Some(sell)) => Some(max_size_within_slippage(buy, sell, budget)),
+            _ => None,
+        };
+        if let Some(target) = self.depth_quantity {
+            let quantity = within_budget.map_or(target, |max| target.min(max));
+            // Buying lifts the buy leg's asks, selling hits the sell leg's bids
+            let buy = buy_book.and_then(|b| vwap_fill_price(&b.asks, quantity));
+            let Some(buy) = buy else {
+                return Err(SkipReason::InsufficientDepth { exchange: opportunity.buy_exchange.clone() });
+            };
+            let sell = sell_book.and_then(|b| vwap_fill_price(&b.bids, quantity));
+            let Some(sell) = sell else {
+                return Err(SkipReason::InsufficientDepth { exchange: opportunity.sell_exchange.clone() });
+            };
+            let spread_percent = (sell - buy) / buy * 100.0;
+            if spread_percent < opportunity.entry_threshold_percent {
+                return Err(SkipReason::SpreadBelowThresholdAtDepth {
+                    quantity,
+                    spread_percent,
+                    threshold_percent: opportunity.entry_threshold_percent,
+                });
+            }
+            opportunity.fillable_quantity = Some(quantity);
+            opportunity.fill_spread_percent = Some(spread_percent);
+        } else if let (Some(buy), Some(sell)) = (buy_book, sell_book) {
+            // Without a target size, what both legs fill within the budget,
+            // or else at the top of book
+            let top = buy.asks.first().zip(sell.bids.first()).map(|(ask, bid)| ask.quantity.min(bid.quantity));
+            let quantity = within_budget.or(top).filter(|&quantity| quantity > 0.0);
+            let fill = quantity.and_then(|quantity| {
+                let buy = vwap_fill_price(&buy.asks, quantity)?;
+                let sell = vwap_fill_price(&sell.bids, quantity)?;
+                Some((quantity, (sell - buy) / buy * 100.0))
+            });
+            if let Some((quantity, spread_percent)) = fill {
+                opportunity.fillable_quantity = Some(quantity);
+                opportunity.fill_spread_percent = Some(spread_percent);
+            }
+        }
+        if let (Some(buy), Some(sell)) = (buy_book, sell_book) {
+            opportunity.imbalance_buy = Some(book_imbalance(buy, IMBALANCE_LEVELS));
+            opportunity.imbalance_sell = Some(book_imbalance(sell, IMBALANCE_LEVELS));
+        }
+        if let (Some(max), Some(buy), Some(sell)) =
+            (self.max_adverse_imbalance, opportunity.imbalance_buy, opportunity.imbalance_sell)
+        {
+            // Ask-heavy where we buy or bid-heavy where we sell: the price
+            // leans toward moving against that leg once filled
+            let leans = [(&opportunity.buy_exchange, buy, -buy), (&opportunity.sell_exchange, sell, sell)];
+            for (exchange, imbalance, against) in leans {
+                if against > max {
+                    return Err(SkipReason::AdverseImbalance { exchange: exchange.clone(), imbalance });
+                }
+            }
+        }
+
+        // Each leg against its own recent mid; a leg without one yet passes
+        if let Some(band) = self.band {
+            let quotes = [
+                (&opportunity.buy_exchange, opportunity.buy_price),
+                (&opportunity.sell_exchange, opportunity.sell_price),
+            ];
+            for (exchange, price) in quotes {
+                let Some(reference) = legs.get(exchange).and_then(|leg| leg.reference_mid) else {
+                    continue;
+                };
+                if !band.admits(price, reference) {
+                    return Err(SkipReason::PriceProtection {
+                        exchange: exchange.clone(),
+                        price,
+                        reference,
+                        deviation_pct: PriceBand::deviation_pct(price, reference),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opportunity(buy_price: f64, sell_price: f64) -> ArbitrageOpportunity {
+        ArbitrageOpportunity {
+            symbol: Arc::from("BTC"),
+            buy_exchange: Arc::from("vest"),
+            sell_exchange: Arc::from("paradex"),
+            buy_price,
+            sell_price,
+            spread_percent: (sell_price - buy_price) / buy_price * 100.0,
+            entry_threshold_percent: 0.0,
+            quote_skew_ms: 0,
+            timestamp_ms: 0,
+            monitor_id: None,
+            fillable_quantity: None,
+            fill_spread_percent: None,
+            imbalance_buy: None,
+            imbalance_sell: None,
+            selected: false,
+        }
+    }
+
+    fn leg(book_levels: usize) -> LegState {
+        let side = vec![(100.0, 1.0); book_levels];
+        LegState { healthy: true, book: Some(Orderbook::from_levels(&side, &side)), reference_mid: None }
+    }
+
+    fn legs(vest: LegState, paradex: LegState) -> LegStates {
+        HashMap::from([(Arc::from("vest"), vest), (Arc::from("paradex"), paradex)])
+    }
+
+    #[test]
+    fn test_gate_passes_healthy_deep_in_band_legs() {
+        let gate = OpportunityGate::new(
+            Some(DepthRequirement { min_levels: 3, min_notional: 0.0 }),
+            Some(PriceBand { max_deviation_pct: 2.0 }),
+        );
+        let mut opp = opportunity(100.0, 100.5);
+        assert_eq!(gate.check(&mut opp, &legs(leg(3), leg(3))), Ok(()));
+        // Sized at the smaller top-of-book quantity
+        assert_eq!(opp.fillable_quantity, Some(1.0));
+        assert_eq!(opp.fill_spread_percent, Some(0.0));
+        assert_eq!((opp.imbalance_buy, opp.imbalance_sell), (Some(0.0), Some(0.0)));
+
+        // No books loaded: left unsized
+        let mut opp = opportunity(100.0, 100.5);
+        let no_books = LegState { healthy: true, ..LegState::default() };
+        assert_eq!(OpportunityGate::default().check(&mut opp, &legs(no_books.clone(), no_books)), Ok(()));
+        assert_eq!(opp.fillable_quantity, None);
+    }
+
+    #[test]
+    fn test_gate_skips_unhealthy_leg() {
+        let gate = OpportunityGate::default();
+        let down = LegState { healthy: false, ..leg(1) };
+        let skip = gate.check(&mut opportunity(100.0, 100.5), &legs(leg(1), down));
+        assert_eq!(skip, Err(SkipReason::LegUnhealthy { exchange: Arc::from("paradex") }));
+
+        // A leg nobody reported on is treated as down
+        let skip = gate.check(&mut opportunity(100.0, 100.5), &LegStates::new());
+        assert_eq!(skip.unwrap_err().event_type(), "LEG_UNHEALTHY");
+    }
+
+    #[test]
+    fn test_gate_skips_thin_or_missing_book() {
+        let gate = OpportunityGate::new(Some(DepthRequirement { min_levels: 3, min_notional: 0.0 }), None);
+        assert!(gate.needs_books());
+        let skip = gate.check(&mut opportunity(100.0, 100.5), &legs(leg(2), leg(3)));
+        assert_eq!(skip, Err(SkipReason::InsufficientDepth { exchange: Arc::from("vest") }));
+
+        let no_book = LegState { healthy: true, ..LegState::default() };
+        let skip = gate.check(&mut opportunity(100.0, 100.5), &legs(leg(3), no_book));
+        assert_eq!(skip.unwrap_err().exchange(), Some("paradex"));
+    }
+
+    #[test]
+    fn test_gate_skips_leg_outside_price_band() {
+        let gate = OpportunityGate::new(None, Some(PriceBand { max_deviation_pct: 2.0 }));
+        assert!(!gate.needs_books());
+        let around = |mid: f64| LegState { reference_mid: Some(mid), ..leg(1) };
+        // A 1.5% cross with each leg near where it has been trading
+        let mut opp = opportunity(100.0, 101.5);
+        assert_eq!(gate.check(&mut opp, &legs(around(100.2), around(101.0))), Ok(()));
+
+        // paradex suddenly quotes 10x off its own recent mid
+        let skip = gate.check(&mut opportunity(100.0, 999.0), &legs(around(100.2), around(101.0))).unwrap_err();
+        assert_eq!(skip.event_type(), "PRICE_PROTECTION_TRIGGERED");
+        assert_eq!(skip.exchange(), Some("paradex"));
+        assert!(matches!(skip, SkipReason::PriceProtection { reference, .. } if reference == 101.0));
+
+        // A narrow cross still trips when the buy leg jumped off market:
+        // the band does not depend on the other leg
+        let skip = gate.check(&mut opportunity(50.0, 50.2), &legs(around(100.2), around(50.1))).unwrap_err();
+        assert_eq!(skip.exchange(), Some("vest"));
+
+        // No history yet: nothing to hold the leg against
+        assert_eq!(gate.check(&mut opportunity(100.0, 999.0), &legs(leg(1), leg(1))), Ok(()));
+    }
+
+    #[test]
+    fn test_gate_skips_adverse_imbalance() {
+        let gate = OpportunityGate::default().with_max_adverse_imbalance(Some(0.5));
+        assert!(gate.needs_books());
+        let book = |bid_qty: f64, ask_qty: f64| LegState {
+            healthy: true,
+            book: Some(Orderbook::from_levels(&[(100.0, bid_qty)], &[(100.5, ask_qty)])),
+            reference_mid: None,
+        };
+        // Bid-heavy where we buy and ask-heavy where we sell lean our way
+        let mut opp = opportunity(100.0, 100.5);
+        assert_eq!(gate.check(&mut opp, &legs(book(9.0, 1.0), book(1.0, 9.0))), Ok(()));
+        assert!((opp.imbalance_buy.unwrap() - 0.8).abs() < 1e-12);
+        assert!((opp.imbalance_sell.unwrap() + 0.8).abs() < 1e-12);
+
+        // Asks outweigh bids 9:1 on the buy leg
+        let skip = gate.check(&mut opportunity(100.0, 100.5), &legs(book(1.0, 9.0), book(1.0, 1.0))).unwrap_err();
+        assert_eq!(skip.event_type(), "ADVERSE_IMBALANCE");
+        assert_eq!(skip.exchange(), Some("vest"));
+
+        // Bids outweigh asks on the sell leg, within the limit at 3:1
+        assert_eq!(gate.check(&mut opportunity(100.0, 100.5), &legs(book(1.0, 1.0), book(3.0, 1.0))), Ok(()));
+        let skip = gate.check(&mut opportunity(100.0, 100.5), &legs(book(1.0, 1.0), book(4.0, 1.0))).unwrap_err();
+        assert!(matches!(skip, SkipReason::AdverseImbalance { imbalance, .. } if (imbalance - 0.6).abs() < 1e-12));
+        assert_eq!(skip.exchange(), Some("paradex"));
+    }
+
+    #[test]
+    fn test_recent_mids_lag_the_latest_quote() {
+        let quote = |exchange: &str, mid: f64| PriceData {
+            exchange: Arc::from(exchange),
+            symbol: Arc::from("BTC"),
+            bid: mid - 0.5,
+            ask: mid + 0.5,
+            timestamp_ms: 0,
+            exchange_time_ms: None,
+        };
+        let (vest, btc) = (Arc::from("vest"), Arc::from("BTC"));
+        let mut mids = RecentMids::default();
+        mids.observe(&quote("vest", 100.0));
+        assert_eq!(mids.reference(&vest, &btc), None, "one quote is no history");
+
+        mids.observe(&quote("paradex", 500.0));
+        mids.observe(&quote("vest", 1_000.0));
+        // The jump is measured against vest's own history, not paradex
+        assert_eq!(mids.reference(&vest, &btc), Some(100.0));
+        mids.observe(&quote("vest", 100.0));
+        assert!((mids.reference(&vest, &btc).unwrap() - 145.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gate_prices_legs_at_depth_quantity() {
+        let gate = OpportunityGate::default().with_depth_quantity(Some(2.0));
+        assert!(gate.needs_books());
+        let book = |bids: &[(f64, f64)], asks: &[(f64, f64)]| LegState {
+            healthy: true,
+            book: Some(Orderbook::from_levels(bids, asks)),
+            reference_mid: None,
+        };
+        // Top of book crosses by 0.5%; 2 units walk vest's asks to 101.25
+        // (VWAP 100.625) while paradex's bids hold at 100.5
+        let vest = book(&[(99.0, 5.0)], &[(100.0, 1.0), (101.25, 1.0)]);
+        let paradex = book(&[(100.5, 5.0)], &[(101.0, 5.0)]);
+        let mut opp = opportunity(100.0, 100.5);
+        opp.entry_threshold_percent = 0.3;
+        let skip = gate.check(&mut opp, &legs(vest.clone(), paradex.clone())).unwrap_err();
+        assert_eq!(skip.event_type(), "SPREAD_BELOW_THRESHOLD_AT_DEPTH");
+        assert_eq!(skip.exchange(), None);
+
+        // Deep enough on both sides: the spread holds at size
+        let deep_vest = book(&[(99.0, 5.0)], &[(100.0, 5.0)]);
+        assert_eq!(gate.check(&mut opp, &legs(deep_vest, paradex.clone())), Ok(()));
+        assert_eq!(opp.fillable_quantity, Some(2.0));
+        assert!((opp.fill_spread_percent.unwrap() - 0.5).abs() < 1e-9);
+
+        // The sell leg's bids can't absorb the size at all
+        let thin_paradex = book(&[(100.5, 1.0)], &[(101.0, 5.0)]);
+        let skip = gate.check(&mut opp, &legs(vest, thin_paradex)).unwrap_err();
+        assert_eq!(skip, SkipReason::InsufficientDepth { exchange: Arc::from("paradex") });
+    }
+
+    #[test]
+    fn test_gate_clamps_size_to_slippage_budget() {
+        let book = |bids: &[(f64, f64)], asks: &[(f64, f64)]| LegState {
+            healthy: true,
+            book: Some(Orderbook::from_levels(bids, asks)),
+            reference_mid: None,
+        };
+        // Within 0.5%, vest's asks fill 1 + 1/3 units (VWAP 100.5); paradex's
+        // bids fill all 5, so vest binds
+        let vest = book(&[(99.0, 5.0)], &[(100.0, 1.0), (102.0, 1.0)]);
+        let paradex = book(&[(101.0, 5.0)], &[(102.0, 5.0)]);
+        let legs = legs(vest, paradex);
+
+        let gate = OpportunityGate::default().with_depth_quantity(Some(2.0)).with_max_slippage(Some(0.5));
+        let mut opp = opportunity(100.0, 101.0);
+        assert_eq!(gate.check(&mut opp, &legs), Ok(()));
+        assert!((opp.fillable_quantity.unwrap() - 4.0 / 3.0).abs() < 1e-9);
+        assert!((opp.fill_spread_percent.unwrap() - 0.5 / 100.5 * 100.0).abs() < 1e-9);
+
+        // Without a target size the budget alone sizes it
+        let gate = OpportunityGate::default().with_max_slippage(Some(0.5));
+        assert!(gate.needs_books());
+        let mut opp = opportunity(100.0, 101.0);
+        assert_eq!(gate.check(&mut opp, &legs), Ok(()));
+        assert!((opp.fillable_quantity.unwrap() - 4.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,369 @@
+//! Process metrics exported in Prometheus text format.
+//!
+//! Values live in atomics so the adapter tasks and the price pipeline can
+//! update them without contending on a lock. The per-exchange and per-symbol
+//! maps only take their write lock the first time a key is seen; labels are
+//! kept in `BTreeMap`s so scrapes render in a stable order.
+//!
+//! [`Metrics::snapshot`] copies everything into a plain owned struct for
+//! JSON consumers (`GET /api/metrics`, debugging).
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::adapters::types::LatencyPercentiles;
+
+/// Per-exchange gauges and counters
+#[derive(Debug, Default)]
+struct ExchangeMetrics {
+    connected: AtomicBool,
+    orderbook_updates: AtomicU64,
+    /// Whether the adapter has measured a WS round-trip time yet
+    ws_rtt_measured: AtomicBool,
+    /// Smoothed WS round-trip time in ms
+    ws_rtt_ms: AtomicU64,
+    /// Whether the adapter has reported ingestion latency yet
+    ingest_latency_measured: AtomicBool,
+    /// Ingestion latency p50/p95/p99 in ms
+    ingest_latency_ms: [AtomicU64; 3],
+    /// Inbound WS messages that parsed / did not
+    messages_parsed: AtomicU64,
+    messages_malformed: AtomicU64,
+    /// Quotes received with best bid above best ask
+    crossed_books: AtomicU64,
+}
+
+/// Quantile labels for `ExchangeMetrics::ingest_latency_ms`
+const INGEST_QUANTILES: [&str; 3] = ["0.5", "0.95", "0.99"];
+
+impl ExchangeMetrics {
+    fn ingest_latency(&self) -> Option<LatencyPercentiles> {
+        if !self.ingest_latency_measured.load(Ordering::Relaxed) {
+            return None;
+        }
+        let [p50, p95, p99] = self.ingest_latency_ms.each_ref().map(|q| q.load(Ordering::Relaxed));
+        Some(LatencyPercentiles { p50, p95, p99 })
+    }
+
+    /// Share of inbound messages that failed to parse, `None` before any
+    fn parse_failure_ratio(&self) -> Option<f64> {
+        let parsed = self.messages_parsed.load(Ordering::Relaxed);
+        let malformed = self.messages_malformed.load(Ordering::Relaxed);
+        let total = parsed + malformed;
+        (total > 0).then(|| malformed as f64 / total as f64)
+    }
+}
+
+/// Shared metrics registry, cheap to update from any task.
+#[derive(Debug)]
+pub struct Metrics {
+    exchanges: RwLock<BTreeMap<Arc<str>, ExchangeMetrics>>,
+    /// Latest cross-exchange spread per symbol, stored as `f64` bits
+    best_spreads: RwLock<BTreeMap<Arc<str>, AtomicU64>>,
+    opportunities: AtomicU64,
+    /// When the registry was created, for uptime
+    started: Instant,
+}
+
+/// One exchange's metrics at snapshot time
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExchangeSnapshot {
+    pub connected: bool,
+    pub orderbook_updates: u64,
+    /// `None` until the adapter has measured a WS round-trip time
+    pub ws_rtt_ms: Option<u64>,
+    /// `None` until the adapter has timestamped book updates
+    pub ingest_latency_ms: Option<LatencyPercentiles>,
+    pub messages_parsed: u64,
+    pub messages_malformed: u64,
+    pub crossed_books: u64,
+}
+
+/// Owned copy of every metric, see [`Metrics::snapshot`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    pub uptime_ms: u64,
+    pub opportunities: u64,
+    pub exchanges: BTreeMap<String, ExchangeSnapshot>,
+    /// Latest cross-exchange spread per symbol (percent)
+    pub best_spreads: BTreeMap<String, f64>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            exchanges: RwLock::default(),
+            best_spreads: RwLock::default(),
+            opportunities: AtomicU64::default(),
+            started: Instant::now(),
+        }
+    }
+}
+
+impl Metrics {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark `exchange`'s adapter as connected or not.
+    pub fn set_connected(&self, exchange: &str, connected: bool) {
+        self.with_exchange(exchange, |m| m.connected.store(connected, Ordering::Relaxed));
+    }
+
+    /// Count one top-of-book change received from `exchange`.
+    pub fn record_orderbook_update(&self, exchange: &str) {
+        self.with_exchange(exchange, |m| {
+            m.orderbook_updates.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    /// Record `exchange`'s smoothed WebSocket PING→PONG round-trip time.
+    pub fn set_ws_rtt(&self, exchange: &str, rtt_ms: u64) {
+        self.with_exchange(exchange, |m| {
+            m.ws_rtt_ms.store(rtt_ms, Ordering::Relaxed);
+            m.ws_rtt_measured.store(true, Ordering::Relaxed);
+        });
+    }
+
+    /// Record `exchange`'s exchange-timestamp → receive latency percentiles.
+    pub fn set_ingest_latency(&self, exchange: &str, latency: LatencyPercentiles) {
+        self.with_exchange(exchange, |m| {
+            for (gauge, ms) in m.ingest_latency_ms.iter().zip([latency.p50, latency.p95, latency.p99]) {
+                gauge.store(ms, Ordering::Relaxed);
+            }
+            m.ingest_latency_measured.store(true, Ordering::Relaxed);
+        });
+    }
+
+    /// Count one crossed quote (best bid above best ask) from `exchange`.
+    pub fn record_crossed_book(&self, exchange: &str) {
+        self.with_exchange(exchange, |m| {
+            m.crossed_books.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    /// Record `exchange`'s running parsed / malformed inbound message counts.
+    pub fn set_parse_stats(&self, exchange: &str, parsed: u64, malformed: u64) {
+        self.with_exchange(exchange, |m| {
+            m.messages_parsed.store(parsed, Ordering::Relaxed);
+            m.messages_malformed.store(malformed, Ordering::Relaxed);
+        });
+    }
+
+    /// Record the current best cross-exchange spread (percent) for `symbol`.
+    pub fn set_best_spread(&self, symbol: &str, spread_percent: f64) {
+        let bits = spread_percent.to_bits();
+        if let Some(gauge) = read(&self.best_spreads).get(symbol) {
+            gauge.store(bits, Ordering::Relaxed);
+            return;
+        }
+        write(&self.best_spreads)
+            .entry(Arc::from(symbol))
+            .or_default()
+            .store(bits, Ordering::Relaxed);
+    }
+
+    /// Count one emitted arbitrage opportunity.
+    pub fn record_opportunity(&self) {
+        self.opportunities.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Render every metric in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let exchanges = read(&self.exchanges);
+
+        header(&mut out, "arbi_exchange_up", "gauge", "Whether the exchange adapter is connected (1) or not (0)");
+        for (exchange, m) in exchanges.iter() {
+            let up = u8::from(m.connected.load(Ordering::Relaxed));
+            let _ = writeln!(out, "arbi_exchange_up{{exchange=\"{exchange}\"}} {up}");
+        }
+
+        header(&mut out, "arbi_orderbook_updates_total", "counter", "Top-of-book changes received per exchange");
+        for (exchange, m) in exchanges.iter() {
+            let updates = m.orderbook_updates.load(Ordering::Relaxed);
+            let _ = writeln!(out, "arbi_orderbook_updates_total{{exchange=\"{exchange}\"}} {updates}");
+        }
+
+        header(&mut out, "arbi_crossed_books_total", "counter", "Quotes received with best bid above best ask per exchange");
+        for (exchange, m) in exchanges.iter() {
+            let crossed = m.crossed_books.load(Ordering::Relaxed);
+            let _ = writeln!(out, "arbi_crossed_books_total{{exchange=\"{exchange}\"}} {crossed}");
+        }
+
+        header(&mut out, "arbi_ws_rtt_ms", "gauge", "Smoothed WebSocket ping round-trip time per exchange (ms)");
+        for (exchange, m) in exchanges.iter().filter(|(_, m)| m.ws_rtt_measured.load(Ordering::Relaxed)) {
+            let rtt = m.ws_rtt_ms.load(Ordering::Relaxed);
+            let _ = writeln!(out, "arbi_ws_rtt_ms{{exchange=\"{exchange}\"}} {rtt}");
+        }
+
+        header(&mut out, "arbi_ingest_latency_ms", "gauge", "Exchange timestamp to local receive latency of book updates (ms)");
+        for (exchange, m) in exchanges.iter().filter(|(_, m)| m.ingest_latency_measured.load(Ordering::Relaxed)) {
+            for (quantile, gauge) in INGEST_QUANTILES.iter().zip(&m.ingest_latency_ms) {
+                let ms = gauge.load(Ordering::Relaxed);
+                let _ = writeln!(out, "arbi_ingest_latency_ms{{exchange=\"{exchange}\",quantile=\"{quantile}\"}} {ms}");
+            }
+        }
+
+        header(&mut out, "arbi_ws_messages_total", "counter", "Inbound WebSocket messages per exchange, by parse result");
+        for (exchange, m) in exchanges.iter() {
+            let parsed = m.messages_parsed.load(Ordering::Relaxed);
+            let malformed = m.messages_malformed.load(Ordering::Relaxed);
+            let _ = writeln!(out, "arbi_ws_messages_total{{exchange=\"{exchange}\",result=\"parsed\"}} {parsed}");
+            let _ = writeln!(out, "arbi_ws_messages_total{{exchange=\"{exchange}\",result=\"malformed\"}} {malformed}");
+        }
+
+        header(&mut out, "arbi_ws_parse_failure_ratio", "gauge", "Share of inbound WebSocket messages that failed to parse per exchange");
+        for (exchange, m) in exchanges.iter() {
+            if let Some(ratio) = m.parse_failure_ratio() {
+                let _ = writeln!(out, "arbi_ws_parse_failure_ratio{{exchange=\"{exchange}\"}} {ratio}");
+            }
+        }
+        drop(exchanges);
+
+        header(&mut out, "arbi_best_spread_percent", "gauge", "Current best cross-exchange spread per symbol (percent)");
+        for (symbol, gauge) in read(&self.best_spreads).iter() {
+            let spread = f64::from_bits(gauge.load(Ordering::Relaxed));
+            let _ = writeln!(out, "arbi_best_spread_percent{{symbol=\"{symbol}\"}} {spread}");
+        }
+
+        header(&mut out, "arbi_opportunities_total", "counter", "Arbitrage opportunities emitted");
+        let _ = writeln!(out, "arbi_opportunities_total {}", self.opportunities.load(Ordering::Relaxed));
+
+        out
+    }
+
+    /// Copy every metric into an owned [`MetricsSnapshot`].
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let exchanges = read(&self.exchanges)
+            .iter()
+            .map(|(exchange, m)| {
+                let snapshot = ExchangeSnapshot {
+                    connected: m.connected.load(Ordering::Relaxed),
+                    orderbook_updates: m.orderbook_updates.load(Ordering::Relaxed),
+                    ws_rtt_ms: m
+                        .ws_rtt_measured
+                        .load(Ordering::Relaxed)
+                        .then(|| m.ws_rtt_ms.load(Ordering::Relaxed)),
+                    ingest_latency_ms: m.ingest_latency(),
+                    messages_parsed: m.messages_parsed.load(Ordering::Relaxed),
+                    messages_malformed: m.messages_malformed.load(Ordering::Relaxed),
+                    crossed_books: m.crossed_books.load(Ordering::Relaxed),
+                };
+                (exchange.to_string(), snapshot)
+            })
+            .collect();
+        let best_spreads = read(&self.best_spreads)
+            .iter()
+            .map(|(symbol, gauge)| (symbol.to_string(), f64::from_bits(gauge.load(Ordering::Relaxed))))
+            .collect();
+
+        MetricsSnapshot {
+            uptime_ms: self.started.elapsed().as_millis() as u64,
+            opportunities: self.opportunities.load(Ordering::Relaxed),
+            exchanges,
+            best_spreads,
+        }
+    }
+
+    fn with_exchange(&self, exchange: &str, f: impl FnOnce(&ExchangeMetrics)) {
+        if let Some(m) = read(&self.exchanges).get(exchange) {
+            f(m);
+            return;
+        }
+        f(write(&self.exchanges).entry(Arc::from(exchange)).or_default());
+    }
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+// Metrics are plain atomics, so a panic mid-update cannot leave them
+// inconsistent — keep serving through a poisoned lock.
+fn read<T>(lock: &RwLock<T>) -> std::sync::RwLockReadGuard<'_, T> {
+    lock.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn write<T>(lock: &RwLock<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+    lock.write().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_counts_and_gauges() {
+        let metrics = Metrics::new();
+        metrics.set_connected("vest", true);
+        metrics.set_connected("paradex", false);
+        metrics.record_orderbook_update("vest");
+        metrics.record_orderbook_update("vest");
+        metrics.set_best_spread("BTC", 0.5);
+        metrics.set_best_spread("BTC", 0.25);
+        metrics.record_opportunity();
+        metrics.set_ws_rtt("vest", 42);
+        metrics.set_ingest_latency("vest", LatencyPercentiles { p50: 8, p95: 30, p99: 55 });
+        metrics.set_parse_stats("vest", 9, 1);
+        metrics.record_crossed_book("paradex");
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE arbi_exchange_up gauge\n"));
+        assert!(text.contains("arbi_exchange_up{exchange=\"vest\"} 1\n"));
+        assert!(text.contains("arbi_exchange_up{exchange=\"paradex\"} 0\n"));
+        assert!(text.contains("arbi_orderbook_updates_total{exchange=\"vest\"} 2\n"));
+        assert!(text.contains("arbi_orderbook_updates_total{exchange=\"paradex\"} 0\n"));
+        assert!(text.contains("arbi_crossed_books_total{exchange=\"paradex\"} 1\n"));
+        assert!(text.contains("arbi_crossed_books_total{exchange=\"vest\"} 0\n"));
+        assert!(text.contains("arbi_best_spread_percent{symbol=\"BTC\"} 0.25\n"));
+        assert!(text.contains("arbi_opportunities_total 1\n"));
+        // RTT only for exchanges that measured one
+        assert!(text.contains("arbi_ws_rtt_ms{exchange=\"vest\"} 42\n"));
+        assert!(!text.contains("arbi_ws_rtt_ms{exchange=\"paradex\"}"));
+        assert!(text.contains("arbi_ingest_latency_ms{exchange=\"vest\",quantile=\"0.5\"} 8\n"));
+        assert!(text.contains("arbi_ingest_latency_ms{exchange=\"vest\",quantile=\"0.95\"} 30\n"));
+        assert!(text.contains("arbi_ingest_latency_ms{exchange=\"vest\",quantile=\"0.99\"} 55\n"));
+        assert!(!text.contains("arbi_ingest_latency_ms{exchange=\"paradex\""));
+        assert!(text.contains("arbi_ws_messages_total{exchange=\"vest\",result=\"parsed\"} 9\n"));
+        assert!(text.contains("arbi_ws_messages_total{exchange=\"vest\",result=\"malformed\"} 1\n"));
+        assert!(text.contains("arbi_ws_parse_failure_ratio{exchange=\"vest\"} 0.1\n"));
+        assert!(!text.contains("arbi_ws_parse_failure_ratio{exchange=\"paradex\"}"));
+    }
+
+    #[test]
+    fn test_snapshot_reflects_updates() {
+        let metrics = Metrics::new();
+        let before = metrics.snapshot();
+        assert_eq!(before.opportunities, 0);
+        assert!(before.exchanges.is_empty());
+
+        metrics.set_connected("vest", true);
+        metrics.record_orderbook_update("vest");
+        metrics.record_orderbook_update("paradex");
+        metrics.set_ws_rtt("vest", 12);
+        metrics.set_best_spread("BTC", 0.3);
+        metrics.record_opportunity();
+        metrics.record_opportunity();
+
+        let after = metrics.snapshot();
+        assert_eq!(after.opportunities, 2);
+        assert_eq!(
+            after.exchanges["vest"],
+            ExchangeSnapshot { connected: true, orderbook_updates: 1, ws_rtt_ms: Some(12), ingest_latency_ms: None, messages_parsed: 0, messages_malformed: 0, crossed_books: 0 }
+        );
+        assert_eq!(
+            after.exchanges["paradex"],
+            ExchangeSnapshot { connected: false, orderbook_updates: 1, ws_rtt_ms: None, ingest_latency_ms: None, messages_parsed: 0, messages_malformed: 0, crossed_books: 0 }
+        );
+        assert_eq!(after.best_spreads["BTC"], 0.3);
+        assert!(after.uptime_ms >= before.uptime_ms);
+    }
+}
@@ -14,23 +14,53 @@
 pub mod aggregator;
 pub mod channels;
 pub mod detector;
+pub mod dispatch;
+pub mod events;
+pub mod gate;
+pub mod metrics;
 pub mod pyth;
+pub mod scan;
+pub mod shutdown;
 pub mod spread;
+pub mod spread_history;
 pub mod types;
+pub mod watchdog;
 
 // Explicit re-exports for new pipeline types
 pub use types::{
-    AggregatedPrice, ArbitrageOpportunity, BroadcastEvent, ExchangePrice, PriceData,
-    current_time_ms,
+    AggregatedPrice, ArbitrageOpportunity, BroadcastEvent, ExchangePrice, PriceBand, PriceData,
+    SequencedEvent, current_time_ms, rank_opportunities,
 };
 pub use aggregator::PriceAggregator;
-pub use detector::{ArbitrageDetector, DetectorConfig};
+pub use detector::{ArbitrageDetector, DetectorConfig, MonitorDetector};
+pub use dispatch::OpportunityDispatcher;
+pub use events::{EventLogFilter, FileEventSink};
+pub use gate::{LegState, LegStates, OpportunityGate, RecentMids, SkipReason};
+pub use metrics::{ExchangeSnapshot, Metrics, MetricsSnapshot};
 
 // Explicit re-exports for spread module
-pub use spread::{SpreadCalculator, SpreadDirection, SpreadResult};
+pub use spread::{
+    book_imbalance, weighted_vwap_fill_price, BookSide, SpreadCalculator, SpreadDirection,
+    SpreadEma, SpreadResult, VwapWeighting,
+};
+
+// Explicit re-exports for spread history (percentile ranking)
+pub use spread_history::{SpreadHistory, DEFAULT_SPREAD_HISTORY_CAPACITY};
 
 // Explicit re-exports for channels module
-pub use channels::{AtomicBestPrices, OrderbookNotify, SharedBestPrices, SharedOrderbooks};
+pub use channels::{
+    AtomicBestPrices, BestPricePublisher, BookTap, OrderbookNotify, SharedBestPrices, SharedOrderbooks,
+    ThrottledBookWriter,
+};
+
+// Explicit re-exports for watchdog module (dead-man's switch)
+pub use watchdog::{spawn_stall_alarm, spawn_watchdog, Watchdog, WatchdogConfig};
+
+// Explicit re-exports for shutdown module (graceful shutdown acks)
+pub use shutdown::{wait_for_tasks, ShutdownListener, ShutdownSignal};
+
+// Explicit re-exports for one-shot scan mode
+pub use scan::{scan_once, scan_once_requested, ScanOptions, ScanReport};
 
 // Explicit re-exports for pyth module (USD/USDC conversion)
 pub use pyth::{spawn_rate_refresh_task, UsdcRateCache};
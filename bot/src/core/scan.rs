@@ -0,0 +1,302 @@
+//! One-shot scan mode (`--once` / `SCAN_ONCE=1`).
+//!
+//! Connects the adapters the configured monitors need, waits for the first
+//! two-sided book on both legs of every monitor, reports entry/exit spreads
+//! (top of book and VWAP for a fixed quantity), then disconnects. Meant for
+//! monitoring scripts and CI smoke tests against testnet.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use serde::Serialize;
+use tracing::{info, warn};
+
+use crate::adapters::manager::disconnect_all;
+use crate::adapters::{create_adapter, AnyAdapter, ExchangeAdapter, SymbolOverrides};
+use crate::adapters::types::Orderbook;
+use crate::config::{DashboardConfig, SpreadBasis};
+use crate::core::spread::{book_imbalance, SpreadCalculator, SpreadDirection, IMBALANCE_LEVELS};
+use crate::core::types::current_time_ms;
+
+/// Default trade size used for VWAP-adjusted spreads (base units)
+const DEFAULT_SCAN_QUANTITY: f64 = 1.0;
+/// Default bound on waiting for books on every leg
+const DEFAULT_SCAN_TIMEOUT_MS: u64 = 15_000;
+/// How often books are re-checked while waiting
+const SCAN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// Bound on disconnecting every adapter after the scan
+const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Whether one-shot scan mode was requested (`--once` flag or `SCAN_ONCE=1`)
+pub fn scan_once_requested() -> bool {
+    std::env::args().any(|arg| arg == "--once")
+        || std::env::var("SCAN_ONCE").is_ok_and(|v| v == "1" || v == "true")
+}
+
+/// Knobs for a one-shot scan
+#[derive(Debug, Clone)]
+pub struct ScanOptions {
+    /// Trade size for the VWAP-adjusted spreads
+    pub vwap_quantity: f64,
+    /// Give up on legs without a book after this long
+    pub timeout: Duration,
+    /// Exchange symbols that don't follow the per-exchange template
+    pub symbol_overrides: SymbolOverrides,
+}
+
+impl ScanOptions {
+    /// Read `SCAN_QUANTITY` and `SCAN_TIMEOUT_MS`, falling back to defaults
+    pub fn from_env() -> Self {
+        let vwap_quantity = std::env::var("SCAN_QUANTITY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_SCAN_QUANTITY);
+        let timeout_ms = std::env::var("SCAN_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_SCAN_TIMEOUT_MS);
+        Self {
+            vwap_quantity,
+            timeout: Duration::from_millis(timeout_ms),
+            symbol_overrides: SymbolOverrides::default(),
+        }
+    }
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            vwap_quantity: DEFAULT_SCAN_QUANTITY,
+            timeout: Duration::from_millis(DEFAULT_SCAN_TIMEOUT_MS),
+            symbol_overrides: SymbolOverrides::default(),
+        }
+    }
+}
+
+/// Spreads for one monitor at the moment both legs had a book
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanReport {
+    pub monitor_id: String,
+    pub symbol: String,
+    pub dex_a: String,
+    pub dex_b: String,
+    /// Direction with the better entry (AOverB buys on A, sells on B)
+    pub direction: SpreadDirection,
+    /// Best entry spread over both directions (top of book, percent)
+    pub entry_spread_pct: f64,
+    /// Exit spread matching that entry direction (top of book, percent)
+    pub exit_spread_pct: f64,
+    /// Best spread between the legs' mid prices (percent), for comparison
+    pub mid_spread_pct: f64,
+    /// Trade size the VWAP spreads are computed for
+    pub vwap_quantity: f64,
+    /// `None` when a book is too thin to fill `vwap_quantity`
+    pub vwap_entry_spread_pct: Option<f64>,
+    pub vwap_exit_spread_pct: Option<f64>,
+    /// Bid/ask volume imbalance over the top levels of each leg, in [-1, 1]
+    pub imbalance_a: f64,
+    pub imbalance_b: f64,
+    pub timestamp_ms: u64,
+}
+
+impl ScanReport {
+    /// Build a report from the two legs' books.
+    ///
+    /// Returns `None` if either book lacks a best bid or ask.
+    pub fn from_books(
+        monitor: &DashboardConfig,
+        book_a: &Orderbook,
+        book_b: &Orderbook,
+        vwap_quantity: f64,
+    ) -> Option<Self> {
+        let dex_a = monitor.dex_a.to_string();
+        let dex_b = monitor.dex_b.to_string();
+        let calc = SpreadCalculator::new(dex_a.as_str(), dex_b.as_str());
+
+        let (direction, _) = calc.best_direction(book_a, book_b)?;
+        let (entry_spread_pct, exit_spread_pct) = calc.calculate_dual_spreads(book_a, book_b)?;
+        let vwap = calc.calculate_dual_spreads_with_depth(book_a, book_b, vwap_quantity);
+        let mid = calc.with_basis(SpreadBasis::Mid).calculate(book_a, book_b)?;
+
+        Some(Self {
+            monitor_id: monitor.id.clone(),
+            symbol: monitor.pair.base().to_string(),
+            dex_a,
+            dex_b,
+            direction,
+            entry_spread_pct,
+            exit_spread_pct,
+            mid_spread_pct: mid.spread_pct,
+            vwap_quantity,
+            vwap_entry_spread_pct: vwap.map(|(entry, _)| entry),
+            vwap_exit_spread_pct: vwap.map(|(_, exit)| exit),
+            imbalance_a: book_imbalance(book_a, IMBALANCE_LEVELS),
+            imbalance_b: book_imbalance(book_b, IMBALANCE_LEVELS),
+            timestamp_ms: current_time_ms(),
+        })
+    }
+}
+
+/// Connect, wait for books on every monitor's legs, report, disconnect.
+///
+/// Monitors whose legs never produced a book within `options.timeout` are
+/// logged and left out of the result, so callers can compare its length
+/// against `monitors.len()`.
+pub async fn scan_once(monitors: &[DashboardConfig], options: &ScanOptions) -> Vec<ScanReport> {
+    // Connect each exchange once, however many monitors use it
+    let mut adapters: HashMap<String, AnyAdapter> = HashMap::new();
+    for monitor in monitors {
+        for dex in [monitor.dex_a.to_string(), monitor.dex_b.to_string()] {
+            if adapters.contains_key(&dex) {
+                continue;
+            }
+            let mut adapter = match create_adapter(&dex) {
+                Ok(a) => a,
+                Err(e) => {
+                    warn!(exchange = %dex, error = %e, "Failed to create adapter");
+                    continue;
+                }
+            };
+            if let Err(e) = adapter.connect().await {
+                warn!(exchange = %dex, error = %e, "Failed to connect");
+                continue;
+            }
+            adapters.insert(dex, adapter);
+        }
+    }
+
+    // Subscribe each (exchange, symbol) leg once
+    let mut legs: HashMap<&str, [Option<(String, String)>; 2]> = HashMap::new();
+    let mut subscribed: HashSet<(String, String)> = HashSet::new();
+    for monitor in monitors {
+        let mut monitor_legs = [None, None];
+        for (slot, dex) in [monitor.dex_a.to_string(), monitor.dex_b.to_string()].into_iter().enumerate() {
+            let Some(adapter) = adapters.get_mut(&dex) else {
+                continue;
+            };
+            let symbol = match options
+                .symbol_overrides
+                .resolve_with_quote(&dex, monitor.pair.base(), monitor.pair.quote())
+            {
+                Ok(s) => s,
+                Err(e) => {
+                    warn!(exchange = %dex, error = %e, "Cannot resolve symbol");
+                    continue;
+                }
+            };
+            if subscribed.insert((dex.clone(), symbol.clone())) {
+                if let Err(e) = adapter.subscribe_orderbook(&symbol).await {
+                    warn!(exchange = %dex, symbol = %symbol, error = %e, "Failed to subscribe");
+                    continue;
+                }
+            }
+            monitor_legs[slot] = Some((dex, symbol));
+        }
+        legs.insert(monitor.id.as_str(), monitor_legs);
+    }
+
+    // Wait until every monitor has a report or the deadline passes
+    let deadline = tokio::time::Instant::now() + options.timeout;
+    let mut reports: HashMap<&str, ScanReport> = HashMap::new();
+    loop {
+        for monitor in monitors {
+            if reports.contains_key(monitor.id.as_str()) {
+                continue;
+            }
+            let [Some(leg_a), Some(leg_b)] = &legs[monitor.id.as_str()] else {
+                continue;
+            };
+            let (Some(book_a), Some(book_b)) = (
+                current_book(&adapters, leg_a).await,
+                current_book(&adapters, leg_b).await,
+            ) else {
+                continue;
+            };
+            if let Some(report) = ScanReport::from_books(monitor, &book_a, &book_b, options.vwap_quantity) {
+                reports.insert(monitor.id.as_str(), report);
+            }
+        }
+
+        if reports.len() == monitors.len() || tokio::time::Instant::now() >= deadline {
+            break;
+        }
+        tokio::time::sleep(SCAN_POLL_INTERVAL).await;
+    }
+
+    disconnect_all(&mut adapters, DISCONNECT_TIMEOUT).await;
+
+    let mut ordered = Vec::with_capacity(reports.len());
+    for monitor in monitors {
+        match reports.remove(monitor.id.as_str()) {
+            Some(report) => ordered.push(report),
+            None => warn!(monitor = %monitor.id, "No book on both legs before timeout"),
+        }
+    }
+    info!(reported = ordered.len(), monitors = monitors.len(), "Scan complete");
+    ordered
+}
+
+/// Snapshot of a leg's shared orderbook, if it has data
+async fn current_book(adapters: &HashMap<String, AnyAdapter>, (dex, symbol): &(String, String)) -> Option<Orderbook> {
+    let shared = adapters.get(dex)?.get_shared_orderbooks();
+    let books = shared.read().await;
+    books.get(symbol).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{BaseAsset, Dex, TradingPair};
+
+    fn monitor() -> DashboardConfig {
+        DashboardConfig {
+            id: "btc_vest_paradex".to_string(),
+            pair: TradingPair::perp(BaseAsset::Btc),
+            dex_a: Dex::Vest,
+            dex_b: Dex::Paradex,
+            spread_entry: 0.1,
+            entry_confirm_ms: None,
+        }
+    }
+
+    #[test]
+    fn test_scan_report_json_shape() {
+        let book_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 0.5), (102.0, 0.5)]);
+        let book_b = Orderbook::from_levels(&[(104.0, 0.5), (102.0, 0.5)], &[(105.0, 1.0)]);
+
+        let report = ScanReport::from_books(&monitor(), &book_a, &book_b, 1.0).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["monitor_id"], "btc_vest_paradex");
+        assert_eq!(json["symbol"], "BTC");
+        assert_eq!(json["dex_a"], "vest");
+        assert_eq!(json["dex_b"], "paradex");
+        assert_eq!(json["vwap_quantity"], 1.0);
+        assert_eq!(json["direction"], "AOverB");
+        // Top of book: buy A at 100, sell B at 104
+        assert!((json["entry_spread_pct"].as_f64().unwrap() - 4.0).abs() < 1e-9);
+        assert!(json["exit_spread_pct"].is_f64());
+        // Mids: A at 99.5, B at 104.5
+        assert!((json["mid_spread_pct"].as_f64().unwrap() - 5.0 / 99.5 * 100.0).abs() < 1e-9);
+        // VWAP: buy A at 101, sell B at 103
+        let vwap_entry = json["vwap_entry_spread_pct"].as_f64().unwrap();
+        assert!((vwap_entry - 2.0 / 101.0 * 100.0).abs() < 1e-9);
+        // A: 1 bid vs 1 ask; B: 1 bid vs 1 ask
+        assert_eq!(json["imbalance_a"], 0.0);
+        assert_eq!(json["imbalance_b"], 0.0);
+        assert!(json["timestamp_ms"].is_u64());
+    }
+
+    #[test]
+    fn test_scan_report_thin_book_has_null_vwap() {
+        let book_a = Orderbook::from_levels(&[(99.0, 0.1)], &[(100.0, 0.1)]);
+        let book_b = Orderbook::from_levels(&[(100.5, 0.1)], &[(101.0, 0.1)]);
+
+        let report = ScanReport::from_books(&monitor(), &book_a, &book_b, 1.0).unwrap();
+        let json = serde_json::to_value(&report).unwrap();
+        assert!(json["vwap_entry_spread_pct"].is_null());
+        assert!(json["vwap_exit_spread_pct"].is_null());
+
+        assert!(ScanReport::from_books(&monitor(), &Orderbook::new(), &book_b, 1.0).is_none());
+    }
+}
@@ -0,0 +1,142 @@
+//! Graceful shutdown coordination.
+//!
+//! `main` flips a [`ShutdownSignal`] on Ctrl+C; long-running tasks watch it
+//! and leave their loops (disconnecting adapters on the way out). Finishing
+//! the task is the acknowledgement: [`wait_for_tasks`] awaits every handle
+//! up to a shared deadline and aborts whatever has not stopped by then.
+
+use std::time::Duration;
+
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Sender half of the shutdown signal, owned by `main`.
+#[derive(Debug)]
+pub struct ShutdownSignal {
+    tx: watch::Sender<bool>,
+}
+
+impl ShutdownSignal {
+    /// Create a signal that has not fired yet.
+    pub fn new() -> Self {
+        let (tx, _) = watch::channel(false);
+        Self { tx }
+    }
+
+    /// Receiver for a task to watch.
+    pub fn subscribe(&self) -> ShutdownListener {
+        ShutdownListener {
+            rx: self.tx.subscribe(),
+        }
+    }
+
+    /// Tell all listeners to stop.
+    pub fn trigger(&self) {
+        self.tx.send_replace(true);
+    }
+}
+
+impl Default for ShutdownSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Receiver half of the shutdown signal, held by each task.
+#[derive(Debug, Clone)]
+pub struct ShutdownListener {
+    rx: watch::Receiver<bool>,
+}
+
+impl ShutdownListener {
+    /// Whether shutdown has been requested.
+    pub fn is_triggered(&self) -> bool {
+        *self.rx.borrow()
+    }
+
+    /// Resolve once shutdown is requested (immediately if it already was).
+    ///
+    /// Also resolves if the `ShutdownSignal` is dropped.
+    pub async fn wait(&mut self) {
+        let _ = self.rx.wait_for(|&stop| stop).await;
+    }
+}
+
+/// Wait (bounded) for tasks to acknowledge shutdown by finishing.
+///
+/// All tasks share one `timeout` deadline. Tasks still running at the
+/// deadline are aborted. Returns the names of the aborted tasks.
+pub async fn wait_for_tasks(tasks: Vec<(String, JoinHandle<()>)>, timeout: Duration) -> Vec<String> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    let mut aborted = Vec::new();
+
+    for (name, mut handle) in tasks {
+        match tokio::time::timeout_at(deadline, &mut handle).await {
+            Ok(_) => info!(task = %name, "Task stopped"),
+            Err(_) => {
+                warn!(task = %name, timeout_ms = timeout.as_millis() as u64, "Task did not stop in time — aborting");
+                handle.abort();
+                aborted.push(name);
+            }
+        }
+    }
+
+    aborted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spawn_worker(mut listener: ShutdownListener, cleanup: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            listener.wait().await;
+            tokio::time::sleep(cleanup).await;
+        })
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_task_acks() {
+        let signal = ShutdownSignal::new();
+        let tasks = vec![
+            ("pipeline".to_string(), spawn_worker(signal.subscribe(), Duration::from_millis(20))),
+            ("vest".to_string(), spawn_worker(signal.subscribe(), Duration::from_millis(40))),
+        ];
+
+        signal.trigger();
+        let started = std::time::Instant::now();
+        let aborted = wait_for_tasks(tasks, Duration::from_secs(2)).await;
+
+        assert!(aborted.is_empty());
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_aborts_tasks_past_timeout() {
+        let signal = ShutdownSignal::new();
+        let stuck = tokio::spawn(std::future::pending::<()>());
+        let tasks = vec![
+            ("pipeline".to_string(), spawn_worker(signal.subscribe(), Duration::ZERO)),
+            ("stuck".to_string(), stuck),
+        ];
+
+        signal.trigger();
+        let started = std::time::Instant::now();
+        let aborted = wait_for_tasks(tasks, Duration::from_millis(100)).await;
+
+        assert_eq!(aborted, vec!["stuck".to_string()]);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_listener_sees_trigger_after_the_fact() {
+        let signal = ShutdownSignal::new();
+        signal.trigger();
+        let mut listener = signal.subscribe();
+        assert!(listener.is_triggered());
+        tokio::time::timeout(Duration::from_millis(100), listener.wait())
+            .await
+            .expect("already-triggered signal resolves immediately");
+    }
+}
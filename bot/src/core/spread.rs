@@ -7,8 +7,13 @@
 //! - `SpreadCalculator`: Main calculator struct for a DEX pair
 //! - `SpreadResult`: Result of spread calculation with direction and prices
 //! - `SpreadDirection`: Direction of the arbitrage opportunity
+//! - `SpreadBasis`: Crossing (execution) or mid-to-mid (research) prices
+//! - `SpreadEma`: Exponential moving average to smooth tick-to-tick spread noise
+//! - `VwapWeighting`: How book depth counts toward a VWAP fill
 
-use crate::adapters::types::Orderbook;
+use crate::adapters::types::{Orderbook, OrderbookLevel};
+use crate::config::SpreadBasis;
+use crate::error::AppError;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -105,6 +110,12 @@ pub struct SpreadCalculator {
     pub dex_a: String,
     /// DEX B identifier (e.g., "paradex")
     pub dex_b: String,
+    /// Trade size for depth-aware pricing (None = top of book only)
+    pub depth_quantity: Option<f64>,
+    /// How depth is counted when pricing legs at VWAP
+    pub vwap_weighting: VwapWeighting,
+    /// Prices `calculate()` measures between (Crossing by default)
+    pub basis: SpreadBasis,
 }
 
 impl SpreadCalculator {
@@ -113,9 +124,34 @@ impl SpreadCalculator {
         Self {
             dex_a: dex_a.into(),
             dex_b: dex_b.into(),
+            depth_quantity: None,
+            vwap_weighting: VwapWeighting::default(),
+            basis: SpreadBasis::default(),
         }
     }
 
+    /// Measure `calculate()` spreads on `basis` (see [`SpreadBasis`])
+    pub fn with_basis(mut self, basis: SpreadBasis) -> Self {
+        self.basis = basis;
+        self
+    }
+
+    /// Price each leg at the VWAP of the depth a `quantity`-sized trade would consume
+    ///
+    /// With this set, `calculate()` walks the asks of the buy leg and the bids
+    /// of the sell leg (per direction) instead of using the best prices.
+    pub fn with_depth_quantity(mut self, quantity: f64) -> Self {
+        self.depth_quantity = Some(quantity);
+        self
+    }
+
+    /// Discount deeper levels when pricing legs at VWAP (see [`VwapWeighting`])
+    pub fn with_vwap_weighting(mut self, weighting: VwapWeighting) -> Result<Self, AppError> {
+        weighting.validate()?;
+        self.vwap_weighting = weighting;
+        Ok(self)
+    }
+
     /// Calculate spread between two orderbooks
     ///
     /// Returns `None` if either orderbook lacks best bid/ask (empty orderbook edge case).
@@ -131,50 +167,80 @@ impl SpreadCalculator {
         orderbook_a: &Orderbook,
         orderbook_b: &Orderbook,
     ) -> Option<SpreadResult> {
+        if let Some(quantity) = self.depth_quantity {
+            return self.calculate_with_depth(orderbook_a, orderbook_b, quantity);
+        }
+
         // Get best prices from both orderbooks
         let ask_a = orderbook_a.best_ask()?;
         let bid_a = orderbook_a.best_bid()?;
         let ask_b = orderbook_b.best_ask()?;
         let bid_b = orderbook_b.best_bid()?;
 
-        // Calculate spreads in both directions using CORRECT formula from arbitrage-v5:
-        // spread = (bid_sell - ask_buy) / ask_buy * 100
-        // This represents the actual profit percentage on entry
-        // Positive spread = we can sell higher than we buy = PROFIT
-
-        // Direction A→B: Buy on A (at ask_a), Sell on B (at bid_b)
-        let spread_a_to_b = ((bid_b - ask_a) / ask_a) * 100.0;
+        self.calculate_from_prices(bid_a, ask_a, bid_b, ask_b)
+    }
 
-        // Direction B→A: Buy on B (at ask_b), Sell on A (at bid_a)
-        let spread_b_to_a = ((bid_a - ask_b) / ask_b) * 100.0;
+    /// More profitable direction between the two books, with its spread
+    ///
+    /// Uses best prices. When neither direction is profitable this still
+    /// returns the less-negative one, for display; callers gate on the
+    /// spread. Returns `None` if either book lacks a best bid or ask.
+    #[inline]
+    #[must_use]
+    pub fn best_direction(
+        &self,
+        orderbook_a: &Orderbook,
+        orderbook_b: &Orderbook,
+    ) -> Option<(SpreadDirection, f64)> {
+        let ask_a = orderbook_a.best_ask()?;
+        let bid_a = orderbook_a.best_bid()?;
+        let ask_b = orderbook_b.best_ask()?;
+        let bid_b = orderbook_b.best_bid()?;
 
-        let timestamp_ms = current_time_ms();
-        let midpoint = (ask_a + bid_b + ask_b + bid_a) / 4.0;
+        Some(Self::best_direction_from_prices(bid_a, ask_a, bid_b, ask_b))
+    }
 
-        // Return the better (higher) spread opportunity
+    /// Pick the direction with the higher entry spread
+    ///
+    /// Spread formula from arbitrage-v5: `(bid_sell - ask_buy) / ask_buy * 100`,
+    /// i.e. the profit percentage on entry (positive = sell above buy).
+    /// A→B buys on A (ask_a) and sells on B (bid_b); B→A the reverse. Ties go
+    /// to A→B.
+    #[inline]
+    fn best_direction_from_prices(bid_a: f64, ask_a: f64, bid_b: f64, ask_b: f64) -> (SpreadDirection, f64) {
+        let spread_a_to_b = Self::calculate_entry_spread(ask_a, bid_b);
+        let spread_b_to_a = Self::calculate_entry_spread(ask_b, bid_a);
         if spread_a_to_b >= spread_b_to_a {
-            // A→B: Buy on A (Vest), Sell on B (Paradex)
-            Some(SpreadResult {
-                spread_pct: spread_a_to_b,
-                direction: SpreadDirection::AOverB,
-                ask_price: ask_a, // We BUY at this price (on A)
-                bid_price: bid_b, // We SELL at this price (on B)
-                midpoint,
-                timestamp_ms,
-            })
+            (SpreadDirection::AOverB, spread_a_to_b)
         } else {
-            // B→A: Buy on B (Paradex), Sell on A (Vest)
-            Some(SpreadResult {
-                spread_pct: spread_b_to_a,
-                direction: SpreadDirection::BOverA,
-                ask_price: ask_b, // We BUY at this price (on B)
-                bid_price: bid_a, // We SELL at this price (on A)
-                midpoint,
-                timestamp_ms,
-            })
+            (SpreadDirection::BOverA, spread_b_to_a)
         }
     }
 
+    /// Calculate spread using the side of each book the trade would consume
+    ///
+    /// A→B buys on A (walks A's asks) and sells on B (walks B's bids);
+    /// B→A buys on B (walks B's asks) and sells on A (walks A's bids).
+    /// Each leg is priced at the VWAP of filling `quantity`.
+    ///
+    /// Returns `None` if either book lacks the depth to fill `quantity` in
+    /// both directions.
+    #[must_use]
+    pub fn calculate_with_depth(
+        &self,
+        orderbook_a: &Orderbook,
+        orderbook_b: &Orderbook,
+        quantity: f64,
+    ) -> Option<SpreadResult> {
+        let weighting = self.vwap_weighting;
+        let ask_a = weighted_vwap_fill_price(&orderbook_a.asks, quantity, weighting)?;
+        let bid_a = weighted_vwap_fill_price(&orderbook_a.bids, quantity, weighting)?;
+        let ask_b = weighted_vwap_fill_price(&orderbook_b.asks, quantity, weighting)?;
+        let bid_b = weighted_vwap_fill_price(&orderbook_b.bids, quantity, weighting)?;
+
+        self.calculate_from_prices(bid_a, ask_a, bid_b, ask_b)
+    }
+
     // =========================================================================
     // Raw Price Calculation (used by AtomicBestPrices hot path)
     // =========================================================================
@@ -183,6 +249,8 @@ impl SpreadCalculator {
     ///
     /// Same logic as `calculate()` but takes 4 `f64` values directly
     /// from `AtomicBestPrices`, avoiding `Orderbook` construction/cloning.
+    /// On the `Mid` basis both legs are priced at their mid, so `ask_price`
+    /// and `bid_price` are the buy and sell legs' mids.
     ///
     /// Returns `None` if any price is 0.0 (uninitialized atomic data).
     #[inline]
@@ -198,32 +266,30 @@ impl SpreadCalculator {
         if ask_a <= 0.0 || bid_a <= 0.0 || ask_b <= 0.0 || bid_b <= 0.0 {
             return None;
         }
-
-        let spread_a_to_b = ((bid_b - ask_a) / ask_a) * 100.0;
-        let spread_b_to_a = ((bid_a - ask_b) / ask_b) * 100.0;
-
-        let timestamp_ms = current_time_ms();
         let midpoint = (ask_a + bid_b + ask_b + bid_a) / 4.0;
-
-        if spread_a_to_b >= spread_b_to_a {
-            Some(SpreadResult {
-                spread_pct: spread_a_to_b,
-                direction: SpreadDirection::AOverB,
-                ask_price: ask_a,
-                bid_price: bid_b,
-                midpoint,
-                timestamp_ms,
-            })
-        } else {
-            Some(SpreadResult {
-                spread_pct: spread_b_to_a,
-                direction: SpreadDirection::BOverA,
-                ask_price: ask_b,
-                bid_price: bid_a,
-                midpoint,
-                timestamp_ms,
-            })
-        }
+        let (bid_a, ask_a, bid_b, ask_b) = match self.basis {
+            SpreadBasis::Crossing => (bid_a, ask_a, bid_b, ask_b),
+            SpreadBasis::Mid => {
+                let (mid_a, mid_b) = ((bid_a + ask_a) / 2.0, (bid_b + ask_b) / 2.0);
+                (mid_a, mid_a, mid_b, mid_b)
+            }
+        };
+
+        let (direction, spread_pct) = Self::best_direction_from_prices(bid_a, ask_a, bid_b, ask_b);
+        // Buy at the ask on one leg, sell at the bid on the other
+        let (ask_price, bid_price) = match direction {
+            SpreadDirection::AOverB => (ask_a, bid_b),
+            SpreadDirection::BOverA => (ask_b, bid_a),
+        };
+
+        Some(SpreadResult {
+            spread_pct,
+            direction,
+            ask_price,
+            bid_price,
+            midpoint,
+            timestamp_ms: current_time_ms(),
+        })
     }
 
     // =========================================================================
@@ -292,26 +358,236 @@ impl SpreadCalculator {
         let ask_b = orderbook_b.best_ask()?;
         let bid_b = orderbook_b.best_bid()?;
 
-        // A→B: Buy A (ask_a), Sell B (bid_b)  →  Exit: Sell A (bid_a), Buy B (ask_b)
-        let entry_a_to_b = Self::calculate_entry_spread(ask_a, bid_b);
-        let exit_a_to_b = Self::calculate_exit_spread(bid_a, ask_b);
+        Some(Self::dual_spreads_from_prices(bid_a, ask_a, bid_b, ask_b))
+    }
 
-        // B→A: Buy B (ask_b), Sell A (bid_a)  →  Exit: Sell B (bid_b), Buy A (ask_a)
-        let entry_b_to_a = Self::calculate_entry_spread(ask_b, bid_a);
-        let exit_b_to_a = Self::calculate_exit_spread(bid_b, ask_a);
+    /// Like `calculate_dual_spreads()`, with every leg priced at the VWAP of
+    /// filling `quantity` on the side of the book it consumes
+    ///
+    /// Returns `None` if either book lacks the depth to fill `quantity`.
+    #[must_use]
+    pub fn calculate_dual_spreads_with_depth(
+        &self,
+        orderbook_a: &Orderbook,
+        orderbook_b: &Orderbook,
+        quantity: f64,
+    ) -> Option<(f64, f64)> {
+        let weighting = self.vwap_weighting;
+        let ask_a = weighted_vwap_fill_price(&orderbook_a.asks, quantity, weighting)?;
+        let bid_a = weighted_vwap_fill_price(&orderbook_a.bids, quantity, weighting)?;
+        let ask_b = weighted_vwap_fill_price(&orderbook_b.asks, quantity, weighting)?;
+        let bid_b = weighted_vwap_fill_price(&orderbook_b.bids, quantity, weighting)?;
 
-        // Return the direction with the best entry, plus its matching exit
-        if entry_a_to_b >= entry_b_to_a {
-            Some((entry_a_to_b, exit_a_to_b))
-        } else {
-            Some((entry_b_to_a, exit_b_to_a))
-        }
+        Some(Self::dual_spreads_from_prices(bid_a, ask_a, bid_b, ask_b))
+    }
+
+    /// Best entry spread over both directions, with its matching exit spread
+    #[inline]
+    fn dual_spreads_from_prices(bid_a: f64, ask_a: f64, bid_b: f64, ask_b: f64) -> (f64, f64) {
+        let (direction, entry) = Self::best_direction_from_prices(bid_a, ask_a, bid_b, ask_b);
+        let exit = match direction {
+            // A→B entry exits by selling A (bid_a) and buying B (ask_b)
+            SpreadDirection::AOverB => Self::calculate_exit_spread(bid_a, ask_b),
+            // B→A entry exits by selling B (bid_b) and buying A (ask_a)
+            SpreadDirection::BOverA => Self::calculate_exit_spread(bid_b, ask_a),
+        };
+        (entry, exit)
     }
 }
 
 // =============================================================================
 // Helper Functions
 // =============================================================================
+
+// =============================================================================
+// EMA Smoothing
+// =============================================================================
+
+/// Exponential moving average of a spread series
+///
+/// `smoothed = alpha * raw + (1 - alpha) * smoothed`; the first sample
+/// initializes the average to the raw value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpreadEma {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl SpreadEma {
+    /// Create an EMA; `alpha` must be in `(0, 1]`
+    pub fn new(alpha: f64) -> Result<Self, AppError> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Err(AppError::Config(format!(
+                "EMA alpha must be in (0, 1] (got {})",
+                alpha
+            )));
+        }
+        Ok(Self { alpha, value: None })
+    }
+
+    /// Feed a raw sample and return the smoothed value
+    #[inline]
+    pub fn update(&mut self, raw: f64) -> f64 {
+        let next = match self.value {
+            Some(prev) => self.alpha * raw + (1.0 - self.alpha) * prev,
+            None => raw,
+        };
+        self.value = Some(next);
+        next
+    }
+
+    /// Current smoothed value (None before the first sample)
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+}
+
+/// How much of each level's volume a VWAP fill may count on
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum VwapWeighting {
+    /// Every level's full quantity is available (plain VWAP)
+    #[default]
+    VolumeProportional,
+    /// Level `i` (0 = best) only counts `quantity · factor^i`, with
+    /// `factor` in `(0, 1]`
+    ///
+    /// Deeper liquidity is less likely to still be there at execution, so
+    /// the fill walks further into the book: the VWAP of a buy rises and of
+    /// a sell falls. `factor = 1` is the same as `VolumeProportional`.
+    DepthDecay { factor: f64 },
+}
+
+impl VwapWeighting {
+    /// Reject a decay factor outside `(0, 1]`
+    pub fn validate(&self) -> Result<(), AppError> {
+        match *self {
+            VwapWeighting::VolumeProportional => Ok(()),
+            VwapWeighting::DepthDecay { factor } if factor > 0.0 && factor <= 1.0 => Ok(()),
+            VwapWeighting::DepthDecay { factor } => Err(AppError::Config(format!(
+                "VWAP depth decay factor must be in (0, 1] (got {})",
+                factor
+            ))),
+        }
+    }
+}
+
+/// Volume-weighted average price of filling `quantity` against `levels`
+///
+/// `levels` must be ordered best-first (asks ascending, bids descending),
+/// i.e. the side of the book the trade consumes.
+///
+/// Returns `None` if `quantity` is not positive or the levels don't hold
+/// enough volume to fill it.
+pub fn vwap_fill_price(levels: &[OrderbookLevel], quantity: f64) -> Option<f64> {
+    weighted_vwap_fill_price(levels, quantity, VwapWeighting::VolumeProportional)
+}
+
+/// `vwap_fill_price()` counting each level's volume per `weighting`
+///
+/// `VWAP = Σ takeᵢ·priceᵢ / quantity`, taking `takeᵢ ≤ wᵢ·quantityᵢ` from
+/// each level best-first until `quantity` is filled, where `wᵢ = 1` for
+/// `VolumeProportional` and `factor^i` for `DepthDecay`. Returns `None` if
+/// the weighted depth cannot fill `quantity`.
+pub fn weighted_vwap_fill_price(
+    levels: &[OrderbookLevel],
+    quantity: f64,
+    weighting: VwapWeighting,
+) -> Option<f64> {
+    if quantity <= 0.0 {
+        return None;
+    }
+    let decay = match weighting {
+        VwapWeighting::VolumeProportional => None,
+        VwapWeighting::DepthDecay { factor } => Some(factor),
+    };
+
+    let mut remaining = quantity;
+    let mut notional = 0.0;
+    // Stays exactly 1.0 without decay, so plain VWAP is unchanged
+    let mut weight = 1.0;
+    for level in levels {
+        let take = remaining.min(level.quantity * weight);
+        notional += take * level.price;
+        remaining -= take;
+        if remaining <= f64::EPSILON {
+            return Some(notional / quantity);
+        }
+        if let Some(factor) = decay {
+            weight *= factor;
+        }
+    }
+    None
+}
+
+/// Book levels per side counted in the imbalance signal
+pub const IMBALANCE_LEVELS: usize = 5;
+
+/// Order-flow imbalance over the top `levels` of each side of `orderbook`
+///
+/// `(bid_vol - ask_vol) / (bid_vol + ask_vol)`, in [-1, 1]: positive when
+/// bids outweigh asks (buy pressure), negative when asks do. Returns 0.0 for
+/// an empty book.
+pub fn book_imbalance(orderbook: &Orderbook, levels: usize) -> f64 {
+    let bid_vol: f64 = orderbook.bids.iter().take(levels).map(|l| l.quantity).sum();
+    let ask_vol: f64 = orderbook.asks.iter().take(levels).map(|l| l.quantity).sum();
+    let total = bid_vol + ask_vol;
+    if total <= 0.0 {
+        return 0.0;
+    }
+    (bid_vol - ask_vol) / total
+}
+
+/// Side of the book a fill consumes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSide {
+    /// Selling into the bids; prices get worse downwards
+    Bid,
+    /// Buying from the asks; prices get worse upwards
+    Ask,
+}
+
+/// Largest quantity fillable against `levels` (the `side` of a book) with
+/// a VWAP no worse than `max_slippage_pct` away from the best level
+///
+/// `levels` must be ordered best-first, as for `vwap_fill_price()`. The last
+/// level is taken partially when only part of it fits the budget. Returns
+/// 0.0 for an empty book.
+pub fn max_fill_within_slippage(levels: &[OrderbookLevel], side: BookSide, max_slippage_pct: f64) -> f64 {
+    let Some(best) = levels.first().map(|l| l.price) else {
+        return 0.0;
+    };
+    let worse_sign = match side {
+        BookSide::Bid => -1.0,
+        BookSide::Ask => 1.0,
+    };
+    let limit = best * (1.0 + worse_sign * max_slippage_pct.max(0.0) / 100.0);
+
+    let mut filled = 0.0;
+    let mut notional = 0.0;
+    for level in levels {
+        if (level.price - limit) * worse_sign <= 0.0 {
+            filled += level.quantity;
+            notional += level.quantity * level.price;
+            continue;
+        }
+        // Partial take x such that (notional + x·p) / (filled + x) = limit
+        let partial = (limit * filled - notional) / (level.price - limit);
+        filled += partial.clamp(0.0, level.quantity);
+        break;
+    }
+    filled
+}
+
+/// Largest size both legs can fill with each leg's VWAP within
+/// `max_slippage_pct` of its best price: `buy`'s asks and `sell`'s bids
+///
+/// The thinner (binding) leg sets the size, so both legs trade the same
+/// quantity and stay delta-neutral.
+pub fn max_size_within_slippage(buy: &Orderbook, sell: &Orderbook, max_slippage_pct: f64) -> f64 {
+    max_fill_within_slippage(&buy.asks, BookSide::Ask, max_slippage_pct)
+        .min(max_fill_within_slippage(&sell.bids, BookSide::Bid, max_slippage_pct))
+}
+
 /// Current unix timestamp in milliseconds
 fn current_time_ms() -> u64 {
     std::time::SystemTime::now()
@@ -329,14 +605,6 @@ mod tests {
     use super::*;
     use crate::adapters::types::OrderbookLevel;
 
-    /// Helper to create an orderbook with specific best ask/bid
-    fn make_orderbook(best_ask: f64, best_bid: f64) -> Orderbook {
-        let mut ob = Orderbook::new();
-        ob.asks.push(OrderbookLevel::new(best_ask, 1.0));
-        ob.bids.push(OrderbookLevel::new(best_bid, 1.0));
-        ob
-    }
-
     // =========================================================================
     // Task 6.1: Test spread calculation with known values
     // =========================================================================
@@ -344,8 +612,8 @@ mod tests {
     #[test]
     fn test_spread_calculation_basic() {
         let calc = SpreadCalculator::new("vest", "paradex");
-        let ob_a = make_orderbook(100.0, 99.0); // Ask=100, Bid=99
-        let ob_b = make_orderbook(99.5, 98.5); // Ask=99.5, Bid=98.5
+        let ob_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]); // Ask=100, Bid=99
+        let ob_b = Orderbook::from_levels(&[(98.5, 1.0)], &[(99.5, 1.0)]); // Ask=99.5, Bid=98.5
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -368,8 +636,8 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         // Test with orderbooks where one direction has positive spread
         // A: ask=98, bid=97  |  B: ask=99, bid=101 (B's bid > A's ask)
-        let ob_a = make_orderbook(98.0, 97.0);
-        let ob_b = make_orderbook(99.0, 101.0);
+        let ob_a = Orderbook::from_levels(&[(97.0, 1.0)], &[(98.0, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(101.0, 1.0)], &[(99.0, 1.0)]);
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -390,8 +658,8 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         // A→B: Buy on A, Sell on B. Need bid_B > ask_A for profit
         // A: ask=98, bid=97  |  B: ask=99, bid=102 (bid_B > ask_A)
-        let ob_a = make_orderbook(98.0, 97.0);
-        let ob_b = make_orderbook(99.0, 102.0);
+        let ob_a = Orderbook::from_levels(&[(97.0, 1.0)], &[(98.0, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(102.0, 1.0)], &[(99.0, 1.0)]);
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -411,8 +679,8 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         // B→A: Buy on B, Sell on A. Need bid_A > ask_B for profit
         // A: ask=99, bid=102  |  B: ask=98, bid=97 (bid_A > ask_B)
-        let ob_a = make_orderbook(99.0, 102.0);
-        let ob_b = make_orderbook(98.0, 97.0);
+        let ob_a = Orderbook::from_levels(&[(102.0, 1.0)], &[(99.0, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(97.0, 1.0)], &[(98.0, 1.0)]);
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -437,7 +705,7 @@ mod tests {
     fn test_spread_empty_orderbook_a_returns_none() {
         let calc = SpreadCalculator::new("vest", "paradex");
         let empty = Orderbook::new();
-        let full = make_orderbook(100.0, 99.0);
+        let full = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
 
         assert!(calc.calculate(&empty, &full).is_none());
     }
@@ -446,7 +714,7 @@ mod tests {
     fn test_spread_empty_orderbook_b_returns_none() {
         let calc = SpreadCalculator::new("vest", "paradex");
         let empty = Orderbook::new();
-        let full = make_orderbook(100.0, 99.0);
+        let full = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
 
         assert!(calc.calculate(&full, &empty).is_none());
     }
@@ -465,7 +733,7 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         let mut ob_a = Orderbook::new();
         ob_a.bids.push(OrderbookLevel::new(99.0, 1.0)); // Only bids, no asks
-        let full = make_orderbook(100.0, 99.0);
+        let full = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
 
         assert!(calc.calculate(&ob_a, &full).is_none());
     }
@@ -475,7 +743,7 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         let mut ob_a = Orderbook::new();
         ob_a.asks.push(OrderbookLevel::new(100.0, 1.0)); // Only asks, no bids
-        let full = make_orderbook(100.0, 99.0);
+        let full = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
 
         assert!(calc.calculate(&ob_a, &full).is_none());
     }
@@ -489,8 +757,8 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         // One direction has less negative spread
         // A: ask=100, bid=99  |  B: ask=101, bid=98
-        let ob_a = make_orderbook(100.0, 99.0);
-        let ob_b = make_orderbook(101.0, 98.0);
+        let ob_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(98.0, 1.0)], &[(101.0, 1.0)]);
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -511,8 +779,8 @@ mod tests {
         let calc = SpreadCalculator::new("vest", "paradex");
         // Both directions have negative spread (normal market, no arbitrage)
         // A: ask=100, bid=99  |  B: ask=101, bid=98
-        let ob_a = make_orderbook(100.0, 99.0);
-        let ob_b = make_orderbook(101.0, 98.0);
+        let ob_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(98.0, 1.0)], &[(101.0, 1.0)]);
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -534,8 +802,8 @@ mod tests {
     #[test]
     fn test_spread_calculation_performance_100k() {
         let calc = SpreadCalculator::new("vest", "paradex");
-        let ob_a = make_orderbook(42150.50, 42149.00);
-        let ob_b = make_orderbook(42151.00, 42148.50);
+        let ob_a = Orderbook::from_levels(&[(42149.00, 1.0)], &[(42150.50, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(42148.50, 1.0)], &[(42151.00, 1.0)]);
 
         let start = std::time::Instant::now();
         for _ in 0..100_000 {
@@ -558,8 +826,8 @@ mod tests {
     #[test]
     fn test_spread_result_timestamp_is_set() {
         let calc = SpreadCalculator::new("vest", "paradex");
-        let ob_a = make_orderbook(100.0, 99.0);
-        let ob_b = make_orderbook(99.5, 98.5);
+        let ob_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
+        let ob_b = Orderbook::from_levels(&[(98.5, 1.0)], &[(99.5, 1.0)]);
 
         let result = calc.calculate(&ob_a, &ob_b).unwrap();
 
@@ -673,8 +941,8 @@ mod tests {
         // Create orderbooks where BID_B > ASK_A for profitable entry
         // DEX A: Ask=99, Bid=98 (we buy at Ask=99)
         // DEX B: Ask=101, Bid=100 (we sell at Bid=100)
-        let ob_a = make_orderbook(99.0, 98.0); // ask, bid
-        let ob_b = make_orderbook(101.0, 100.0); // ask, bid
+        let ob_a = Orderbook::from_levels(&[(98.0, 1.0)], &[(99.0, 1.0)]); // bid, ask
+        let ob_b = Orderbook::from_levels(&[(100.0, 1.0)], &[(101.0, 1.0)]); // bid, ask
 
         let (entry, exit) = calc.calculate_dual_spreads(&ob_a, &ob_b).unwrap();
 
@@ -704,7 +972,7 @@ mod tests {
     fn test_calculate_dual_spreads_empty_orderbook() {
         let calc = SpreadCalculator::new("vest", "paradex");
         let empty = Orderbook::new();
-        let full = make_orderbook(100.0, 99.0);
+        let full = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]);
 
         assert!(calc.calculate_dual_spreads(&empty, &full).is_none());
         assert!(calc.calculate_dual_spreads(&full, &empty).is_none());
@@ -719,8 +987,8 @@ mod tests {
         // Scenario: Vest ask < Paradex bid = arbitrage opportunity
         // Vest: Ask=42145.00, Bid=42140.00 (we buy at Ask=42145)
         // Paradex: Ask=42160.00, Bid=42155.00 (we sell at Bid=42155)
-        let ob_vest = make_orderbook(42145.00, 42140.00);
-        let ob_paradex = make_orderbook(42160.00, 42155.00);
+        let ob_vest = Orderbook::from_levels(&[(42140.00, 1.0)], &[(42145.00, 1.0)]);
+        let ob_paradex = Orderbook::from_levels(&[(42155.00, 1.0)], &[(42160.00, 1.0)]);
 
         let (entry, exit) = calc.calculate_dual_spreads(&ob_vest, &ob_paradex).unwrap();
 
@@ -808,6 +1076,270 @@ mod tests {
         }
     }
 
+    // =========================================================================
+    // Depth-aware (side-correct) spread
+    // =========================================================================
+
+    #[test]
+    fn test_vwap_fill_price_walks_levels() {
+        let levels = vec![OrderbookLevel::new(100.0, 0.5), OrderbookLevel::new(101.0, 0.5)];
+        let vwap = vwap_fill_price(&levels, 1.0).unwrap();
+        assert!((vwap - 100.5).abs() < 1e-9);
+        // Partial first level only
+        assert_eq!(vwap_fill_price(&levels, 0.25), Some(100.0));
+    }
+
+    #[test]
+    fn test_depth_decay_worsens_vwap() {
+        let asks = vec![
+            OrderbookLevel::new(100.0, 1.0),
+            OrderbookLevel::new(101.0, 1.0),
+            OrderbookLevel::new(102.0, 1.0),
+        ];
+        let bids = vec![
+            OrderbookLevel::new(99.0, 1.0),
+            OrderbookLevel::new(98.0, 1.0),
+            OrderbookLevel::new(97.0, 1.0),
+        ];
+        let plain = VwapWeighting::VolumeProportional;
+        let decay = VwapWeighting::DepthDecay { factor: 0.5 };
+
+        // Default matches the unweighted VWAP exactly
+        let plain_buy = weighted_vwap_fill_price(&asks, 1.6, plain).unwrap();
+        assert_eq!(Some(plain_buy), vwap_fill_price(&asks, 1.6));
+        assert!((plain_buy - (100.0 + 0.6 * 101.0) / 1.6).abs() < 1e-9);
+
+        // Decay counts 1.0, 0.5, 0.25: the last 0.1 comes from the third level
+        let decayed_buy = weighted_vwap_fill_price(&asks, 1.6, decay).unwrap();
+        assert!((decayed_buy - (100.0 + 0.5 * 101.0 + 0.1 * 102.0) / 1.6).abs() < 1e-9);
+        assert!(decayed_buy > plain_buy, "{decayed_buy} <= {plain_buy}");
+
+        let plain_sell = weighted_vwap_fill_price(&bids, 1.6, plain).unwrap();
+        let decayed_sell = weighted_vwap_fill_price(&bids, 1.6, decay).unwrap();
+        assert!(decayed_sell < plain_sell);
+
+        // Decayed depth is 1.75, so 2.0 cannot be filled
+        assert!(weighted_vwap_fill_price(&asks, 2.0, decay).is_none());
+        assert!(weighted_vwap_fill_price(&asks, 2.0, plain).is_some());
+
+        assert!(VwapWeighting::DepthDecay { factor: 0.0 }.validate().is_err());
+        assert!(VwapWeighting::DepthDecay { factor: 1.5 }.validate().is_err());
+        let calc = SpreadCalculator::new("vest", "paradex").with_vwap_weighting(decay).unwrap();
+        assert_eq!(calc.vwap_weighting, decay);
+    }
+
+    #[test]
+    fn test_dual_spreads_with_depth_uses_vwap_prices() {
+        let calc = SpreadCalculator::new("vest", "paradex");
+        // A: asks 100 x0.5, 102 x0.5 (VWAP 101); bids 99 x1
+        let book_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 0.5), (102.0, 0.5)]);
+        // B: bids 104 x0.5, 102 x0.5 (VWAP 103); asks 105 x1
+        let book_b = Orderbook::from_levels(&[(104.0, 0.5), (102.0, 0.5)], &[(105.0, 1.0)]);
+
+        let (entry, exit) = calc.calculate_dual_spreads_with_depth(&book_a, &book_b, 1.0).unwrap();
+        assert!((entry - (103.0 - 101.0) / 101.0 * 100.0).abs() < 1e-9);
+        assert!((exit - (99.0 - 105.0) / 105.0 * 100.0).abs() < 1e-9);
+
+        assert!(calc.calculate_dual_spreads_with_depth(&book_a, &book_b, 2.0).is_none());
+    }
+
+    #[test]
+    fn test_binding_leg_sets_size_per_direction() {
+        // A: deep asks, thin bids
+        let book_a = Orderbook::from_levels(&[(99.0, 0.2), (90.0, 10.0)], &[(100.0, 10.0)]);
+        // B: thin asks, deep bids
+        let book_b = Orderbook::from_levels(&[(100.5, 10.0)], &[(101.0, 0.3), (110.0, 10.0)]);
+
+        // A→B: buy A asks (10) / sell B bids (10) → both deep
+        let a_to_b = max_size_within_slippage(&book_a, &book_b, 0.1);
+        assert!((a_to_b - 10.0).abs() < 1e-9);
+
+        // B→A: buy B asks (~0.3) / sell A bids (~0.2) → A's bids bind
+        let b_to_a = max_size_within_slippage(&book_b, &book_a, 0.1);
+        assert_eq!(b_to_a, max_fill_within_slippage(&book_a.bids, BookSide::Bid, 0.1));
+        assert!(b_to_a > 0.2 && b_to_a < 0.21);
+        assert!(b_to_a < max_fill_within_slippage(&book_b.asks, BookSide::Ask, 0.1));
+    }
+
+    #[test]
+    fn test_max_fill_takes_partial_level_at_budget() {
+        // 1 @ 100, then 102: 1% budget allows VWAP up to 101 → 1 more unit
+        let asks = vec![OrderbookLevel::new(100.0, 1.0), OrderbookLevel::new(102.0, 5.0)];
+        let filled = max_fill_within_slippage(&asks, BookSide::Ask, 1.0);
+        assert!((filled - 2.0).abs() < 1e-9);
+        assert!((vwap_fill_price(&asks, filled).unwrap() - 101.0).abs() < 1e-9);
+
+        // Bids get worse downwards: 1 @ 100, then 98
+        let bids = vec![OrderbookLevel::new(100.0, 1.0), OrderbookLevel::new(98.0, 5.0)];
+        assert!((max_fill_within_slippage(&bids, BookSide::Bid, 1.0) - 2.0).abs() < 1e-9);
+
+        // Two levels at the same price don't tell the side apart; 98 is
+        // still past the bid budget
+        let bids = vec![
+            OrderbookLevel::new(100.0, 0.5),
+            OrderbookLevel::new(100.0, 0.5),
+            OrderbookLevel::new(98.0, 5.0),
+        ];
+        assert!((max_fill_within_slippage(&bids, BookSide::Bid, 1.0) - 2.0).abs() < 1e-9);
+
+        assert_eq!(max_fill_within_slippage(&[], BookSide::Ask, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_best_direction() {
+        let calc = SpreadCalculator::new("vest", "paradex");
+
+        // A cheap, B rich → buy A, sell B
+        let a = Orderbook::from_levels(&[(100.0, 1.0)], &[(100.5, 1.0)]);
+        let b = Orderbook::from_levels(&[(101.0, 1.0)], &[(101.5, 1.0)]);
+        let (direction, spread) = calc.best_direction(&a, &b).unwrap();
+        assert_eq!(direction, SpreadDirection::AOverB);
+        assert!((spread - 0.5 / 100.5 * 100.0).abs() < 1e-9);
+
+        // Swapped books → buy B, sell A
+        let (direction, spread) = calc.best_direction(&b, &a).unwrap();
+        assert_eq!(direction, SpreadDirection::BOverA);
+        assert!(spread > 0.0);
+
+        // Both negative: the less-negative direction is still reported
+        let a = Orderbook::from_levels(&[(100.0, 1.0)], &[(101.0, 1.0)]);
+        let b = Orderbook::from_levels(&[(100.2, 1.0)], &[(101.4, 1.0)]);
+        let (direction, spread) = calc.best_direction(&a, &b).unwrap();
+        assert_eq!(direction, SpreadDirection::AOverB);
+        assert!((spread - (100.2 - 101.0) / 101.0 * 100.0).abs() < 1e-9);
+        assert!(spread < 0.0);
+        assert!(spread > SpreadCalculator::calculate_entry_spread(101.4, 100.0));
+
+        assert!(calc.best_direction(&Orderbook::new(), &b).is_none());
+    }
+
+    #[test]
+    fn test_mid_basis_ignores_each_books_own_spread() {
+        let ob_a = Orderbook::from_levels(&[(99.0, 1.0)], &[(100.0, 1.0)]); // mid 99.5
+        let ob_b = Orderbook::from_levels(&[(100.8, 1.0)], &[(101.2, 1.0)]); // mid 101.0
+
+        let crossing = SpreadCalculator::new("vest", "paradex").calculate(&ob_a, &ob_b).unwrap();
+        let mid = SpreadCalculator::new("vest", "paradex")
+            .with_basis(SpreadBasis::Mid)
+            .calculate(&ob_a, &ob_b)
+            .unwrap();
+
+        // Crossing: (100.8 - 100) / 100; mid: (101 - 99.5) / 99.5
+        assert_eq!(crossing.direction, SpreadDirection::AOverB);
+        assert!((crossing.spread_pct - 0.8).abs() < 1e-9);
+        assert_eq!(mid.direction, SpreadDirection::AOverB);
+        assert!((mid.spread_pct - 1.5 / 99.5 * 100.0).abs() < 1e-9);
+        assert_eq!((mid.ask_price, mid.bid_price), (99.5, 101.0));
+        assert_eq!(mid.midpoint, crossing.midpoint);
+
+        // Swapping the legs flips the direction, not the spread
+        let reversed = SpreadCalculator::new("vest", "paradex")
+            .with_basis(SpreadBasis::Mid)
+            .calculate(&ob_b, &ob_a)
+            .unwrap();
+        assert_eq!(reversed.direction, SpreadDirection::BOverA);
+        assert!((reversed.spread_pct - mid.spread_pct).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_book_imbalance() {
+        let balanced = Orderbook::from_levels(&[(100.0, 1.0), (99.0, 1.0)], &[(101.0, 2.0)]);
+        assert_eq!(book_imbalance(&balanced, 5), 0.0);
+
+        let bid_heavy = Orderbook::from_levels(&[(100.0, 3.0)], &[(101.0, 1.0)]);
+        assert!((book_imbalance(&bid_heavy, 5) - 0.5).abs() < 1e-12);
+
+        // Only the top level counts: 1 bid vs 3 asks
+        let ask_heavy = Orderbook::from_levels(&[(100.0, 1.0), (99.0, 50.0)], &[(101.0, 3.0), (102.0, 50.0)]);
+        assert!((book_imbalance(&ask_heavy, 1) + 0.5).abs() < 1e-12);
+
+        assert_eq!(book_imbalance(&Orderbook::new(), 5), 0.0);
+    }
+
+    #[test]
+    fn test_vwap_fill_price_insufficient_depth() {
+        let levels = vec![OrderbookLevel::new(100.0, 0.5)];
+        assert!(vwap_fill_price(&levels, 1.0).is_none());
+        assert!(vwap_fill_price(&levels, 0.0).is_none());
+        assert!(vwap_fill_price(&[], 1.0).is_none());
+    }
+
+    #[test]
+    fn test_depth_spread_is_side_correct() {
+        let calc = SpreadCalculator::new("vest", "paradex");
+        // A: thin asks, B: thin bids — the A→B trade eats into both
+        let ob_a = Orderbook::from_levels(&[(99.0, 2.0)], &[(100.0, 0.5), (101.0, 0.5)]);
+        let ob_b = Orderbook::from_levels(&[(103.0, 0.5), (100.0, 0.5)], &[(102.0, 2.0)]);
+
+        let result = calc.calculate_with_depth(&ob_a, &ob_b, 1.0).unwrap();
+        assert_eq!(result.direction, SpreadDirection::AOverB);
+        assert!((result.ask_price - 100.5).abs() < 1e-9); // A's asks (we lift)
+        assert!((result.bid_price - 101.5).abs() < 1e-9); // B's bids (we hit)
+        let expected = (101.5 - 100.5) / 100.5 * 100.0;
+        assert!((result.spread_pct - expected).abs() < 1e-9);
+
+        // Naive same-side computation (B's asks for the sell leg) overstates it
+        let naive_sell = vwap_fill_price(&ob_b.asks, 1.0).unwrap();
+        let naive = SpreadCalculator::calculate_entry_spread(100.5, naive_sell);
+        assert!(naive > result.spread_pct + 0.1);
+    }
+
+    #[test]
+    fn test_depth_quantity_config_switches_calculate() {
+        let ob_a = Orderbook::from_levels(&[(99.0, 2.0)], &[(100.0, 0.5), (101.0, 0.5)]);
+        let ob_b = Orderbook::from_levels(&[(103.0, 0.5), (100.0, 0.5)], &[(102.0, 2.0)]);
+
+        let top = SpreadCalculator::new("vest", "paradex");
+        let deep = SpreadCalculator::new("vest", "paradex").with_depth_quantity(1.0);
+
+        let top_result = top.calculate(&ob_a, &ob_b).unwrap();
+        let deep_result = deep.calculate(&ob_a, &ob_b).unwrap();
+        assert!((top_result.spread_pct - 3.0).abs() < 1e-9);
+        assert!(deep_result.spread_pct < top_result.spread_pct);
+
+        // Not enough depth for the configured size → no result
+        let too_big = SpreadCalculator::new("vest", "paradex").with_depth_quantity(5.0);
+        assert!(too_big.calculate(&ob_a, &ob_b).is_none());
+    }
+
+    #[test]
+    fn test_ema_first_sample_initializes_and_converges() {
+        let mut ema = SpreadEma::new(0.5).unwrap();
+        assert_eq!(ema.value(), None);
+        assert_eq!(ema.update(0.40), 0.40);
+        assert!((ema.update(0.20) - 0.30).abs() < 1e-12);
+
+        // A sustained level pulls the average toward it geometrically
+        let mut last = 0.0;
+        for _ in 0..50 {
+            last = ema.update(0.10);
+        }
+        assert!((last - 0.10).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ema_damps_single_tick_flicker() {
+        let mut ema = SpreadEma::new(0.2).unwrap();
+        for _ in 0..20 {
+            ema.update(0.05);
+        }
+        // One noisy tick above a 0.10 threshold barely moves the average
+        assert!(ema.update(0.25) < 0.10);
+    }
+
+    #[test]
+    fn test_ema_alpha_validation() {
+        assert!(SpreadEma::new(1.0).is_ok());
+        for alpha in [0.0, -0.1, 1.5, f64::NAN] {
+            let err = SpreadEma::new(alpha).err();
+            assert!(
+                err.is_some_and(|e| e.to_string().contains("alpha")),
+                "alpha {} should be rejected",
+                alpha
+            );
+        }
+    }
+
     // =========================================================================
     // Property-based tests (proptest)
     // =========================================================================
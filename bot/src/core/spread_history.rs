@@ -0,0 +1,131 @@
+//! Fixed-size spread history for ranking the current spread.
+//!
+//! Pushes are O(1) (the oldest sample is evicted past capacity). Percentiles
+//! copy and sort the window on demand, which is cheap at the sizes used
+//! here and keeps the hot path free of any ordering work.
+
+use std::collections::VecDeque;
+
+/// Default number of spreads kept per symbol
+pub const DEFAULT_SPREAD_HISTORY_CAPACITY: usize = 1000;
+
+/// Ring buffer of the most recent spread samples (percent).
+#[derive(Debug, Clone)]
+pub struct SpreadHistory {
+    samples: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl SpreadHistory {
+    /// Create an empty history holding at most `capacity` samples (min 1).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Record a spread, evicting the oldest sample when full.
+    pub fn push(&mut self, spread: f64) {
+        if !spread.is_finite() {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(spread);
+    }
+
+    /// Number of samples currently held
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether no samples have been recorded yet
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Most recently pushed spread
+    pub fn latest(&self) -> Option<f64> {
+        self.samples.back().copied()
+    }
+
+    /// Spread at percentile `p` (0–100, nearest-rank).
+    ///
+    /// Returns `None` when empty or `p` is out of range.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if self.samples.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let sorted = self.sorted();
+        let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.saturating_sub(1).min(sorted.len() - 1)])
+    }
+
+    /// Percentage of samples at or below `spread` (0–100).
+    pub fn percentile_rank(&self, spread: f64) -> Option<f64> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let at_or_below = self.samples.iter().filter(|&&s| s <= spread).count();
+        Some(at_or_below as f64 / self.samples.len() as f64 * 100.0)
+    }
+
+    fn sorted(&self) -> Vec<f64> {
+        let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        sorted
+    }
+}
+
+impl Default for SpreadHistory {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_SPREAD_HISTORY_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles_of_known_distribution() {
+        let mut history = SpreadHistory::with_capacity(100);
+        // Push 1..=100 out of order
+        for i in (1..=100).rev() {
+            history.push(i as f64 / 100.0);
+        }
+
+        assert_eq!(history.percentile(0.0), Some(0.01));
+        assert_eq!(history.percentile(50.0), Some(0.50));
+        assert_eq!(history.percentile(90.0), Some(0.90));
+        assert_eq!(history.percentile(100.0), Some(1.00));
+        assert_eq!(history.percentile(101.0), None);
+        assert_eq!(history.percentile_rank(0.25), Some(25.0));
+    }
+
+    #[test]
+    fn test_history_evicts_oldest_past_capacity() {
+        let mut history = SpreadHistory::with_capacity(3);
+        for spread in [0.1, 0.2, 0.3, 0.4, 0.5] {
+            history.push(spread);
+        }
+
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.latest(), Some(0.5));
+        assert_eq!(history.percentile(0.0), Some(0.3));
+        assert_eq!(history.percentile(100.0), Some(0.5));
+    }
+
+    #[test]
+    fn test_empty_history_and_non_finite_samples() {
+        let mut history = SpreadHistory::with_capacity(10);
+        assert_eq!(history.percentile(50.0), None);
+        assert_eq!(history.percentile_rank(0.1), None);
+
+        history.push(f64::NAN);
+        assert!(history.is_empty());
+    }
+}
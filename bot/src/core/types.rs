@@ -4,8 +4,11 @@
 //! providing a common vocabulary for prices, aggregated views, and opportunities.
 
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
+use crate::config::{AppConfig, SpreadBasis};
+
 // =============================================================================
 // Price Data (from a single exchange)
 // =============================================================================
@@ -24,8 +27,13 @@ pub struct PriceData {
     pub bid: f64,
     /// Best ask price
     pub ask: f64,
-    /// Timestamp in milliseconds (epoch)
+    /// Local receive time in milliseconds (epoch), what freshness is
+    /// measured against
     pub timestamp_ms: u64,
+    /// Exchange's own stamp on the quote, when it sends one. On the
+    /// exchange's clock, so only comparable with other exchange times.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exchange_time_ms: Option<u64>,
 }
 
 impl PriceData {
@@ -69,6 +77,104 @@ pub struct AggregatedPrice {
     pub best_ask: Option<ExchangePrice>,
     /// Timestamp of aggregation
     pub timestamp_ms: u64,
+    /// Newest price timestamp among `prices` (0 when there are none)
+    #[serde(default)]
+    pub last_update_ms: u64,
+}
+
+impl AggregatedPrice {
+    /// Build an aggregated view, picking the highest bid and lowest ask.
+    ///
+    /// On ties the first exchange in `prices` wins.
+    pub fn from_prices(symbol: Arc<str>, prices: Vec<PriceData>, timestamp_ms: u64) -> Self {
+        let mut best_bid: Option<ExchangePrice> = None;
+        let mut best_ask: Option<ExchangePrice> = None;
+
+        for price in &prices {
+            if best_bid.as_ref().is_none_or(|b| price.bid > b.price) {
+                best_bid = Some(ExchangePrice {
+                    exchange: price.exchange.clone(),
+                    price: price.bid,
+                });
+            }
+            if best_ask.as_ref().is_none_or(|a| price.ask < a.price) {
+                best_ask = Some(ExchangePrice {
+                    exchange: price.exchange.clone(),
+                    price: price.ask,
+                });
+            }
+        }
+
+        let last_update_ms = prices.iter().map(|p| p.timestamp_ms).max().unwrap_or(0);
+        Self {
+            symbol,
+            prices,
+            best_bid,
+            best_ask,
+            timestamp_ms,
+            last_update_ms,
+        }
+    }
+
+    /// Cross-exchange spread in percent: `(best_bid - best_ask) / best_ask * 100`.
+    ///
+    /// Negative when the book is not crossed. `None` unless best bid and best
+    /// ask come from different exchanges.
+    pub fn cross_spread_percent(&self) -> Option<f64> {
+        let (bid, ask) = (self.best_bid.as_ref()?, self.best_ask.as_ref()?);
+        if bid.exchange == ask.exchange || ask.price <= 0.0 {
+            return None;
+        }
+        Some((bid.price - ask.price) / ask.price * 100.0)
+    }
+
+    /// Spread between the best-bid and best-ask exchanges on `basis`.
+    ///
+    /// `Crossing` is [`Self::cross_spread_percent`]; `Mid` compares the same
+    /// two exchanges' mid prices instead of their crossing quotes.
+    pub fn spread_percent(&self, basis: SpreadBasis) -> Option<f64> {
+        let cross = self.cross_spread_percent()?;
+        match basis {
+            SpreadBasis::Crossing => Some(cross),
+            SpreadBasis::Mid => {
+                let (bid, ask) = (self.best_bid.as_ref()?, self.best_ask.as_ref()?);
+                let mid = |exchange: &str| {
+                    let price = self.prices.iter().find(|p| &*p.exchange == exchange)?;
+                    Some((price.bid + price.ask) / 2.0)
+                };
+                let (sell_mid, buy_mid) = (mid(&bid.exchange)?, mid(&ask.exchange)?);
+                Some((sell_mid - buy_mid) / buy_mid * 100.0)
+            }
+        }
+    }
+
+    /// Advance `exchange`'s quote time to its book's latest exchange time.
+    ///
+    /// The manager only re-emits a leg when its top of book moves, so a quiet
+    /// but live leg keeps the times of its last price change; its book still
+    /// carries when the exchange last confirmed that quote. A book stamped
+    /// after the emitted quote has arrived since, so the quote counts as
+    /// received by `now_ms`.
+    pub fn refresh_quote_time(&mut self, exchange: &str, exchange_time_ms: u64, now_ms: u64) {
+        let Some(price) = self.prices.iter_mut().find(|p| &*p.exchange == exchange) else {
+            return;
+        };
+        if price.exchange_time_ms.is_some_and(|emitted| exchange_time_ms > emitted) {
+            price.exchange_time_ms = Some(exchange_time_ms);
+            price.timestamp_ms = price.timestamp_ms.max(now_ms);
+            self.last_update_ms = self.last_update_ms.max(price.timestamp_ms);
+        }
+    }
+
+    /// Keep only prices from exchanges matching `keep`, recomputing best bid/ask.
+    pub fn retain_exchanges(self, keep: impl Fn(&str) -> bool) -> Self {
+        let prices = self
+            .prices
+            .into_iter()
+            .filter(|p| keep(&p.exchange))
+            .collect();
+        Self::from_prices(self.symbol, prices, self.timestamp_ms)
+    }
 }
 
 // =============================================================================
@@ -92,8 +198,86 @@ pub struct ArbitrageOpportunity {
     pub sell_price: f64,
     /// Spread as a percentage: (sell - buy) / buy * 100
     pub spread_percent: f64,
+    /// Spread required to emit: minimum spread plus both legs' taker fees (breakeven)
+    #[serde(default)]
+    pub entry_threshold_percent: f64,
+    /// Gap between the two legs' quote timestamps (ms)
+    #[serde(default)]
+    pub quote_skew_ms: u64,
     /// Detection timestamp
     pub timestamp_ms: u64,
+    /// Monitor that detected this opportunity (None for the global detector)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor_id: Option<Arc<str>>,
+    /// Base quantity both legs' books can fill, set by the gate when it
+    /// loaded them: `depth_quantity`, or else the smaller top-of-book size,
+    /// clamped to what both legs fill within `max_slippage_pct`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fillable_quantity: Option<f64>,
+    /// Spread at the VWAP of filling `fillable_quantity` on both legs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill_spread_percent: Option<f64>,
+    /// Order-flow imbalance of the buy leg's book (see `book_imbalance`),
+    /// set by the gate when it loaded both books
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imbalance_buy: Option<f64>,
+    /// Order-flow imbalance of the sell leg's book
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub imbalance_sell: Option<f64>,
+    /// Best-scoring opportunity of its dispatch window across all pairs
+    #[serde(default)]
+    pub selected: bool,
+}
+
+impl ArbitrageOpportunity {
+    /// Expected edge, used to rank competing opportunities.
+    ///
+    /// When the legs' books sized the opportunity, this is the quote-currency
+    /// edge of filling `fillable_quantity`: the VWAP spread left after the
+    /// entry threshold, times the buy notional. Unsized opportunities score
+    /// their per-unit edge in percent instead. Whether a run sizes depends
+    /// only on the depth config, so the two are not mixed.
+    #[inline]
+    pub fn score(&self) -> f64 {
+        let edge_percent = self.fill_spread_percent.unwrap_or(self.spread_percent) - self.entry_threshold_percent;
+        match self.fillable_quantity {
+            Some(quantity) => edge_percent / 100.0 * quantity * self.buy_price,
+            None => edge_percent,
+        }
+    }
+}
+
+/// Furthest a leg price may sit from a reference price, in percent.
+///
+/// Guards against acting on a corrupted or stale book quoting far off market.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PriceBand {
+    pub max_deviation_pct: f64,
+}
+
+impl PriceBand {
+    /// Band configured by `max_price_deviation_pct`, `None` when unset.
+    pub fn from_config(config: &AppConfig) -> Option<Self> {
+        config
+            .max_price_deviation_pct
+            .map(|max_deviation_pct| PriceBand { max_deviation_pct })
+    }
+
+    /// Distance of `price` from `reference` in percent of `reference`
+    #[inline]
+    pub fn deviation_pct(price: f64, reference: f64) -> f64 {
+        ((price - reference) / reference).abs() * 100.0
+    }
+
+    /// Whether `price` lies within the band around `reference`
+    pub fn admits(&self, price: f64, reference: f64) -> bool {
+        reference > 0.0 && Self::deviation_pct(price, reference) <= self.max_deviation_pct
+    }
+}
+
+/// Sort `opportunities` best first by [`ArbitrageOpportunity::score`].
+pub fn rank_opportunities(opportunities: &mut [ArbitrageOpportunity]) {
+    opportunities.sort_by(|a, b| b.score().total_cmp(&a.score()));
 }
 
 // =============================================================================
@@ -101,7 +285,7 @@ pub struct ArbitrageOpportunity {
 // =============================================================================
 
 /// Events broadcast to WebSocket clients.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data")]
 pub enum BroadcastEvent {
     /// New price from an exchange
@@ -118,6 +302,35 @@ pub enum BroadcastEvent {
     },
 }
 
+/// Next [`SequencedEvent::seq`], shared by every task in the process
+static NEXT_EVENT_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// A [`BroadcastEvent`] stamped with a process-wide sequence number.
+///
+/// Timestamps from concurrent tasks collide at ms resolution; `seq` is
+/// unique and increases in creation order, so the WS feed and the event log
+/// can be ordered deterministically. Serializes as the event with a `seq`
+/// field next to `type` and `data`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequencedEvent {
+    pub seq: u64,
+    #[serde(flatten)]
+    pub event: BroadcastEvent,
+}
+
+impl SequencedEvent {
+    /// Stamp `event` with the next sequence number.
+    pub fn new(event: BroadcastEvent) -> Self {
+        Self { seq: NEXT_EVENT_SEQ.fetch_add(1, Ordering::Relaxed), event }
+    }
+}
+
+impl From<BroadcastEvent> for SequencedEvent {
+    fn from(event: BroadcastEvent) -> Self {
+        Self::new(event)
+    }
+}
+
 // =============================================================================
 // Utility
 // =============================================================================
@@ -143,6 +356,7 @@ mod tests {
             bid: 100_000.0,
             ask: 100_010.0,
             timestamp_ms: 0,
+            exchange_time_ms: None,
         };
         // (10 / 100_000) * 10_000 = 1.0 bps
         assert!((price.spread_bps() - 1.0).abs() < 0.001);
@@ -156,6 +370,7 @@ mod tests {
             bid: 0.0,
             ask: 100.0,
             timestamp_ms: 0,
+            exchange_time_ms: None,
         };
         assert_eq!(price.spread_bps(), 0.0);
     }
@@ -168,12 +383,157 @@ mod tests {
             bid: 50000.0,
             ask: 50010.0,
             timestamp_ms: 1700000000000,
+            exchange_time_ms: None,
         });
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("\"type\":\"price\""));
         assert!(json.contains("\"exchange\":\"vest\""));
     }
 
+    #[test]
+    fn test_sequenced_events_are_unique_and_increasing() {
+        let event = || BroadcastEvent::ExchangeStatus { exchange: "vest".to_string(), connected: true };
+        let per_thread: Vec<Vec<u64>> = std::thread::scope(|scope| {
+            let workers: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| (0..250).map(|_| SequencedEvent::new(event()).seq).collect::<Vec<_>>()))
+                .collect();
+            workers.into_iter().map(|w| w.join().unwrap()).collect()
+        });
+
+        for seqs in &per_thread {
+            assert!(seqs.windows(2).all(|w| w[0] < w[1]), "increasing within a task");
+        }
+        let mut all: Vec<u64> = per_thread.concat();
+        all.sort_unstable();
+        all.dedup();
+        assert_eq!(all.len(), 1000, "unique across tasks");
+
+        let json = serde_json::to_value(SequencedEvent::new(event())).unwrap();
+        assert!(json["seq"].as_u64().unwrap() > 0);
+        assert_eq!(json["type"], "exchange_status");
+        let decoded: SequencedEvent = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(decoded.seq, json["seq"].as_u64().unwrap());
+    }
+
+    #[test]
+    fn test_cross_spread_percent() {
+        let price = |exchange: &str, bid: f64, ask: f64| PriceData {
+            exchange: Arc::from(exchange),
+            symbol: Arc::from("BTC"),
+            bid,
+            ask,
+            timestamp_ms: 0,
+            exchange_time_ms: None,
+        };
+        let crossed = AggregatedPrice::from_prices(
+            Arc::from("BTC"),
+            vec![price("vest", 100.0, 100.5), price("paradex", 101.0, 101.5)],
+            0,
+        );
+        // paradex bid 101 vs vest ask 100.5
+        assert!((crossed.cross_spread_percent().unwrap() - 0.4975).abs() < 0.0001);
+
+        let single = AggregatedPrice::from_prices(Arc::from("BTC"), vec![price("vest", 100.0, 100.5)], 0);
+        assert_eq!(single.cross_spread_percent(), None);
+
+        // Mid basis: paradex mid 101.25 vs vest mid 100.25
+        assert_eq!(crossed.spread_percent(SpreadBasis::Crossing), crossed.cross_spread_percent());
+        let mid = crossed.spread_percent(SpreadBasis::Mid).unwrap();
+        assert!((mid - 1.0 / 100.25 * 100.0).abs() < 1e-9);
+        assert_eq!(single.spread_percent(SpreadBasis::Mid), None);
+    }
+
+    #[test]
+    fn test_refresh_quote_time_only_moves_forward() {
+        // Exchange clocks run 60s behind local time
+        let price = |exchange: &str, timestamp_ms: u64| PriceData {
+            exchange: Arc::from(exchange),
+            symbol: Arc::from("BTC"),
+            bid: 100.0,
+            ask: 100.5,
+            timestamp_ms,
+            exchange_time_ms: Some(timestamp_ms - 60_000),
+        };
+        let mut agg = AggregatedPrice::from_prices(
+            Arc::from("BTC"),
+            vec![price("vest", 61_000), price("paradex", 61_400)],
+            61_400,
+        );
+        // vest's top has not moved since 61_000, but its book was updated since
+        agg.refresh_quote_time("vest", 1_350, 61_500);
+        assert_eq!(agg.prices[0].exchange_time_ms, Some(1_350));
+        assert_eq!(agg.prices[0].timestamp_ms, 61_500, "received on the local clock");
+        assert_eq!(agg.last_update_ms, 61_500);
+
+        // An older book time never rewinds the quote, nor freshens it
+        agg.refresh_quote_time("paradex", 900, 61_500);
+        assert_eq!(agg.prices[1].exchange_time_ms, Some(1_400));
+        assert_eq!(agg.prices[1].timestamp_ms, 61_400);
+        agg.refresh_quote_time("lighter", 2_000, 62_000);
+        assert_eq!(agg.last_update_ms, 61_500, "unknown exchange is ignored");
+    }
+
+    #[test]
+    fn test_price_band_admits_within_deviation() {
+        let band = PriceBand { max_deviation_pct: 2.0 };
+        assert!(band.admits(100.5, 101.0));
+        assert!(band.admits(103.0, 101.0));
+        assert!(!band.admits(999.0, 101.0));
+        assert!(!band.admits(100.0, 0.0));
+
+        let config = AppConfig {
+            max_price_deviation_pct: Some(2.5),
+            ..AppConfig::default()
+        };
+        assert_eq!(PriceBand::from_config(&config), Some(PriceBand { max_deviation_pct: 2.5 }));
+        assert_eq!(PriceBand::from_config(&AppConfig::default()), None);
+    }
+
+    #[test]
+    fn test_rank_opportunities_best_edge_first() {
+        let opportunity = |symbol: &str, spread: f64, threshold: f64| ArbitrageOpportunity {
+            symbol: Arc::from(symbol),
+            buy_exchange: Arc::from("vest"),
+            sell_exchange: Arc::from("paradex"),
+            buy_price: 100.0,
+            sell_price: 100.0 + spread,
+            spread_percent: spread,
+            entry_threshold_percent: threshold,
+            quote_skew_ms: 0,
+            timestamp_ms: 0,
+            monitor_id: None,
+            fillable_quantity: None,
+            fill_spread_percent: None,
+            imbalance_buy: None,
+            imbalance_sell: None,
+            selected: false,
+        };
+        // Widest raw spread, but the fees eat most of it
+        let mut opportunities = vec![
+            opportunity("BTC", 0.50, 0.45),
+            opportunity("ETH", 0.30, 0.10),
+            opportunity("SOL", 0.25, 0.15),
+        ];
+        rank_opportunities(&mut opportunities);
+
+        let ranked: Vec<&str> = opportunities.iter().map(|o| o.symbol.as_ref()).collect();
+        assert_eq!(ranked, vec!["ETH", "SOL", "BTC"]);
+        assert!((opportunities[0].score() - 0.20).abs() < 1e-9);
+
+        // Sized: SOL's thinner edge over 50 units beats ETH's over 1
+        let sized = |symbol: &str, spread: f64, fill_spread: f64, quantity: f64| ArbitrageOpportunity {
+            fillable_quantity: Some(quantity),
+            fill_spread_percent: Some(fill_spread),
+            ..opportunity(symbol, spread, 0.10)
+        };
+        let mut opportunities = vec![sized("ETH", 0.30, 0.30, 1.0), sized("SOL", 0.25, 0.15, 50.0)];
+        rank_opportunities(&mut opportunities);
+        assert_eq!(opportunities[0].symbol.as_ref(), "SOL");
+        // 0.05% of 50 × 100
+        assert!((opportunities[0].score() - 2.5).abs() < 1e-9);
+        assert!((opportunities[1].score() - 0.2).abs() < 1e-9);
+    }
+
     #[test]
     fn test_current_time_ms() {
         let now = current_time_ms();
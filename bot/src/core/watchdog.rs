@@ -0,0 +1,223 @@
+//! Dead-man's switch for unattended operation.
+//!
+//! The control loop "pets" a [`Watchdog`] on every iteration. A separate,
+//! minimal task checks the last pet timestamp and fires a one-shot action
+//! if the loop goes silent for longer than `deadman_timeout_ms` (deadlock,
+//! wedged lock, runaway await). The task shares nothing with the control
+//! loop except an `AtomicU64`, so it keeps running even if the loop is stuck.
+//!
+//! [`spawn_stall_alarm`] uses the same handle for loops that can stay alive
+//! while no longer producing anything (e.g. prices flowing but no spread
+//! computed): it reports each stall and keeps watching.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+use tracing::error;
+
+use crate::core::types::current_time_ms;
+
+/// Configuration for the dead-man's switch.
+#[derive(Debug, Clone)]
+pub struct WatchdogConfig {
+    /// Maximum time without a pet before the switch trips (ms)
+    pub deadman_timeout_ms: u64,
+    /// How often the watchdog task checks the last pet (ms)
+    pub check_interval_ms: u64,
+}
+
+impl WatchdogConfig {
+    /// Create a config with a check interval of a quarter of the timeout.
+    pub fn new(deadman_timeout_ms: u64) -> Self {
+        Self {
+            deadman_timeout_ms,
+            check_interval_ms: (deadman_timeout_ms / 4).max(1),
+        }
+    }
+
+    /// Period of a loop's idle heartbeat that pets this watchdog: `period`,
+    /// shortened to a quarter of the timeout so a quiet loop never trips it.
+    pub fn heartbeat_period(&self, period: Duration) -> Duration {
+        period.min(Duration::from_millis((self.deadman_timeout_ms / 4).max(1)))
+    }
+}
+
+/// Cloneable handle used by the control loop to signal liveness.
+#[derive(Debug, Clone)]
+pub struct Watchdog {
+    last_pet_ms: Arc<AtomicU64>,
+}
+
+impl Watchdog {
+    /// Record that the control loop is alive.
+    #[inline]
+    pub fn pet(&self) {
+        self.last_pet_ms.store(current_time_ms(), Ordering::Relaxed);
+    }
+
+    /// Milliseconds since the last pet.
+    pub fn silence_ms(&self) -> u64 {
+        current_time_ms().saturating_sub(self.last_pet_ms.load(Ordering::Relaxed))
+    }
+}
+
+/// Spawn the dead-man's switch task.
+///
+/// `on_trip` is invoked once, with the observed silence in ms, when the
+/// watchdog has not been petted within `deadman_timeout_ms`. The task exits
+/// after tripping.
+pub fn spawn_watchdog<F>(config: WatchdogConfig, on_trip: F) -> (Watchdog, JoinHandle<()>)
+where
+    F: FnOnce(u64) + Send + 'static,
+{
+    let watchdog = Watchdog {
+        last_pet_ms: Arc::new(AtomicU64::new(current_time_ms())),
+    };
+    let monitor = watchdog.clone();
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_millis(config.check_interval_ms));
+        loop {
+            interval.tick().await;
+            let silence_ms = monitor.silence_ms();
+            if silence_ms > config.deadman_timeout_ms {
+                error!(
+                    event_type = "DEADMAN_TRIGGERED",
+                    silence_ms = silence_ms,
+                    timeout_ms = config.deadman_timeout_ms,
+                    "Dead-man's switch tripped — control loop went silent"
+                );
+                on_trip(silence_ms);
+                break;
+            }
+        }
+    });
+
+    (watchdog, handle)
+}
+
+/// Spawn a stall alarm that reports, rather than acts on, a silent loop.
+///
+/// `on_stall` is invoked with the observed silence in ms once per stall,
+/// i.e. when the watchdog has not been petted within `deadman_timeout_ms`;
+/// the next pet re-arms it. The task runs until aborted.
+pub fn spawn_stall_alarm<F>(config: WatchdogConfig, mut on_stall: F) -> (Watchdog, JoinHandle<()>)
+where
+    F: FnMut(u64) + Send + 'static,
+{
+    let watchdog = Watchdog {
+        last_pet_ms: Arc::new(AtomicU64::new(current_time_ms())),
+    };
+    let monitor = watchdog.clone();
+
+    let handle = tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_millis(config.check_interval_ms));
+        let mut stalled = false;
+        loop {
+            interval.tick().await;
+            let silence_ms = monitor.silence_ms();
+            if silence_ms <= config.deadman_timeout_ms {
+                stalled = false;
+            } else if !stalled {
+                stalled = true;
+                on_stall(silence_ms);
+            }
+        }
+    });
+
+    (watchdog, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    #[tokio::test]
+    async fn test_stalled_heartbeat_triggers_action() {
+        let tripped = Arc::new(AtomicBool::new(false));
+        let flag = tripped.clone();
+
+        let (_watchdog, handle) = spawn_watchdog(WatchdogConfig::new(50), move |_| {
+            flag.store(true, Ordering::SeqCst);
+        });
+
+        // Never pet — the switch must trip and the task must exit
+        tokio::time::timeout(Duration::from_secs(2), handle)
+            .await
+            .expect("watchdog should trip")
+            .unwrap();
+        assert!(tripped.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_petted_watchdog_does_not_trip() {
+        let tripped = Arc::new(AtomicBool::new(false));
+        let flag = tripped.clone();
+
+        let (watchdog, handle) = spawn_watchdog(WatchdogConfig::new(100), move |_| {
+            flag.store(true, Ordering::SeqCst);
+        });
+
+        for _ in 0..10 {
+            watchdog.pet();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        assert!(!tripped.load(Ordering::SeqCst));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_quiet_loop_with_short_timeout_does_not_trip() {
+        let tripped = Arc::new(AtomicBool::new(false));
+        let flag = tripped.clone();
+        let config = WatchdogConfig::new(200);
+        let period = config.heartbeat_period(Duration::from_secs(1));
+        assert_eq!(period, Duration::from_millis(50));
+        assert_eq!(WatchdogConfig::new(60_000).heartbeat_period(Duration::from_secs(1)), Duration::from_secs(1));
+
+        let (watchdog, handle) = spawn_watchdog(config, move |_| {
+            flag.store(true, Ordering::SeqCst);
+        });
+
+        // No prices at all: only the heartbeat wakes the loop
+        let mut heartbeat = tokio::time::interval(period);
+        let quiet = tokio::time::sleep(Duration::from_millis(600));
+        tokio::pin!(quiet);
+        loop {
+            watchdog.pet();
+            tokio::select! {
+                _ = heartbeat.tick() => {}
+                _ = &mut quiet => break,
+            }
+        }
+
+        assert!(!tripped.load(Ordering::SeqCst));
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_stall_alarm_fires_once_per_stall() {
+        let stalls = Arc::new(AtomicU64::new(0));
+        let count = stalls.clone();
+
+        let (watchdog, handle) = spawn_stall_alarm(WatchdogConfig::new(40), move |_| {
+            count.fetch_add(1, Ordering::SeqCst);
+        });
+
+        // Never petted: reported once, not on every check
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(stalls.load(Ordering::SeqCst), 1);
+
+        // A pet re-arms the alarm for the next stall
+        watchdog.pet();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(stalls.load(Ordering::SeqCst), 1);
+        tokio::time::sleep(Duration::from_millis(130)).await;
+        assert_eq!(stalls.load(Ordering::SeqCst), 2);
+        handle.abort();
+    }
+}
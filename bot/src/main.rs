@@ -6,26 +6,35 @@
 //! 3. ExchangeManager → all adapters
 //! 4. PriceAggregator + ArbitrageDetector pipeline
 //! 5. axum WebSocket API server
-//! 6. Ctrl+C graceful shutdown
+//! 6. Ctrl+C graceful shutdown (SIGHUP reloads monitor thresholds)
+//!
+//! With `--once` (or `SCAN_ONCE=1`) it instead prints one spread report per
+//! monitor as JSON and exits.
 
 use std::sync::Arc;
 
-use tokio::sync::{broadcast, RwLock};
-use tracing::{error, info, warn};
+use tokio::sync::{broadcast, watch, RwLock};
+use tracing::{debug, error, info, warn};
 
-use hft_bot::adapters::ExchangeManager;
-use hft_bot::config::{init_logging, load_config};
+use hft_bot::adapters::shared::MaintenanceBackoff;
+use hft_bot::adapters::{ExchangeManager, RecordingLayer};
+use hft_bot::config::{init_logging, init_logging_to, load_config, reload_monitors, AppConfig};
 use hft_bot::core::{
-    ArbitrageDetector, BroadcastEvent, DetectorConfig, PriceAggregator, PriceData,
+    current_time_ms, scan_once, scan_once_requested, spawn_stall_alarm, spawn_watchdog,
+    wait_for_tasks,
+    ArbitrageDetector, ArbitrageOpportunity, BroadcastEvent, DetectorConfig, EventLogFilter, FileEventSink, LegState,
+    LegStates, Metrics, MonitorDetector, OpportunityDispatcher, OpportunityGate, PriceAggregator, PriceBand, PriceData, RecentMids, ScanOptions, SequencedEvent, ShutdownSignal, WatchdogConfig,
 };
 use hft_bot::server::{self, AppState};
 
 /// Broadcast channel capacity for price data
 const PRICE_CHANNEL_CAPACITY: usize = 4096;
-/// Broadcast channel capacity for client events
-const EVENT_CHANNEL_CAPACITY: usize = 1024;
+/// Default broadcast channel capacity for client events
+const DEFAULT_EVENT_CHANNEL_CAPACITY: usize = 1024;
 /// Default server port (can be overridden with PORT env var)
 const DEFAULT_PORT: u16 = 8080;
+/// Default bound on waiting for tasks to stop at shutdown
+const DEFAULT_SHUTDOWN_TIMEOUT_MS: u64 = 5_000;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -33,13 +42,18 @@ async fn main() -> anyhow::Result<()> {
     // 1. Config + logging
     // =========================================================================
     dotenvy::dotenv().ok();
-    init_logging();
+    if scan_once_requested() {
+        // stdout carries the JSON report
+        init_logging_to(std::io::stderr);
+    } else {
+        init_logging();
+    }
 
     info!("=== Arbi v5 Rust Backend ===");
 
     // Load config for exchange/symbol list
     let config_path = std::path::Path::new("config.yaml");
-    let (exchanges, symbols) = match load_config(config_path) {
+    let (exchanges, symbols, app_config) = match load_config(config_path) {
         Ok(config) => {
             info!("Config loaded from config.yaml");
             // Extract unique exchanges and symbols from monitor configs
@@ -53,6 +67,7 @@ async fn main() -> anyhow::Result<()> {
             (
                 exch_set.into_iter().collect::<Vec<_>>(),
                 sym_set.into_iter().collect::<Vec<_>>(),
+                config,
             )
         }
         Err(e) => {
@@ -64,12 +79,32 @@ async fn main() -> anyhow::Result<()> {
                     "hyperliquid".into(), "grvt".into(), "reya".into(),
                     "hotstuff".into(), "pacifica".into(), "extended".into(),
                     "nado".into(), "nord".into(), "ethereal".into(),
+                    "backpack".into(),
                 ],
                 vec!["BTC".into(), "ETH".into(), "SOL".into()],
+                AppConfig::default(),
             )
         }
     };
 
+    // One-shot scan: report current spreads as JSON and exit (no server)
+    if scan_once_requested() {
+        let options = ScanOptions {
+            symbol_overrides: app_config.symbol_overrides(),
+            ..ScanOptions::from_env()
+        };
+        let reports = scan_once(&app_config.bots, &options).await;
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+        if reports.len() < app_config.bots.len() {
+            anyhow::bail!(
+                "scan incomplete: {} of {} monitors reported",
+                reports.len(),
+                app_config.bots.len()
+            );
+        }
+        return Ok(());
+    }
+
     let port: u16 = std::env::var("PORT")
         .ok()
         .and_then(|p| p.parse().ok())
@@ -86,18 +121,68 @@ async fn main() -> anyhow::Result<()> {
     // 2. Broadcast channels
     // =========================================================================
     let (price_tx, _) = broadcast::channel::<PriceData>(PRICE_CHANNEL_CAPACITY);
-    let (event_tx, _) = broadcast::channel::<BroadcastEvent>(EVENT_CHANNEL_CAPACITY);
+    let (event_tx, _) = broadcast::channel::<SequencedEvent>(
+        app_config.event_channel_capacity.unwrap_or(DEFAULT_EVENT_CHANNEL_CAPACITY),
+    );
 
     // =========================================================================
     // 3. ExchangeManager → launch all adapters
     // =========================================================================
-    let manager = ExchangeManager::new(exchanges, symbols, price_tx.clone());
+    let shutdown = ShutdownSignal::new();
+    let metrics = Arc::new(Metrics::new());
+    let mut manager = ExchangeManager::new(exchanges, symbols, price_tx.clone())
+        .with_shutdown(shutdown.subscribe())
+        .with_metrics(metrics.clone())
+        .with_maintenance_backoff(MaintenanceBackoff::from_env())
+        .with_symbol_overrides(app_config.symbol_overrides())
+        .with_crossed_book_policy(app_config.crossed_book_policy);
+    if let Some(path) = &app_config.replay_path {
+        info!(path = %path, pace = ?app_config.replay_pace(), "Replay mode: exchanges will not be contacted");
+        manager = manager.with_replay(path, app_config.replay_pace());
+    } else if let Some(path) = &app_config.record_path {
+        match RecordingLayer::new(path).await {
+            Ok(recorder) => {
+                info!(path = %path, "Recording orderbooks");
+                manager = manager.with_recorder(Arc::new(recorder));
+            }
+            Err(e) => error!(path = %path, error = %e, "Could not open orderbook recording"),
+        }
+    }
 
     let adapter_handles = manager.connect_all().await;
     info!(
         count = adapter_handles.len(),
         "Exchange adapters launched"
     );
+    let book_reader = manager.book_reader();
+    let depth_requirement = app_config.depth_requirement();
+    let spread_basis = app_config.spread_basis;
+    let dispatch_window = app_config.dispatch_window_ms.map(tokio::time::Duration::from_millis);
+    let price_band = PriceBand::from_config(&app_config);
+    let opportunity_gate =
+        OpportunityGate::new(depth_requirement, price_band)
+            .with_depth_quantity(app_config.depth_quantity)
+            .with_max_slippage(app_config.max_slippage_pct)
+            .with_max_adverse_imbalance(app_config.max_adverse_imbalance);
+    if let Some(requirement) = depth_requirement {
+        info!(
+            min_levels = requirement.min_levels,
+            min_notional = requirement.min_notional,
+            "Book depth gating enabled"
+        );
+    }
+    if let Some(quantity) = app_config.depth_quantity {
+        info!(quantity = quantity, "Depth-aware spread gating enabled");
+    }
+    if let Some(slippage) = app_config.max_slippage_pct {
+        info!(max_slippage_pct = slippage, "Slippage-budget sizing enabled");
+    }
+    if let Some(imbalance) = app_config.max_adverse_imbalance {
+        info!(max_adverse_imbalance = imbalance, "Book imbalance gating enabled");
+    }
+    if let Some(band) = price_band {
+        info!(max_deviation_pct = band.max_deviation_pct, "Price protection band enabled");
+    }
 
     // =========================================================================
     // 4. Price pipeline: Aggregator + Detector
@@ -105,50 +190,237 @@ async fn main() -> anyhow::Result<()> {
     let aggregator = Arc::new(RwLock::new(PriceAggregator::new()));
     let pipeline_aggregator = aggregator.clone();
     let pipeline_event_tx = event_tx.clone();
+    // Server state is built up front so the pipeline can record spread history
+    let state = AppState::new(event_tx.clone(), aggregator.clone()).with_metrics(metrics.clone());
+    let pipeline_state = state.clone();
+
+    // Dead-man's switch: if the pipeline loop stops petting the watchdog
+    // (deadlock, wedged lock), exit so the process supervisor can restart us.
+    // The scanner holds no orders or positions, so there is nothing to flatten.
+    // The pipeline's idle heartbeat pets it, so a short timeout speeds up the
+    // heartbeat rather than tripping on a quiet market.
+    let mut heartbeat_period = tokio::time::Duration::from_secs(1);
+    let (watchdog, watchdog_handle) = app_config
+        .deadman_timeout_ms
+        .map(|timeout_ms| {
+            let config = WatchdogConfig::new(timeout_ms);
+            heartbeat_period = config.heartbeat_period(heartbeat_period);
+            info!(timeout_ms = timeout_ms, "Dead-man's switch enabled");
+            spawn_watchdog(config, |silence_ms| {
+                error!(silence_ms = silence_ms, "Pipeline stalled — exiting");
+                std::process::exit(1);
+            })
+        })
+        .unzip();
 
+    // Spread stall alarm: adapters can all look connected while no spread is
+    // being computed (e.g. one leg's book never fills). Report it and keep going.
+    let (spread_alarm, spread_alarm_handle) = app_config
+        .spread_stall_ms
+        .map(|stall_ms| {
+            info!(stall_ms = stall_ms, "Spread stall alarm enabled");
+            spawn_stall_alarm(WatchdogConfig::new(stall_ms), move |silence_ms| {
+                warn!(
+                    event_type = "SPREAD_STALL",
+                    silence_ms = silence_ms,
+                    stall_ms = stall_ms,
+                    "No cross-exchange spread computed recently — pipeline may be blind"
+                );
+            })
+        })
+        .unzip();
+
+    let detector_config = DetectorConfig {
+        min_spread_percent: 0.05,
+        max_price_age_ms: 5_000,
+        min_confirmations: 2,
+        ema_alpha: app_config.spread_ema_alpha,
+        taker_fee_bps: app_config.detector_taker_fees(),
+        max_quote_skew_ms: app_config.max_quote_skew_ms,
+        warmup_ms: app_config.warmup_ms,
+        ..Default::default()
+    };
+    // One detector per configured monitor (own thresholds, own exchange pair);
+    // without monitors, a single detector scans every exchange combination.
+    let mut monitors: Vec<MonitorDetector> = app_config
+        .bots
+        .iter()
+        .map(|bot| MonitorDetector::from_config(bot, &detector_config))
+        .collect();
+    info!(count = monitors.len(), "Monitor detectors configured");
+
+    // SIGHUP re-reads config.yaml; accepted monitor thresholds reach the
+    // pipeline through this channel
+    let (monitors_tx, mut monitors_rx) = watch::channel(app_config.bots.clone());
+    let mut reload_handle = None;
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::hangup()) {
+            Ok(mut hangup) => {
+                let mut reload_shutdown = shutdown.subscribe();
+                reload_handle = Some(tokio::spawn(async move {
+                    loop {
+                        tokio::select! {
+                            _ = hangup.recv() => {}
+                            _ = reload_shutdown.wait() => break,
+                        }
+                        let current = monitors_tx.borrow().clone();
+                        match reload_monitors(config_path, &current) {
+                            Ok(bots) => {
+                                monitors_tx.send_replace(bots);
+                            }
+                            Err(e) => warn!(
+                                event_type = "CONFIG_RELOAD_REJECTED",
+                                error = %e,
+                                "Config reload rejected, keeping running thresholds"
+                            ),
+                        }
+                    }
+                }));
+            }
+            Err(e) => warn!(error = %e, "Could not install SIGHUP handler, config reload disabled"),
+        }
+    }
+
+    let mut pipeline_shutdown = shutdown.subscribe();
     let pipeline_handle = tokio::spawn(async move {
         let mut price_rx = price_tx.subscribe();
-        let mut detector = ArbitrageDetector::with_config(DetectorConfig {
-            min_spread_percent: 0.05,
-            max_price_age_ms: 5_000,
-            min_confirmations: 2,
-            ..Default::default()
-        });
+        let mut heartbeat = tokio::time::interval(heartbeat_period);
+        let mut detector = ArbitrageDetector::with_config(detector_config.clone());
+        let mut dispatcher = OpportunityDispatcher::new(dispatch_window);
+        let mut dispatch_tick = dispatch_window.map(tokio::time::interval);
+        let emit = |opportunities: Vec<ArbitrageOpportunity>| {
+            for opportunity in opportunities {
+                info!(
+                    monitor = opportunity.monitor_id.as_deref().unwrap_or("-"),
+                    symbol = opportunity.symbol.as_ref(),
+                    buy = opportunity.buy_exchange.as_ref(),
+                    sell = opportunity.sell_exchange.as_ref(),
+                    spread = format!("{:.4}%", opportunity.spread_percent),
+                    breakeven = format!("{:.4}%", opportunity.entry_threshold_percent),
+                    quote_skew_ms = opportunity.quote_skew_ms,
+                    fillable_quantity = ?opportunity.fillable_quantity,
+                    imbalance_buy = ?opportunity.imbalance_buy,
+                    imbalance_sell = ?opportunity.imbalance_sell,
+                    score = opportunity.score(),
+                    selected = opportunity.selected,
+                    "🔥 Arbitrage opportunity detected"
+                );
+                metrics.record_opportunity();
+                let _ = pipeline_event_tx.send(BroadcastEvent::Opportunity(opportunity).into());
+            }
+        };
 
         let mut update_count: u64 = 0;
+        // Price band references, one per exchange and symbol
+        let mut recent_mids = RecentMids::default();
 
         loop {
-            match price_rx.recv().await {
+            if let Some(ref wd) = watchdog {
+                wd.pet();
+            }
+
+            let recv = tokio::select! {
+                recv = price_rx.recv() => recv,
+                // Keep petting the watchdog when the market is quiet, and
+                // stop serving quotes from exchanges that went dark
+                _ = heartbeat.tick() => {
+                    let evicted = pipeline_aggregator.write().await.evict_stale(current_time_ms());
+                    if evicted > 0 {
+                        debug!(evicted = evicted, "Evicted stale prices");
+                    }
+                    continue;
+                }
+                // Close the dispatch window: the best opportunity across pairs
+                // goes out selected
+                Some(_) = async {
+                    match dispatch_tick.as_mut() {
+                        Some(tick) => Some(tick.tick().await),
+                        None => None,
+                    }
+                } => {
+                    emit(dispatcher.dispatch());
+                    continue;
+                }
+                Ok(()) = monitors_rx.changed() => {
+                    let bots = monitors_rx.borrow_and_update().clone();
+                    for monitor in monitors.iter_mut() {
+                        if let Some(bot) = bots.iter().find(|b| b.id.as_str() == monitor.id.as_ref()) {
+                            monitor.set_config(detector_config.for_monitor(bot));
+                        }
+                    }
+                    info!(event_type = "CONFIG_RELOADED", monitors = bots.len(), "Monitor thresholds reloaded");
+                    continue;
+                }
+                _ = pipeline_shutdown.wait() => {
+                    info!("Shutdown requested, pipeline stopping");
+                    break;
+                }
+            };
+
+            match recv {
                 Ok(price_data) => {
                     update_count += 1;
 
                     // Forward raw price as event
-                    let _ = pipeline_event_tx.send(BroadcastEvent::Price(price_data.clone()));
+                    let _ = pipeline_event_tx.send(BroadcastEvent::Price(price_data.clone()).into());
+                    metrics.record_orderbook_update(&price_data.exchange);
+                    recent_mids.observe(&price_data);
 
                     // Aggregate
-                    let aggregated = {
+                    let mut aggregated = {
                         let mut agg = pipeline_aggregator.write().await;
                         agg.update(price_data)
                     };
+                    if let Some(spread) = aggregated.spread_percent(spread_basis) {
+                        if let Some(ref alarm) = spread_alarm {
+                            alarm.pet();
+                        }
+                        pipeline_state.push_spread(&aggregated.symbol, spread).await;
+                        metrics.set_best_spread(&aggregated.symbol, spread);
+                    }
+
+                    // Legs behind a possible cross, for the pre-emission gates
+                    let mut legs = LegStates::new();
+                    if aggregated.cross_spread_percent().is_some_and(|spread| spread > 0.0) {
+                        // Quote skew compares when each exchange last stamped
+                        // its book, not when the manager last saw its top move
+                        let exchanges: Vec<_> = aggregated.prices.iter().map(|p| p.exchange.clone()).collect();
+                        for exchange in &exchanges {
+                            if let Some(ts) = book_reader.exchange_time_ms(exchange, &aggregated.symbol).await {
+                                aggregated.refresh_quote_time(exchange, ts, current_time_ms());
+                            }
+                        }
+                        for price in &aggregated.prices {
+                            let book = if opportunity_gate.needs_books() {
+                                book_reader.book(&price.exchange, &aggregated.symbol).await
+                            } else {
+                                None
+                            };
+                            let healthy = book_reader.is_healthy(&price.exchange, &aggregated.symbol).await;
+                            let reference_mid = recent_mids.reference(&price.exchange, &aggregated.symbol);
+                            legs.insert(price.exchange.clone(), LegState { healthy, book, reference_mid });
+                        }
+                    }
+                    let gate = |opportunity: &mut ArbitrageOpportunity| {
+                        opportunity_gate.check(opportunity, &legs)
+                    };
 
                     // Detect arbitrage
-                    if let Some(opportunity) = detector.detect(&aggregated) {
-                        info!(
-                            symbol = opportunity.symbol.as_ref(),
-                            buy = opportunity.buy_exchange.as_ref(),
-                            sell = opportunity.sell_exchange.as_ref(),
-                            spread = format!("{:.4}%", opportunity.spread_percent),
-                            "🔥 Arbitrage opportunity detected"
-                        );
-                        let _ = pipeline_event_tx
-                            .send(BroadcastEvent::Opportunity(opportunity));
-                    }
+                    let opportunities: Vec<_> = if monitors.is_empty() {
+                        detector.detect_with(&aggregated, gate).into_iter().collect()
+                    } else {
+                        monitors.iter_mut().filter_map(|m| m.detect_with(&aggregated, gate)).collect()
+                    };
+                    emit(dispatcher.offer(opportunities));
 
                     // Periodic cleanup
                     if update_count % 1000 == 0 {
                         let mut agg = pipeline_aggregator.write().await;
                         agg.cleanup();
                         detector.cleanup();
+                        monitors.iter_mut().for_each(|m| m.cleanup());
                     }
 
                     // Periodic stats
@@ -173,14 +445,48 @@ async fn main() -> anyhow::Result<()> {
         }
     });
 
+    // Optional JSONL event log: opportunities and status changes, plus
+    // sampled price ticks when configured
+    let mut event_log_handle = None;
+    if let Some(path) = app_config.event_log_path.clone() {
+        match FileEventSink::new(&path).await {
+            Ok(sink) => {
+                let price_interval_ms = app_config.event_log_price_interval_ms;
+                info!(path = %path, price_interval_ms = ?price_interval_ms, "Event log enabled");
+                let mut filter = EventLogFilter::new(price_interval_ms);
+                let mut event_rx = event_tx.subscribe();
+                let mut log_shutdown = shutdown.subscribe();
+                event_log_handle = Some(tokio::spawn(async move {
+                    loop {
+                        let event = tokio::select! {
+                            event = event_rx.recv() => event,
+                            _ = log_shutdown.wait() => break,
+                        };
+                        match event {
+                            Ok(event) if filter.admits(&event) => {
+                                if let Err(e) = sink.write(&event).await {
+                                    warn!(error = %e, "Failed to write event log");
+                                }
+                            }
+                            Ok(_) => {}
+                            Err(broadcast::error::RecvError::Lagged(n)) => {
+                                warn!(skipped = n, "Event log lagged, skipped events");
+                            }
+                            Err(broadcast::error::RecvError::Closed) => break,
+                        }
+                    }
+                    if let Err(e) = sink.flush().await {
+                        warn!(error = %e, "Failed to flush event log");
+                    }
+                }));
+            }
+            Err(e) => error!(path = %path, error = %e, "Could not open event log"),
+        }
+    }
+
     // =========================================================================
     // 5. axum WebSocket API server
     // =========================================================================
-    let state = AppState {
-        event_tx: event_tx.clone(),
-        aggregator: aggregator.clone(),
-    };
-
     let server_handle = tokio::spawn(async move {
         if let Err(e) = server::start_server(state, port).await {
             error!(error = %e, "WebSocket server failed");
@@ -197,13 +503,35 @@ async fn main() -> anyhow::Result<()> {
     tokio::signal::ctrl_c().await?;
     info!("Shutdown signal received");
 
-    // Abort all tasks
-    pipeline_handle.abort();
-    server_handle.abort();
-    for (name, handle) in adapter_handles {
-        info!(exchange = %name, "Stopping adapter");
-        handle.abort();
+    // Ask tasks to stop, then wait (bounded) for each to finish. The
+    // pipeline stops petting the watchdogs now, so stop them first: a slow
+    // shutdown must not trip the dead-man's switch or raise SPREAD_STALL.
+    shutdown.trigger();
+    let mut tasks = vec![("pipeline".to_string(), pipeline_handle)];
+    for (name, handle) in [("watchdog", watchdog_handle), ("spread_alarm", spread_alarm_handle)] {
+        if let Some(handle) = handle {
+            handle.abort();
+            tasks.push((name.to_string(), handle));
+        }
+    }
+    tasks.extend(adapter_handles);
+    if let Some(handle) = event_log_handle {
+        tasks.push(("event_log".to_string(), handle));
     }
+    if let Some(handle) = reload_handle {
+        tasks.push(("config_reload".to_string(), handle));
+    }
+    let aborted = wait_for_tasks(
+        tasks,
+        tokio::time::Duration::from_millis(
+            app_config.shutdown_timeout_ms.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_MS),
+        ),
+    )
+    .await;
+    if !aborted.is_empty() {
+        warn!(tasks = ?aborted, "Tasks aborted after shutdown timeout");
+    }
+    server_handle.abort();
 
     info!("=== Shutdown complete ===");
     Ok(())
@@ -4,40 +4,78 @@
 
 pub mod ws;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
 use axum::{
     Router,
-    extract::State,
-    response::Json,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json},
     routing::get,
 };
+use serde::Deserialize;
 use tower_http::cors::CorsLayer;
 use tracing::info;
 
+use crate::adapters::factory::SUPPORTED_EXCHANGES;
 use crate::core::aggregator::PriceAggregator;
-use crate::core::types::{AggregatedPrice, BroadcastEvent};
+use crate::core::metrics::{Metrics, MetricsSnapshot};
+use crate::core::spread_history::SpreadHistory;
+use crate::core::types::{AggregatedPrice, SequencedEvent};
 
 /// Shared application state for the HTTP/WS server.
 #[derive(Clone)]
 pub struct AppState {
     /// Broadcast channel for real-time events → WS clients
-    pub event_tx: broadcast::Sender<BroadcastEvent>,
+    pub event_tx: broadcast::Sender<SequencedEvent>,
     /// Price aggregator (for REST snapshots)
     pub aggregator: Arc<RwLock<PriceAggregator>>,
+    /// Recent cross-exchange spreads per symbol (for percentile ranking)
+    pub spread_history: Arc<RwLock<HashMap<Arc<str>, SpreadHistory>>>,
+    /// Counters and gauges served on GET /metrics
+    pub metrics: Arc<Metrics>,
+}
+
+impl AppState {
+    /// Create state with an empty spread history and fresh metrics.
+    pub fn new(
+        event_tx: broadcast::Sender<SequencedEvent>,
+        aggregator: Arc<RwLock<PriceAggregator>>,
+    ) -> Self {
+        Self {
+            event_tx,
+            aggregator,
+            spread_history: Arc::new(RwLock::new(HashMap::new())),
+            metrics: Arc::new(Metrics::new()),
+        }
+    }
+
+    /// Serve `metrics` (shared with the adapters and pipeline) on /metrics.
+    pub fn with_metrics(mut self, metrics: Arc<Metrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Record the latest spread (percent) for `symbol`.
+    pub async fn push_spread(&self, symbol: &Arc<str>, spread: f64) {
+        let mut history = self.spread_history.write().await;
+        history.entry(symbol.clone()).or_default().push(spread);
+    }
+
+    /// Spread at percentile `p` (0–100) over `symbol`'s recent history.
+    pub async fn spread_percentile(&self, symbol: &str, p: f64) -> Option<f64> {
+        let history = self.spread_history.read().await;
+        history.get(symbol)?.percentile(p)
+    }
 }
 
 /// Start the HTTP/WebSocket server.
 ///
 /// Blocks until the server shuts down.
 pub async fn start_server(state: AppState, port: u16) -> anyhow::Result<()> {
-    let app = Router::new()
-        .route("/health", get(health_handler))
-        .route("/api/prices", get(prices_handler))
-        .route("/ws", get(ws::ws_handler))
-        .layer(CorsLayer::permissive())
-        .with_state(state);
+    let app = router(state);
 
     let addr = format!("0.0.0.0:{}", port);
     info!(address = %addr, "Starting WebSocket API server");
@@ -48,6 +86,19 @@ pub async fn start_server(state: AppState, port: u16) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Build the HTTP/WS router with all routes and middleware.
+fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/health", get(health_handler))
+        .route("/metrics", get(metrics_handler))
+        .route("/api/metrics", get(metrics_snapshot_handler))
+        .route("/api/prices", get(prices_handler))
+        .route("/api/spreads/:symbol", get(spread_stats_handler))
+        .route("/ws", get(ws::ws_handler))
+        .layer(CorsLayer::permissive())
+        .with_state(state)
+}
+
 /// GET /health — server status
 async fn health_handler() -> Json<serde_json::Value> {
     Json(serde_json::json!({
@@ -56,10 +107,253 @@ async fn health_handler() -> Json<serde_json::Value> {
     }))
 }
 
-/// GET /api/prices — snapshot of all aggregated prices
+/// GET /metrics — Prometheus text exposition
+async fn metrics_handler(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(),
+    )
+}
+
+/// GET /api/metrics — the same metrics as a JSON snapshot
+async fn metrics_snapshot_handler(State(state): State<AppState>) -> Json<MetricsSnapshot> {
+    Json(state.metrics.snapshot())
+}
+
+/// Optional filters for GET /api/prices
+#[derive(Debug, Default, Deserialize)]
+struct PricesQuery {
+    /// Only return prices from this exchange (e.g. "paradex")
+    exchange: Option<String>,
+    /// Only return this normalized symbol (e.g. "BTC")
+    symbol: Option<String>,
+}
+
+/// GET /api/prices — snapshot of aggregated prices
+///
+/// Supports `?exchange=paradex&symbol=BTC`. Without params, returns all.
+/// An unknown exchange name yields `400` with a JSON error body.
 async fn prices_handler(
     State(state): State<AppState>,
-) -> Json<Vec<AggregatedPrice>> {
+    Query(query): Query<PricesQuery>,
+) -> Result<Json<Vec<AggregatedPrice>>, (StatusCode, Json<serde_json::Value>)> {
+    let exchange = query.exchange.map(|e| e.to_lowercase());
+    if let Some(ref name) = exchange {
+        if !SUPPORTED_EXCHANGES.contains(&name.as_str()) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": format!("Unknown exchange '{}'", name),
+                    "supported": SUPPORTED_EXCHANGES,
+                })),
+            ));
+        }
+    }
+
     let agg = state.aggregator.read().await;
-    Json(agg.get_all())
+    Ok(Json(filter_prices(
+        agg.get_all(),
+        exchange.as_deref(),
+        query.symbol.as_deref(),
+    )))
+}
+
+/// GET /api/spreads/{symbol} — current spread ranked against recent history
+///
+/// Returns `404` until at least one spread was recorded for the symbol.
+async fn spread_stats_handler(
+    State(state): State<AppState>,
+    Path(symbol): Path<String>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let symbol = symbol.to_uppercase();
+    let history = state.spread_history.read().await;
+    let Some(spreads) = history.get(symbol.as_str()).filter(|h| !h.is_empty()) else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": format!("No spread history for '{}'", symbol) })),
+        ));
+    };
+
+    let current = spreads.latest();
+    Ok(Json(serde_json::json!({
+        "symbol": symbol,
+        "samples": spreads.len(),
+        "current": current,
+        "current_percentile": current.and_then(|c| spreads.percentile_rank(c)),
+        "p50": spreads.percentile(50.0),
+        "p90": spreads.percentile(90.0),
+        "p99": spreads.percentile(99.0),
+    })))
+}
+
+/// Narrow aggregated prices to one exchange and/or symbol.
+///
+/// When filtering by exchange, each entry keeps only that exchange's price
+/// (best bid/ask then come from that single quote) and symbols it does not
+/// quote are dropped.
+fn filter_prices(
+    all: Vec<AggregatedPrice>,
+    exchange: Option<&str>,
+    symbol: Option<&str>,
+) -> Vec<AggregatedPrice> {
+    all.into_iter()
+        .filter(|agg| symbol.is_none_or(|s| agg.symbol.eq_ignore_ascii_case(s)))
+        .filter_map(|agg| {
+            let Some(exchange) = exchange else {
+                return Some(agg);
+            };
+            let agg = agg.retain_exchanges(|e| e == exchange);
+            (!agg.prices.is_empty()).then_some(agg)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{current_time_ms, PriceData};
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn make_price(exchange: &str, symbol: &str, bid: f64, ask: f64) -> PriceData {
+        PriceData {
+            exchange: Arc::from(exchange),
+            symbol: Arc::from(symbol),
+            bid,
+            ask,
+            timestamp_ms: current_time_ms(),
+            exchange_time_ms: None,
+        }
+    }
+
+    fn test_state() -> AppState {
+        let mut agg = PriceAggregator::new();
+        agg.update(make_price("vest", "BTC", 50000.0, 50010.0));
+        agg.update(make_price("paradex", "BTC", 50020.0, 50030.0));
+        agg.update(make_price("paradex", "ETH", 3000.0, 3001.0));
+
+        let (event_tx, _) = broadcast::channel(16);
+        AppState::new(event_tx, Arc::new(RwLock::new(agg)))
+    }
+
+    async fn get(uri: &str) -> (StatusCode, serde_json::Value) {
+        get_with(test_state(), uri).await
+    }
+
+    async fn get_with(state: AppState, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = router(state)
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_prices_unfiltered_returns_all() {
+        let (status, body) = get("/api/prices").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_prices_filtered_by_exchange_and_symbol() {
+        let (status, body) = get("/api/prices?exchange=paradex&symbol=BTC").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let entries = body.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["symbol"], "BTC");
+        let prices = entries[0]["prices"].as_array().unwrap();
+        assert_eq!(prices.len(), 1);
+        assert_eq!(prices[0]["exchange"], "paradex");
+        assert_eq!(entries[0]["best_ask"]["exchange"], "paradex");
+    }
+
+    #[tokio::test]
+    async fn test_prices_filtered_by_exchange_drops_unquoted_symbols() {
+        let (status, body) = get("/api/prices?exchange=vest").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let entries = body.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["symbol"], "BTC");
+    }
+
+    #[tokio::test]
+    async fn test_prices_unknown_exchange_is_bad_request() {
+        let (status, body) = get("/api/prices?exchange=binance").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("binance"));
+    }
+
+    #[tokio::test]
+    async fn test_spread_stats_rank_current_spread() {
+        let state = test_state();
+        let btc: Arc<str> = Arc::from("BTC");
+        for spread in [0.05, 0.01, 0.04, 0.02, 0.03] {
+            state.push_spread(&btc, spread).await;
+        }
+        assert_eq!(state.spread_percentile("BTC", 50.0).await, Some(0.03));
+
+        let (status, body) = get_with(state, "/api/spreads/btc").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["samples"], 5);
+        assert_eq!(body["current"], 0.03);
+        assert_eq!(body["current_percentile"], 60.0);
+        assert_eq!(body["p90"], 0.05);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_scrape_lists_expected_series() {
+        let state = test_state();
+        state.metrics.set_connected("vest", true);
+        state.metrics.record_orderbook_update("vest");
+        state.metrics.set_best_spread("BTC", 0.04);
+        state.metrics.record_opportunity();
+
+        let response = router(state)
+            .oneshot(Request::builder().uri("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()[header::CONTENT_TYPE].to_str().unwrap().starts_with("text/plain"));
+
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+        for line in [
+            "# TYPE arbi_exchange_up gauge",
+            "arbi_exchange_up{exchange=\"vest\"} 1",
+            "# TYPE arbi_orderbook_updates_total counter",
+            "arbi_orderbook_updates_total{exchange=\"vest\"} 1",
+            "# TYPE arbi_best_spread_percent gauge",
+            "arbi_best_spread_percent{symbol=\"BTC\"} 0.04",
+            "# TYPE arbi_opportunities_total counter",
+            "arbi_opportunities_total 1",
+        ] {
+            assert!(text.lines().any(|l| l == line), "missing {line:?} in:\n{text}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_snapshot_as_json() {
+        let state = test_state();
+        state.metrics.set_connected("vest", true);
+        state.metrics.record_opportunity();
+
+        let (status, body) = get_with(state, "/api/metrics").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["opportunities"], 1);
+        assert_eq!(body["exchanges"]["vest"]["connected"], true);
+        assert!(body["exchanges"]["vest"]["ws_rtt_ms"].is_null());
+        assert!(body["uptime_ms"].is_u64());
+    }
+
+    #[tokio::test]
+    async fn test_spread_stats_unknown_symbol_is_not_found() {
+        let (status, _) = get("/api/spreads/DOGE").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
 }
@@ -1,57 +1,131 @@
 //! WebSocket handler for real-time event streaming.
 //!
 //! Clients connect to `/ws` and receive JSON events:
-//! - `{ "type": "price", "data": { ... } }`
-//! - `{ "type": "opportunity", "data": { ... } }`
+//! - `{ "seq": 1, "type": "price", "data": { ... } }`
+//! - `{ "seq": 2, "type": "opportunity", "data": { ... } }`
+//!
+//! `seq` is unique and increasing across the process (see [`SequencedEvent`]).
+//!
+//! Clients connecting with `/ws?format=cbor` receive the same events as
+//! CBOR-encoded binary frames instead, which are considerably smaller.
+//!
+//! A client too slow to keep up with the broadcast channel is not dropped:
+//! it receives `{ "type": "resync", "dropped": n }` followed by
+//! `{ "type": "snapshot", "data": [ ... ] }` with every aggregated price,
+//! then resumes streaming.
 
 use axum::{
-    extract::{State, WebSocketUpgrade, ws::{Message, WebSocket}},
+    extract::{Query, State, WebSocketUpgrade, ws::{Message, WebSocket}},
     response::IntoResponse,
 };
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{self, error::RecvError};
 use tracing::{info, warn};
 
 use super::AppState;
+use crate::core::types::{AggregatedPrice, SequencedEvent};
+
+/// Wire encoding negotiated per client via `?format=`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WireFormat {
+    /// JSON text frames (default)
+    #[default]
+    Json,
+    /// CBOR binary frames
+    Cbor,
+}
+
+/// Query parameters for GET /ws
+#[derive(Debug, Default, Deserialize)]
+pub struct WsParams {
+    #[serde(default)]
+    format: WireFormat,
+}
+
+/// Server-to-client messages that are not broadcast events
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum ControlMessage {
+    /// `dropped` events were skipped for this client; a snapshot follows
+    Resync { dropped: u64 },
+    /// Every aggregated price, replacing whatever the client had
+    Snapshot { data: Vec<AggregatedPrice> },
+}
 
 /// WebSocket upgrade handler at GET /ws
 pub async fn ws_handler(
     ws: WebSocketUpgrade,
     State(state): State<AppState>,
+    Query(params): Query<WsParams>,
 ) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_ws(socket, state))
+    ws.on_upgrade(move |socket| handle_ws(socket, state, params.format))
+}
+
+/// Encode a broadcast event (or control message) as a WS frame in the client's format.
+fn encode_event<T: Serialize>(event: &T, format: WireFormat) -> Result<Message, String> {
+    match format {
+        WireFormat::Json => serde_json::to_string(event)
+            .map(Message::Text)
+            .map_err(|e| e.to_string()),
+        WireFormat::Cbor => {
+            let mut buf = Vec::with_capacity(128);
+            ciborium::into_writer(event, &mut buf).map_err(|e| e.to_string())?;
+            Ok(Message::Binary(buf))
+        }
+    }
+}
+
+/// Frames to send for the next broadcast event.
+///
+/// After a lag, this is a resync notice plus a full price snapshot. Returns
+/// `None` once the channel is closed.
+async fn next_frames(
+    rx: &mut broadcast::Receiver<SequencedEvent>,
+    state: &AppState,
+    format: WireFormat,
+) -> Option<Vec<Message>> {
+    let messages = match rx.recv().await {
+        Ok(evt) => vec![encode_event(&evt, format)],
+        Err(RecvError::Lagged(n)) => {
+            warn!(skipped = n, "WS client lagged, resyncing with a snapshot");
+            let data = state.aggregator.read().await.get_all();
+            vec![
+                encode_event(&ControlMessage::Resync { dropped: n }, format),
+                encode_event(&ControlMessage::Snapshot { data }, format),
+            ]
+        }
+        Err(RecvError::Closed) => return None,
+    };
+
+    Some(
+        messages
+            .into_iter()
+            .filter_map(|m| m.map_err(|e| warn!(error = %e, "Failed to serialize event")).ok())
+            .collect(),
+    )
 }
 
 /// Handle an individual WebSocket connection.
 ///
-/// Subscribes to the broadcast channel and forwards all events as JSON.
-async fn handle_ws(mut socket: WebSocket, state: AppState) {
+/// Subscribes to the broadcast channel and forwards all events in `format`.
+async fn handle_ws(mut socket: WebSocket, state: AppState, format: WireFormat) {
     let mut rx = state.event_tx.subscribe();
     let peer = "ws-client"; // axum doesn't expose peer addr easily
 
-    info!(peer = peer, "WebSocket client connected");
+    info!(peer = peer, format = ?format, "WebSocket client connected");
 
-    loop {
+    'conn: loop {
         tokio::select! {
             // Forward broadcast events to the WS client
-            event = rx.recv() => {
-                match event {
-                    Ok(evt) => {
-                        match serde_json::to_string(&evt) {
-                            Ok(json) => {
-                                if socket.send(Message::Text(json.into())).await.is_err() {
-                                    // Client disconnected
-                                    break;
-                                }
-                            }
-                            Err(e) => {
-                                warn!(error = %e, "Failed to serialize event");
-                            }
-                        }
-                    }
-                    Err(tokio::sync::broadcast::error::RecvError::Lagged(n)) => {
-                        warn!(skipped = n, peer = peer, "WS client lagged, skipped events");
-                    }
-                    Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                        break;
+            frames = next_frames(&mut rx, &state, format) => {
+                let Some(frames) = frames else {
+                    break;
+                };
+                for frame in frames {
+                    if socket.send(frame).await.is_err() {
+                        // Client disconnected
+                        break 'conn;
                     }
                 }
             }
@@ -76,3 +150,115 @@ async fn handle_ws(mut socket: WebSocket, state: AppState) {
 
     info!(peer = peer, "WebSocket client disconnected");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::aggregator::PriceAggregator;
+    use crate::core::types::{BroadcastEvent, PriceData};
+    use futures_util::StreamExt;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::RwLock;
+    use tokio_tungstenite::tungstenite::Message as ClientMessage;
+
+    fn price_event() -> BroadcastEvent {
+        BroadcastEvent::Price(PriceData {
+            exchange: Arc::from("vest"),
+            symbol: Arc::from("BTC"),
+            bid: 50000.0,
+            ask: 50010.0,
+            timestamp_ms: 1700000000000,
+            exchange_time_ms: None,
+        })
+    }
+
+    #[test]
+    fn test_cbor_frame_is_smaller_than_json() {
+        let event = price_event();
+        let Message::Text(json) = encode_event(&event, WireFormat::Json).unwrap() else {
+            panic!("JSON format must produce a text frame");
+        };
+        let Message::Binary(cbor) = encode_event(&event, WireFormat::Cbor).unwrap() else {
+            panic!("CBOR format must produce a binary frame");
+        };
+        assert!(cbor.len() < json.len());
+    }
+
+    fn json_of(frame: &Message) -> serde_json::Value {
+        let Message::Text(text) = frame else {
+            panic!("expected text frame, got {:?}", frame);
+        };
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_lagging_receiver_gets_resync_then_snapshot() {
+        let mut agg = PriceAggregator::new();
+        let BroadcastEvent::Price(mut price) = price_event() else {
+            unreachable!()
+        };
+        price.timestamp_ms = crate::core::types::current_time_ms();
+        agg.update(price);
+
+        let (event_tx, mut rx) = broadcast::channel(2);
+        let state = AppState::new(event_tx.clone(), Arc::new(RwLock::new(agg)));
+        // Overflow the channel: the receiver misses the oldest 3 of 5 events
+        for _ in 0..5 {
+            event_tx.send(price_event().into()).unwrap();
+        }
+
+        let frames = next_frames(&mut rx, &state, WireFormat::Json).await.unwrap();
+        assert_eq!(frames.len(), 2);
+        let resync = json_of(&frames[0]);
+        assert_eq!(resync["type"], "resync");
+        assert_eq!(resync["dropped"], 3);
+        let snapshot = json_of(&frames[1]);
+        assert_eq!(snapshot["type"], "snapshot");
+        assert_eq!(snapshot["data"][0]["symbol"], "BTC");
+
+        // Streaming resumes with the events still buffered, in sequence
+        let frames = next_frames(&mut rx, &state, WireFormat::Json).await.unwrap();
+        let first = json_of(&frames[0]);
+        assert_eq!(first["type"], "price");
+        let frames = next_frames(&mut rx, &state, WireFormat::Json).await.unwrap();
+        assert!(json_of(&frames[0])["seq"].as_u64() > first["seq"].as_u64());
+    }
+
+    #[tokio::test]
+    async fn test_cbor_client_receives_decodable_frames() {
+        let (event_tx, _) = broadcast::channel(16);
+        let state = AppState::new(event_tx.clone(), Arc::new(RwLock::new(PriceAggregator::new())));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, super::super::router(state)).await.unwrap();
+        });
+
+        let url = format!("ws://{}/ws?format=cbor", addr);
+        let (mut client, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+
+        // The server subscribes after the upgrade completes; keep publishing until a frame lands
+        let expected = SequencedEvent::new(price_event());
+        let frame = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                let _ = event_tx.send(expected.clone());
+                tokio::select! {
+                    msg = client.next() => return msg.unwrap().unwrap(),
+                    _ = tokio::time::sleep(Duration::from_millis(20)) => {}
+                }
+            }
+        })
+        .await
+        .expect("should receive a frame");
+
+        let ClientMessage::Binary(bytes) = frame else {
+            panic!("expected binary frame, got {:?}", frame);
+        };
+        let decoded: SequencedEvent = ciborium::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+    }
+}